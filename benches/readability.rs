@@ -1,5 +1,9 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-use readabilityrs::{is_probably_readerable, Readability};
+use readabilityrs::{
+    is_probably_readerable, normalize_whitespace, unescape_html_entities, Readability,
+    ReadabilityOptions,
+};
+use regex::Regex;
 use std::fs;
 use std::path::Path;
 
@@ -38,10 +42,117 @@ fn bench_parse_by_size(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_large_documents(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_documents");
+    group.sample_size(20);
+
+    for name in ["guardian-1", "wikipedia-2", "yahoo-1", "yahoo-2", "yahoo-3"] {
+        let html = match load_test_case(name) {
+            Some(h) => h,
+            None => continue,
+        };
+
+        group.throughput(Throughput::Bytes(html.len() as u64));
+        group.bench_with_input(BenchmarkId::new("doc", name), &html, |b, html| {
+            b.iter(|| {
+                let readability = Readability::new(std::hint::black_box(html), None, None).unwrap();
+                std::hint::black_box(readability.parse())
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_script_heavy(c: &mut Criterion) {
+    let html = format!(
+        "<html><head><script>{}</script></head><body><article>{}</article></body></html>",
+        "window.__state = {\"items\": [1, 2, 3]};\n".repeat(125_000),
+        "<p>Paragraph of article text that is long enough to be scored as content.</p>".repeat(20)
+    );
+
+    let mut group = c.benchmark_group("script_heavy");
+    group.sample_size(20);
+    group.throughput(Throughput::Bytes(html.len() as u64));
+    for (label, max_script_style_bytes) in [("strip", 64 * 1024), ("keep", 0)] {
+        let options = ReadabilityOptions::builder()
+            .max_script_style_bytes(max_script_style_bytes)
+            .build();
+        group.bench_with_input(BenchmarkId::new("doc", label), &html, |b, html| {
+            b.iter(|| {
+                let readability =
+                    Readability::new(std::hint::black_box(html), None, Some(options.clone()))
+                        .unwrap();
+                std::hint::black_box(readability.parse())
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_utility_classes(c: &mut Criterion) {
+    // Utility-first CSS puts dozens of classes on every element
+    let classes = "flex flex-col items-center justify-between gap-4 px-4 py-2 md:px-8 md:py-4 lg:px-12 text-sm md:text-base text-gray-700 dark:text-gray-300 bg-white dark:bg-gray-900 rounded-lg shadow-sm hover:shadow-md transition-shadow duration-200 ease-in-out border border-gray-200 dark:border-gray-700 max-w-prose mx-auto leading-relaxed tracking-tight";
+    let block = format!(
+        "<div class=\"{classes}\"><div class=\"{classes}\"><p class=\"{classes}\">Pollen counts climb every spring, and for millions of people that means weeks of sneezing.</p><span class=\"{classes}\">Allergy season</span></div></div>"
+    );
+    let html = format!(
+        "<html><body><main class=\"{classes}\">{}</main></body></html>",
+        block.repeat(400)
+    );
+
+    let mut group = c.benchmark_group("utility_classes");
+    group.sample_size(20);
+    group.throughput(Throughput::Bytes(html.len() as u64));
+    group.bench_with_input(BenchmarkId::new("doc", "tailwind"), &html, |b, html| {
+        b.iter(|| {
+            let readability = Readability::new(std::hint::black_box(html), None, None).unwrap();
+            std::hint::black_box(readability.parse())
+        });
+    });
+    group.finish();
+}
+
+fn bench_unescape_entities(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unescape_html_entities");
+
+    let plain = "Babies Who Eat Peanuts Early May Avoid Allergy, Study Finds".repeat(4);
+    let escaped = "Fish &amp; Chips &mdash; &ldquo;Best in Town&rdquo; &#8217;24".repeat(4);
+    for (name, text) in [("plain", plain), ("entities", escaped)] {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("text", name), &text, |b, text| {
+            b.iter(|| std::hint::black_box(unescape_html_entities(std::hint::black_box(text))));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_normalize_whitespace(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize_whitespace");
+
+    let clean = "Pollen counts climb every spring, and for millions of people that means weeks of sneezing. ".repeat(20);
+    let spaced = clean.replace(". ", ".  \n  ");
+    let no_break = clean.replace(", ", ",\u{a0}");
+    // The plain regex collapse the single-pass path replaces, for comparison
+    let regex = Regex::new(r"\s{2,}").unwrap();
+    for (name, text) in [("clean", clean), ("spaced", spaced), ("no_break", no_break)] {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("paragraph", name), &text, |b, text| {
+            b.iter(|| std::hint::black_box(normalize_whitespace(std::hint::black_box(text))));
+        });
+        group.bench_with_input(BenchmarkId::new("regex", name), &text, |b, text| {
+            b.iter(|| std::hint::black_box(regex.replace_all(std::hint::black_box(text), " ")));
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_readerable_check(c: &mut Criterion) {
     let mut group = c.benchmark_group("readerable");
 
-    let test_cases = ["001", "medium-1", "guardian-1"];
+    let test_cases = ["001", "medium-1", "guardian-1", "yahoo-2", "wikipedia-2"];
 
     for name in test_cases {
         let html = match load_test_case(name) {
@@ -60,6 +171,80 @@ fn bench_readerable_check(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_readerable_then_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("readerable_then_new");
+
+    for name in ["medium-1", "guardian-1", "yahoo-2"] {
+        let html = match load_test_case(name) {
+            Some(h) => h,
+            None => continue,
+        };
+
+        group.throughput(Throughput::Bytes(html.len() as u64));
+        // Check the string, then parse it again for extraction
+        group.bench_with_input(BenchmarkId::new("two_calls", name), &html, |b, html| {
+            b.iter(|| {
+                let html = std::hint::black_box(html);
+                std::hint::black_box(
+                    is_probably_readerable(html, None)
+                        .then(|| Readability::new(html, None, None).unwrap()),
+                )
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("new_if_readerable", name),
+            &html,
+            |b, html| {
+                b.iter(|| {
+                    std::hint::black_box(
+                        Readability::new_if_readerable(std::hint::black_box(html), None, None)
+                            .unwrap(),
+                    )
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_content_output(c: &mut Criterion) {
+    let Some(html) = load_test_case("guardian-1") else {
+        return;
+    };
+    let Some(article) = Readability::new(&html, None, None).unwrap().parse() else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("content_output");
+    group.throughput(Throughput::Bytes(
+        article.content.as_ref().map_or(0, |c| c.len()) as u64,
+    ));
+    let mut out = String::new();
+    group.bench_function(BenchmarkId::new("content", "guardian-1"), |b| {
+        b.iter(|| {
+            out.clear();
+            out.push_str(
+                &std::hint::black_box(&article)
+                    .content
+                    .clone()
+                    .unwrap_or_default(),
+            );
+            std::hint::black_box(out.len())
+        });
+    });
+    group.bench_function(BenchmarkId::new("write_content", "guardian-1"), |b| {
+        b.iter(|| {
+            out.clear();
+            std::hint::black_box(&article)
+                .write_content(&mut out)
+                .unwrap();
+            std::hint::black_box(out.len())
+        });
+    });
+    group.finish();
+}
+
 fn bench_batch(c: &mut Criterion) {
     let docs: Vec<String> = ["001", "002", "aclu", "ars-1", "bbc-1", "medium-1"]
         .iter()
@@ -88,7 +273,14 @@ fn bench_batch(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_parse_by_size,
+    bench_large_documents,
+    bench_script_heavy,
+    bench_utility_classes,
+    bench_unescape_entities,
+    bench_normalize_whitespace,
     bench_readerable_check,
+    bench_readerable_then_parse,
+    bench_content_output,
     bench_batch
 );
 criterion_main!(benches);
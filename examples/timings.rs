@@ -0,0 +1,72 @@
+//! Print how long each parse phase takes on a saved page.
+//!
+//! ```text
+//! cargo run --release --example timings -- page.html [https://example.com/article]
+//! ```
+
+use readabilityrs::{Readability, ReadabilityOptions};
+use std::process::ExitCode;
+use std::time::Duration;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        eprintln!("usage: timings <page.html> [url]");
+        return ExitCode::FAILURE;
+    };
+    let url = args.next();
+
+    let html = match std::fs::read_to_string(&path) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let options = ReadabilityOptions::builder().collect_timings(true).build();
+    let readability = match Readability::new(&html, url.as_deref(), Some(options)) {
+        Ok(readability) => readability,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(timings) = readability.parse().and_then(|article| article.timings) else {
+        eprintln!("{path}: no article found");
+        return ExitCode::FAILURE;
+    };
+
+    let total = timings.total;
+    let phases = [
+        ("html parse", timings.html_parse),
+        ("prep", timings.prep),
+        ("scoring", timings.scoring),
+        ("candidate selection", timings.candidate_selection),
+        ("cleanup", timings.cleanup),
+        ("serialization", timings.serialization),
+        ("metadata", timings.metadata),
+        ("other", total.saturating_sub(timings.phases_total())),
+    ];
+    for (name, time) in phases {
+        println!(
+            "{name:<20} {:>10} {:>5.1}%",
+            millis(time),
+            percent(time, total)
+        );
+    }
+    println!("{:<20} {:>10}", "total", millis(total));
+
+    ExitCode::SUCCESS
+}
+
+fn millis(time: Duration) -> String {
+    format!("{:.2}ms", time.as_secs_f64() * 1000.0)
+}
+
+fn percent(time: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        return 0.0;
+    }
+    time.as_secs_f64() / total.as_secs_f64() * 100.0
+}
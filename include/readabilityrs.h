@@ -0,0 +1,82 @@
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#ifndef READABILITYRS_H
+#define READABILITYRS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Outcome of [`readability_parse`]
+typedef enum {
+  // An article was extracted
+  READABILITY_STATUS_OK = 0,
+  // The document was read but holds no article
+  READABILITY_STATUS_NO_CONTENT = 1,
+  // A required pointer was null
+  READABILITY_STATUS_NULL_ARGUMENT = 2,
+  // The HTML or the URL is not valid UTF-8
+  READABILITY_STATUS_INVALID_UTF8 = 3,
+  // The URL does not parse, or links cannot resolve against it
+  READABILITY_STATUS_INVALID_URL = 4,
+  // The document was rejected, for example for being too large or too deep
+  READABILITY_STATUS_INVALID_DOCUMENT = 5,
+  // The extractor panicked
+  READABILITY_STATUS_PANIC = 6,
+} ReadabilityStatus;
+
+// An extracted article, owned by the caller until [`readability_article_free`]
+typedef struct ReadabilityArticle ReadabilityArticle;
+
+// Extract the article from `html_len` bytes of UTF-8 HTML at `html_ptr`.
+//
+// `url_ptr` is a NUL-terminated document URL used to resolve relative links,
+// or null. On [`ReadabilityStatus::Ok`] the article is stored in
+// `*out_article`; on any other status `*out_article` is set to null.
+//
+// # Safety
+//
+// `html_ptr` must point to `html_len` readable bytes, `url_ptr` must be null
+// or a valid C string, and `out_article` must be a valid pointer to write to.
+ReadabilityStatus readability_parse(const uint8_t *html_ptr,
+                                    size_t html_len,
+                                    const char *url_ptr,
+                                    ReadabilityArticle **out_article);
+
+// The article title, or null when there is none.
+//
+// # Safety
+//
+// `article` must come from [`readability_parse`] and not yet be freed, and
+// `out_len` must be null or a valid pointer to write the length to.
+const uint8_t *readability_article_title(const ReadabilityArticle *article, size_t *out_len);
+
+// The article byline, or null when there is none.
+//
+// # Safety
+//
+// Same as [`readability_article_title`].
+const uint8_t *readability_article_byline(const ReadabilityArticle *article, size_t *out_len);
+
+// The cleaned article HTML, or null when there is none.
+//
+// # Safety
+//
+// Same as [`readability_article_title`].
+const uint8_t *readability_article_content(const ReadabilityArticle *article, size_t *out_len);
+
+// The article excerpt, or null when there is none.
+//
+// # Safety
+//
+// Same as [`readability_article_title`].
+const uint8_t *readability_article_excerpt(const ReadabilityArticle *article, size_t *out_len);
+
+// Free an article returned by [`readability_parse`]. Null is ignored.
+//
+// # Safety
+//
+// `article` must be null or come from [`readability_parse`], and must not be
+// used again afterwards.
+void readability_article_free(ReadabilityArticle *article);
+
+#endif  /* READABILITYRS_H */
@@ -30,6 +30,7 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 
 /// Represents a successfully parsed article with extracted content and metadata.
 ///
@@ -106,6 +107,25 @@ pub struct Article {
     /// separated by commas.
     pub byline: Option<String>,
 
+    /// Individual author names split from the byline.
+    ///
+    /// The byline prefix ("By", "Von", "Por", ...) is dropped and the names are
+    /// split on commas and on conjunctions such as "and", "y", "und", "et" or "、".
+    /// If any part does not look like a name, the whole byline is kept as one entry.
+    /// Honorifics such as "Dr." and suffixes such as "Jr." are kept, while credentials
+    /// such as "MD" or "PhD" are removed from each name.
+    /// Empty when no byline was found.
+    #[serde(default)]
+    pub authors: Vec<String>,
+
+    /// People credited for other work on the article.
+    ///
+    /// Lines such as "Photography by Ansel Adams", "Illustrations by Quentin Blake" or
+    /// "Video by AP" near the byline are collected here instead of becoming the byline.
+    /// Credits inside figure captions belong to a single image and are not included.
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+
     /// The image is extracted from various sources, checking JSON-LD structured data first
     /// (supporting simple URLs, ImageObject with `url` property, and arrays of images).
     /// If not found, Open Graph meta tags are checked in priority order: `og:image:secure_url`,
@@ -117,7 +137,9 @@ pub struct Article {
 
     /// Text direction hint: "ltr" (left-to-right), "rtl" (right-to-left), or "auto".
     ///
-    /// Extracted from the `dir` attribute on the `<html>` element.
+    /// Taken from the nearest `dir` attribute on the article container or its
+    /// ancestors, falling back to the `<html>` element. The same value is set as
+    /// `dir` on the root elements of `content`.
     pub dir: Option<String>,
 
     /// Name of the website or publication.
@@ -144,10 +166,183 @@ pub struct Article {
     /// This is the extracted content before the final cleaning steps,
     /// useful for debugging or custom post-processing.
     pub raw_content: Option<String>,
+
+    /// The URL relative links and media sources were resolved against.
+    ///
+    /// The document's first `<base href>` resolved against the URL given to
+    /// [`Readability::new`](crate::Readability::new), or that URL itself when
+    /// there is no usable `<base>`. Normalized as by the WHATWG URL parser:
+    /// lowercase scheme and host, default port dropped, dot segments resolved.
+    /// `None` when nothing was resolved.
+    pub resolved_base_url: Option<String>,
+
+    /// Wall-clock time spent in each parse phase.
+    ///
+    /// Only recorded when `collect_timings` is enabled in the options.
+    pub timings: Option<Timings>,
+}
+
+/// Wall-clock time spent in each phase of a parse
+///
+/// Phases that run once per extraction attempt (prep, scoring, candidate
+/// selection and serialization) add up across all attempts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct Timings {
+    /// Parsing HTML into DOM trees: the source document and its preprocessed copy
+    pub html_parse: Duration,
+    /// Preprocessing the document and removing unlikely candidates
+    pub prep: Duration,
+    /// Scoring candidate elements and applying link density penalties
+    pub scoring: Duration,
+    /// Picking the top candidate
+    pub candidate_selection: Duration,
+    /// Cleaning the extracted content and deriving text, excerpt and direction
+    pub cleanup: Duration,
+    /// Building the article HTML from the top candidate and its siblings
+    pub serialization: Duration,
+    /// Extracting JSON-LD and meta tag metadata
+    pub metadata: Duration,
+    /// The whole parse, from `Readability::new` until the article is returned
+    pub total: Duration,
+}
+
+impl Timings {
+    /// Run `f`, adding its wall time to the `phase` field when timing is enabled
+    pub(crate) fn record<R>(
+        timings: &mut Option<Timings>,
+        phase: fn(&mut Timings) -> &mut Duration,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let start = Self::start(timings);
+        let result = f();
+        Self::stop(timings, phase, start);
+        result
+    }
+
+    /// Read the clock if timing is enabled
+    pub(crate) fn start(timings: &Option<Timings>) -> Option<Instant> {
+        timings.as_ref().map(|_| Instant::now())
+    }
+
+    /// Add the time since `start` to the `phase` field
+    pub(crate) fn stop(
+        timings: &mut Option<Timings>,
+        phase: fn(&mut Timings) -> &mut Duration,
+        start: Option<Instant>,
+    ) {
+        if let (Some(timings), Some(start)) = (timings.as_mut(), start) {
+            *phase(timings) += start.elapsed();
+        }
+    }
+
+    /// Sum of all phases, which stays a little below `total`
+    pub fn phases_total(&self) -> Duration {
+        self.html_parse
+            + self.prep
+            + self.scoring
+            + self.candidate_selection
+            + self.cleanup
+            + self.serialization
+            + self.metadata
+    }
+}
+
+/// A person or agency credited for non-writing work on an article
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Contributor {
+    /// What the contributor is credited for
+    pub role: ContributorRole,
+    /// The credited name, as written ("Ansel Adams", "AP")
+    pub name: String,
+}
+
+/// Kind of work a [`Contributor`] is credited for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContributorRole {
+    /// "Photography by", "Photos by"
+    Photography,
+    /// "Illustrations by", "Illustrated by"
+    Illustration,
+    /// "Video by"
+    Video,
+    /// "Graphics by"
+    Graphics,
+    /// "Reviewed by", "Medically reviewed by"
+    Review,
+    /// "Edited by"
+    Editing,
+    /// "Fact-checked by"
+    FactCheck,
+    /// "Updated by"
+    Update,
 }
 
 impl Article {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Write the cleaned HTML content to `w`, writing nothing when there is none.
+    ///
+    /// Lets callers stream the article into a response body or file writer
+    /// without cloning [`content`](Self::content) first.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     content: Some("<div><p>Hello</p></div>".to_string()),
+    ///     ..Article::default()
+    /// };
+    ///
+    /// let mut page = String::from("<main>");
+    /// article.write_content(&mut page).unwrap();
+    /// page.push_str("</main>");
+    /// assert_eq!(page, "<main><div><p>Hello</p></div></main>");
+    /// ```
+    pub fn write_content(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        match &self.content {
+            Some(content) => w.write_str(content),
+            None => Ok(()),
+        }
+    }
+
+    /// The article shaped like the object Readability.js's `parse()` returns.
+    ///
+    /// Keys are `title`, `byline`, `dir`, `lang`, `content`, `textContent`,
+    /// `length`, `excerpt`, `siteName` and `publishedTime`, with `null` for
+    /// missing values. Unlike [`length`](Self::length), which counts bytes,
+    /// `length` here is the number of characters in the text content. Handy for
+    /// diffing this crate against Mozilla's implementation.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     title: Some("Café".to_string()),
+    ///     text_content: Some("Déjà vu".to_string()),
+    ///     ..Article::default()
+    /// };
+    ///
+    /// let json = article.to_mozilla_json();
+    /// assert_eq!(json["title"], "Café");
+    /// assert_eq!(json["textContent"], "Déjà vu");
+    /// assert_eq!(json["length"], 7);
+    /// assert!(json["siteName"].is_null());
+    /// ```
+    pub fn to_mozilla_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "title": self.title,
+            "byline": self.byline,
+            "dir": self.dir,
+            "lang": self.lang,
+            "content": self.content,
+            "textContent": self.text_content,
+            "length": self.text_content.as_deref().map_or(0, |text| text.chars().count()),
+            "excerpt": self.excerpt,
+            "siteName": self.site_name,
+            "publishedTime": self.published_time,
+        })
+    }
 }
@@ -0,0 +1,116 @@
+//! Command-line front end, built with the `cli` feature.
+//!
+//! Reads HTML from a file or stdin and prints the extracted article:
+//!
+//! ```text
+//! curl -s https://example.com/post | readability --url https://example.com/post
+//! readability page.html --output json
+//! ```
+//!
+//! Exits with 1 when no article is found (or, with `--readerable-only`, when
+//! the page does not look readerable) and 2 on bad input.
+
+use clap::{Parser, ValueEnum};
+use readabilityrs::{is_probably_readerable, Readability, ReadabilityOptions};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Extract the main article from an HTML page
+#[derive(Debug, Parser)]
+#[command(name = "readability", version)]
+struct Args {
+    /// HTML file to read; stdin when absent or `-`
+    file: Option<PathBuf>,
+
+    /// URL of the page, used to resolve relative links
+    #[arg(long)]
+    url: Option<String>,
+
+    /// What to print
+    #[arg(long, value_enum, default_value_t = Output::Html)]
+    output: Output,
+
+    /// Minimum article length in characters
+    #[arg(long)]
+    char_threshold: Option<usize>,
+
+    /// Give up without parsing when the page does not look like an article
+    #[arg(long)]
+    readerable_only: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Output {
+    /// The cleaned article HTML
+    Html,
+    /// The article text
+    Text,
+    /// The article converted to Markdown
+    Markdown,
+    /// The whole `Article`, serialized
+    Json,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("readability: {message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Print the article, returning whether one was found
+fn run(args: &Args) -> Result<bool, String> {
+    let html = read_input(args.file.as_ref()).map_err(|e| format!("cannot read input: {e}"))?;
+
+    if args.readerable_only && !is_probably_readerable(&html, None) {
+        eprintln!("readability: page is probably not readerable");
+        return Ok(false);
+    }
+
+    let mut options = ReadabilityOptions::builder();
+    if let Some(threshold) = args.char_threshold {
+        options = options.char_threshold(threshold);
+    }
+    let readability = Readability::new(&html, args.url.as_deref(), Some(options.build()))
+        .map_err(|e| e.to_string())?;
+    let Some(article) = readability.parse() else {
+        eprintln!("readability: no article found");
+        return Ok(false);
+    };
+
+    let rendered = match args.output {
+        Output::Html => article.content.unwrap_or_default(),
+        Output::Text => article.text_content.unwrap_or_default(),
+        Output::Markdown => {
+            let body = html2md::parse_html(article.content.as_deref().unwrap_or_default());
+            match &article.title {
+                Some(title) => format!("# {title}\n\n{body}"),
+                None => body,
+            }
+        }
+        Output::Json => serde_json::to_string_pretty(&article).map_err(|e| e.to_string())?,
+    };
+
+    match writeln!(io::stdout().lock(), "{}", rendered.trim_end()) {
+        // The reader went away, as with `| head`
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(e.to_string()),
+        _ => Ok(true),
+    }
+}
+
+fn read_input(file: Option<&PathBuf>) -> io::Result<String> {
+    let mut bytes = Vec::new();
+    match file {
+        Some(path) if path.as_os_str() != "-" => bytes = std::fs::read(path)?,
+        _ => {
+            io::stdin().lock().read_to_end(&mut bytes)?;
+        }
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
@@ -1,136 +1,1810 @@
 //! Content cleaning and post-processing functions.
 
-use crate::constants::{DIV_TO_P_ELEMS, REGEXPS};
+use crate::constants::{
+    truncate_class_id, AD_LABELS, DEPRECATED_SIZE_ATTRIBUTE_ELEMS, DIV_TO_P_ELEMS, FONT_ATTRIBUTES,
+    MAX_INLINE_SVG_ELEMENTS, PHRASING_ELEMS, PRESENTATIONAL_ATTRIBUTES,
+    PRESENTATIONAL_WRAPPER_ELEMS, REGEXPS, RELATED_CONTENT_PHRASES, SEMANTIC_INLINE_ELEMS,
+    SRCSET_ELEMS, URL_ATTRIBUTES,
+};
+use crate::content_extractor::is_void_element;
 use crate::error::Result;
-use kuchikikiki::{traits::*, NodeData, NodeRef};
+use crate::options::{ImageUrlRewriter, ReadabilityOptions, UrlContext, UrlRewriter};
+use crate::utils::{
+    classify_url, has_opaque_scheme, is_byline_duplicate, is_in_page_toc, looks_like_image_credit,
+    split_leading_dateline, strip_tracking_params, trim_soft_space, unescape_html_entities_owned,
+    BylineWords, SpecialScheme, UrlKind, MIN_TOC_LINKS,
+};
+use kuchikikiki::{iter::NodeEdge, traits::*, NodeData, NodeRef};
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use scraper::{ElementRef, Html, Selector};
+use std::borrow::Cow;
+use url::{Position, Url};
 
 /// Clean and post-process extracted article content (light version)
 ///
 /// This function:
-/// - Fixes relative URLs to absolute
 /// - Removes nav-like sections
-pub fn clean_article_content_light(html: &str, base_url: Option<&str>) -> Result<String> {
-    let mut result = html.to_string();
-
-    if let Some(base) = base_url {
-        result = fix_relative_urls_in_html(&result, base);
-    }
-
-    result = remove_nav_like_sections(&result);
-
-    Ok(result)
+pub fn clean_article_content_light(html: &str) -> Result<String> {
+    Ok(remove_nav_like_sections(html))
 }
 
 /// Clean and post-process extracted article content (full version)
 ///
 /// This function:
-/// - Removes unwanted elements (scripts, styles, forms, etc.)
+/// - Removes nav-like sections
 /// - Fixes relative URLs to absolute
-/// - Cleans up empty elements
-/// - Normalizes whitespace
-pub fn clean_article_content(html: &str, base_url: Option<&str>) -> Result<String> {
-    let mut result = clean_article_content_light(html, base_url)?;
-    result = remove_conditionally(&result);
-    Ok(result)
-}
-
-/// Fix relative URLs in HTML string using regex
-fn fix_relative_urls_in_html(html: &str, _base_url: &str) -> String {
-    // For now, just return as-is
-    // TODO: Implement proper URL fixing without re-parsing the entire tree
-    html.to_string()
+/// - Normalizes code blocks, inline SVG, video embeds and figures
+/// - Removes low-quality blocks conditionally and unwraps layout tables
+pub fn clean_article_content(
+    html: &str,
+    base_url: Option<&str>,
+    options: &ReadabilityOptions,
+) -> Result<String> {
+    let result = clean_article_content_light(html)?;
+    Ok(remove_conditionally(&result, base_url, options))
 }
 
 /// Remove nav-like sections using lightweight regex patterns.
 fn remove_nav_like_sections(html: &str) -> String {
     static NAV_REGEX: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"(?is)<nav\b[^>]*?>.*?</nav>").unwrap());
+    // One class and one id pattern per tag and keyword, applied in this order
+    static NAV_SECTION_REGEXES: Lazy<Vec<Regex>> = Lazy::new(|| {
+        let tags = ["div", "section", "ul", "ol"];
+        let keywords = ["nav", "navbar", "menu", "breadcrumbs", "sidebar", "widget"];
+        let mut regexes = Vec::new();
+        for tag in tags {
+            for keyword in keywords {
+                for attr in ["class", "id"] {
+                    let pattern = format!(
+                        r#"(?is)<{tag}\b[^>]*?{attr}="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
+                    );
+                    regexes.push(Regex::new(&pattern).unwrap());
+                }
+            }
+        }
+        regexes
+    });
+
+    let mut result = NAV_REGEX
+        .replace_all(html, |caps: &Captures| keep_in_page_toc(&caps[0]))
+        .to_string();
+
+    for re in NAV_SECTION_REGEXES.iter() {
+        result = re.replace_all(&result, "").to_string();
+    }
+
+    result
+}
+
+/// Keep a removed navigation block only if it is a table of contents for the page
+pub(crate) fn keep_in_page_toc(block: &str) -> String {
+    if is_in_page_toc(block) {
+        block.to_string()
+    } else {
+        String::new()
+    }
+}
+
+fn remove_conditionally(
+    html: &str,
+    base_url: Option<&str>,
+    options: &ReadabilityOptions,
+) -> String {
+    remove_conditionally_dom(html, base_url, options)
+        .unwrap_or_else(|| remove_conditionally_regex(html))
+}
+
+fn remove_conditionally_dom(
+    html: &str,
+    base_url: Option<&str>,
+    options: &ReadabilityOptions,
+) -> Option<String> {
+    let document = kuchikikiki::parse_html().one(html);
+    let (target_node, children_only) = body_or_document(&document);
+    let video_regex = options.video_regex();
+    let base = base_url.and_then(|base| Url::parse(base).ok());
+
+    clean_media_elements(&target_node);
+    remove_tracking_images(&target_node);
+    remove_loading_placeholders(&target_node);
+    remove_ad_labels(&target_node, &options.ad_labels);
+    if base.is_some() || options.assume_https_for_protocol_relative {
+        fix_relative_uris(
+            &target_node,
+            base.as_ref(),
+            options.assume_https_for_protocol_relative,
+        );
+    }
+    if options.strip_tracking_params {
+        strip_link_tracking_params(&target_node, &options.extra_tracking_params);
+    }
+    remove_related_modules(
+        &target_node,
+        base.as_ref(),
+        &options.related_content_phrases,
+    );
+    truncate_trailing_comments(&target_node);
+    remove_pull_quotes(&target_node);
+    normalize_code_blocks(&target_node);
+    clean_inline_svgs(&target_node);
+    clean_video_embeds(&target_node, video_regex);
+    wrap_captioned_images(&target_node);
+    if options.strip_image_credits {
+        strip_image_credits(&target_node);
+    }
+    if options.strip_toc {
+        strip_tables_of_contents(&target_node);
+    }
+    if options.strip_datelines {
+        strip_lede_dateline(&target_node);
+    }
+    mark_data_tables(&target_node);
+
+    let cleanup_tags = ["form", "fieldset", "table", "ul", "ol", "div", "section"];
+    for tag in cleanup_tags {
+        clean_conditionally_tag(&target_node, tag, video_regex);
+    }
+    unwrap_layout_tables(&target_node);
+    if options.open_details {
+        open_details(&target_node);
+    }
+    collapse_br_runs(&target_node);
+    remove_empty_paragraphs(&target_node);
+    if let Some(rewriter) = &options.image_url_rewriter {
+        rewrite_image_urls(&target_node, rewriter);
+    }
+    if let Some(rewriter) = &options.url_rewriter {
+        rewrite_urls(&target_node, rewriter);
+    }
+
+    Some(serialize_node(&target_node, children_only, html.len()))
+}
+
+/// Turn relative links and media sources into absolute URLs
+///
+/// This implements Mozilla's _fixRelativeUris, extended to every attribute in
+/// `URL_ATTRIBUTES` and to `srcset` candidates. In-page anchors (`#id`) are
+/// left untouched since they point into the article itself, as are links with a
+/// non-hierarchical scheme (`mailto:`, `tel:`, `sms:`, `geo:`...), which are
+/// kept exactly as written. `javascript:` links are left to
+/// [`sanitize_content`]. See [`resolve_url`] for how each URL resolves.
+///
+/// Hosts are spelled one way across the document: in Unicode when any link
+/// writes its host in Unicode (`https://мвд.рф/`), in ASCII otherwise.
+fn fix_relative_uris(root: &NodeRef, base: Option<&Url>, assume_https: bool) {
+    let unicode_hosts = writes_unicode_hosts(root);
+    for (tag, attributes) in URL_ATTRIBUTES {
+        for node in select_nodes(root, tag) {
+            for attribute in *attributes {
+                let Some(value) = get_attribute(&node, attribute) else {
+                    continue;
+                };
+                if matches!(
+                    classify_url(&value),
+                    UrlKind::Fragment | UrlKind::Special(_)
+                ) {
+                    continue;
+                }
+                if let (Some(element), Some(resolved)) = (
+                    node.as_element(),
+                    resolve_url(&value, base, assume_https, unicode_hosts),
+                ) {
+                    element.attributes.borrow_mut().insert(*attribute, resolved);
+                }
+            }
+        }
+    }
+
+    for tag in SRCSET_ELEMS {
+        for node in select_nodes(root, &format!("{tag}[srcset]")) {
+            if let Some(srcset) = get_attribute(&node, "srcset") {
+                let resolved = SRCSET_URL_REGEX.replace_all(&srcset, |caps: &Captures| {
+                    let url = resolve_url(&caps[1], base, assume_https, unicode_hosts)
+                        .unwrap_or_else(|| caps[1].to_string());
+                    format!(
+                        "{url}{}{}",
+                        caps.get(2).map_or("", |m| m.as_str()),
+                        &caps[3]
+                    )
+                });
+                if let Some(element) = node.as_element() {
+                    element
+                        .attributes
+                        .borrow_mut()
+                        .insert("srcset", resolved.into_owned());
+                }
+            }
+        }
+    }
+}
+
+/// Whether any link or media URL in the document writes its host in Unicode
+fn writes_unicode_hosts(root: &NodeRef) -> bool {
+    let in_attributes = URL_ATTRIBUTES.iter().any(|(tag, attributes)| {
+        select_nodes(root, tag).iter().any(|node| {
+            attributes
+                .iter()
+                .filter_map(|attribute| get_attribute(node, attribute))
+                .any(|value| writes_unicode_host(&value))
+        })
+    });
+    in_attributes
+        || SRCSET_ELEMS.iter().any(|tag| {
+            select_nodes(root, &format!("{tag}[srcset]"))
+                .iter()
+                .filter_map(|node| get_attribute(node, "srcset"))
+                .any(|srcset| srcset.split_whitespace().any(writes_unicode_host))
+        })
+}
+
+/// Remove tracking query parameters from link targets
+///
+/// See [`strip_tracking_params`] for which parameters go.
+fn strip_link_tracking_params(root: &NodeRef, extra: &[String]) {
+    for node in select_nodes(root, "a[href], area[href]") {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let mut attrs = element.attributes.borrow_mut();
+        if let Some(stripped) = attrs
+            .get("href")
+            .and_then(|href| strip_tracking_params(href, extra))
+        {
+            attrs.insert("href", stripped);
+        }
+    }
+}
+
+/// Pass the article's link and media URLs through the caller's [`UrlRewriter`]
+///
+/// Runs once cleanup is done, so only URLs that make it into the output are
+/// offered, already resolved by [`fix_relative_uris`].
+fn rewrite_urls(root: &NodeRef, rewriter: &UrlRewriter) {
+    for node in root.descendants() {
+        let (Some(element), Some(context)) = (node.as_element(), url_context(&node)) else {
+            continue;
+        };
+        let mut attrs = element.attributes.borrow_mut();
+        let attributes: &[&str] = match context {
+            UrlContext::Link => &["href"],
+            _ => &["src", "srcset"],
+        };
+        for attribute in attributes {
+            let Some(value) = attrs.get(*attribute) else {
+                continue;
+            };
+            let rewritten = if *attribute == "srcset" {
+                SRCSET_URL_REGEX
+                    .replace_all(value, |caps: &Captures| {
+                        let url =
+                            rewriter(context, &caps[1]).unwrap_or_else(|| caps[1].to_string());
+                        format!(
+                            "{url}{}{}",
+                            caps.get(2).map_or("", |m| m.as_str()),
+                            &caps[3]
+                        )
+                    })
+                    .into_owned()
+            } else {
+                match rewriter(context, value.trim()) {
+                    Some(url) => url,
+                    None => continue,
+                }
+            };
+            attrs.insert(*attribute, rewritten);
+        }
+    }
+}
+
+/// Pass image URLs through the caller's [`ImageUrlRewriter`] with a size hint
+///
+/// Covers `<img>` `src` and `srcset`, `<picture>` `<source>` `srcset` and
+/// `<video>` `poster`. See [`ReadabilityOptions::image_url_rewriter`] for how
+/// the size is worked out.
+fn rewrite_image_urls(root: &NodeRef, rewriter: &ImageUrlRewriter) {
+    for node in select_nodes(root, "img, picture > source, video[poster]") {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        // A <source> without a declared size takes the one of its picture's <img>
+        let sized = if node_has_tag(&node, "source") && image_dimension(&node, "width").is_none() {
+            node.parent()
+                .and_then(|picture| first_image(&picture))
+                .unwrap_or_else(|| node.clone())
+        } else {
+            node.clone()
+        };
+        let width = image_dimension(&sized, "width").map(|width| width as u32);
+        let height = image_dimension(&sized, "height").map(|height| height as u32);
+        let declared = (width.is_some() || height.is_some())
+            .then(|| (width.unwrap_or(0), height.unwrap_or(0)));
+
+        // A video's own `src` is not an image
+        let attribute = if node_has_tag(&node, "video") {
+            "poster"
+        } else {
+            "src"
+        };
+        let mut attrs = element.attributes.borrow_mut();
+        if let Some(value) = attrs
+            .get(attribute)
+            .filter(|value| !value.trim().is_empty())
+        {
+            let rewritten = rewriter(value.trim(), declared);
+            attrs.insert(attribute, rewritten);
+        }
+        if let Some(srcset) = attrs.get("srcset") {
+            let rewritten = SRCSET_URL_REGEX
+                .replace_all(srcset, |caps: &Captures| {
+                    let descriptor = caps.get(2).map_or("", |m| m.as_str());
+                    let size = srcset_candidate_size(descriptor.trim(), width, height).or(declared);
+                    format!("{}{descriptor}{}", rewriter(&caps[1], size), &caps[3])
+                })
+                .into_owned();
+            attrs.insert("srcset", rewritten);
+        }
+    }
+}
+
+/// Size of a `srcset` candidate from its `640w` or `2x` descriptor
+///
+/// The side the descriptor does not give is scaled from the declared size, or
+/// `0` when that is not known.
+fn srcset_candidate_size(
+    descriptor: &str,
+    width: Option<u32>,
+    height: Option<u32>,
+) -> Option<(u32, u32)> {
+    if let Some(w) = descriptor
+        .strip_suffix('w')
+        .and_then(|w| w.parse::<u32>().ok())
+    {
+        let h = match (width, height) {
+            (Some(width), Some(height)) if width > 0 => {
+                (f64::from(w) * f64::from(height) / f64::from(width)).round() as u32
+            }
+            _ => 0,
+        };
+        return Some((w, h));
+    }
+    let density: f64 = descriptor.strip_suffix('x')?.parse().ok()?;
+    let scale =
+        |side: Option<u32>| side.map_or(0, |side| (f64::from(side) * density).round() as u32);
+    (width.is_some() || height.is_some()).then(|| (scale(width), scale(height)))
+}
+
+/// Which kind of URL an element's `href`, `src` or `srcset` holds, if any
+fn url_context(node: &NodeRef) -> Option<UrlContext> {
+    let element = node.as_element()?;
+    match element.name.local.as_ref() {
+        "a" | "area" => Some(UrlContext::Link),
+        "img" => Some(UrlContext::Image),
+        "input" => element
+            .attributes
+            .borrow()
+            .get("type")
+            .is_some_and(|kind| kind.eq_ignore_ascii_case("image"))
+            .then_some(UrlContext::Image),
+        "audio" | "video" => Some(UrlContext::Media),
+        "source" | "track" => match node
+            .parent()
+            .as_ref()
+            .and_then(|parent| parent.as_element())
+        {
+            Some(parent) if parent.name.local.as_ref() == "picture" => Some(UrlContext::Image),
+            Some(parent) if matches!(parent.name.local.as_ref(), "audio" | "video") => {
+                Some(UrlContext::Media)
+            }
+            _ => None,
+        },
+        "iframe" => Some(UrlContext::Iframe),
+        _ => None,
+    }
+}
+
+// A `srcset` candidate: URL, optional width/density descriptor, separator
+static SRCSET_URL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\S+)(\s+[\d.]+[xw])?(\s*(?:,|$))").unwrap());
+
+/// Resolve a link or media URL against the base URL.
+///
+/// A scheme-relative `//host/path` keeps the host and takes the base's scheme.
+/// Without a base only those resolve, to `https://host/path`, and only when
+/// `assume_https` is set. URLs with a non-hierarchical scheme never resolve.
+/// `None` means the value is left as it is. The host is spelled in Unicode
+/// when `unicode_host` is set, see [`serialize_resolved_url`].
+fn resolve_url(
+    value: &str,
+    base: Option<&Url>,
+    assume_https: bool,
+    unicode_host: bool,
+) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || has_opaque_scheme(value) {
+        return None;
+    }
+    let resolved = match base {
+        Some(base) => base.join(value),
+        None if assume_https && classify_url(value) == UrlKind::ProtocolRelative => {
+            Url::parse(&format!("https:{value}"))
+        }
+        None => return None,
+    };
+    resolved
+        .ok()
+        .map(|url| serialize_resolved_url(&url, unicode_host))
+}
+
+/// Whether an absolute or scheme-relative URL writes its host with non-ASCII
+/// characters, as in `https://мвд.рф/`
+fn writes_unicode_host(written: &str) -> bool {
+    matches!(
+        classify_url(written),
+        UrlKind::Absolute | UrlKind::ProtocolRelative
+    ) && written.split_once("//").is_some_and(|(_, rest)| {
+        !rest
+            .split(['/', '?', '#', '\\'])
+            .next()
+            .unwrap_or_default()
+            .is_ascii()
+    })
+}
+
+/// Serialize a resolved URL with its host in (normalized) Unicode or in ASCII
+///
+/// The url crate always serializes internationalized hosts as punycode. With
+/// `unicode_host`, punycode labels are turned back into Unicode so the output
+/// reads like the page; otherwise the host stays ASCII.
+fn serialize_resolved_url(url: &Url, unicode_host: bool) -> String {
+    let unicode = url
+        .host_str()
+        .filter(|host| unicode_host && host.contains("xn--"))
+        .map(url::quirks::domain_to_unicode)
+        .filter(|host| !host.is_empty());
+    match unicode {
+        Some(host) => format!(
+            "{}{host}{}",
+            &url[..Position::BeforeHost],
+            &url[Position::AfterHost..]
+        ),
+        None => url.to_string(),
+    }
+}
+
+// Trailing "Photo: ..." style clause of a caption and the source it credits
+static IMAGE_CREDIT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:^|[\s.;,|(\[—–-])(?P<clause>[(\[]?\s*(?:photo(?:graph)?|image|picture|credit|source|illustration)s?(?:\s+credit)?(?:\s+by\s+|\s*[:\-–—|]\s*)(?P<source>[^:]+?)\s*[)\]]?\s*$)",
+    )
+    .unwrap()
+});
+
+/// Remove a trailing photo credit clause from each figure caption
+///
+/// Only the credit is dropped, with the separator before it. A caption that
+/// held nothing but the credit is removed.
+fn strip_image_credits(root: &NodeRef) {
+    for caption in select_nodes(root, "figcaption") {
+        let text = caption.text_contents();
+        let Some(caps) = IMAGE_CREDIT_REGEX.captures(&text) else {
+            continue;
+        };
+        if !looks_like_image_credit(&caps["source"]) {
+            continue;
+        }
+
+        let description = text[..caps.name("clause").unwrap().start()]
+            .trim_end_matches(|c: char| c.is_whitespace() || "|—–-(".contains(c));
+        if description.trim().is_empty() {
+            caption.detach();
+            continue;
+        }
+        truncate_text(&caption, description.len());
+        for element in caption.descendants().collect::<Vec<_>>() {
+            if element.as_element().is_some()
+                && element.text_contents().trim().is_empty()
+                && element.select_first("img, picture, video, svg").is_err()
+                && !node_has_tag(&element, "br")
+            {
+                element.detach();
+            }
+        }
+        while let Some(last) = caption.last_child() {
+            let is_blank = last
+                .as_text()
+                .is_some_and(|text| text.borrow().trim().is_empty());
+            if !(is_blank || node_has_tag(&last, "br")) {
+                break;
+            }
+            last.detach();
+        }
+    }
+}
+
+/// Cut a node's text down to its first `len` bytes, emptying later text nodes
+fn truncate_text(node: &NodeRef, len: usize) {
+    let mut offset = 0;
+    for text_node in node.descendants().text_nodes() {
+        let mut text = text_node.borrow_mut();
+        let end = offset + text.len();
+        if offset >= len {
+            text.clear();
+        } else if end > len {
+            text.truncate(len - offset);
+        }
+        offset = end;
+    }
+}
+
+/// Add an `open` attribute to every `<details>` so collapsed content shows
+fn open_details(root: &NodeRef) {
+    for details in select_nodes(root, "details") {
+        if let Some(element) = details.as_element() {
+            element
+                .attributes
+                .borrow_mut()
+                .insert("open", String::new());
+        }
+    }
+}
+
+/// Remove the dateline opening the first paragraph ("CAIRO —")
+///
+/// Only the dateline clause is dropped; the rest of the paragraph stays.
+/// Inline wrappers left empty (`<strong>CAIRO —</strong>`) are removed.
+fn strip_lede_dateline(root: &NodeRef) {
+    let Some(paragraph) = select_nodes(root, "p")
+        .into_iter()
+        .find(|p| !p.text_contents().trim().is_empty())
+    else {
+        return;
+    };
+    let Some(mut remaining) = split_leading_dateline(&paragraph.text_contents()) else {
+        return;
+    };
+
+    let mut emptied = Vec::new();
+    for node in paragraph.descendants() {
+        if remaining == 0 {
+            break;
+        }
+        if let Some(text) = node.as_text() {
+            let mut text = text.borrow_mut();
+            let cut = remaining.min(text.len());
+            text.replace_range(..cut, "");
+            remaining -= cut;
+            if text.is_empty() {
+                emptied.push(node.clone());
+            }
+        }
+    }
+
+    for node in emptied {
+        let mut wrapper = node.parent();
+        node.detach();
+        while let Some(element) = wrapper.filter(|element| {
+            *element != paragraph
+                && node_has_inline_tag(element)
+                && element.children().next().is_none()
+        }) {
+            wrapper = element.parent();
+            element.detach();
+        }
+    }
+}
+
+/// Remove tables of contents embedded in the article
+///
+/// A table of contents is a list where nearly every item links to a fragment
+/// of the page, introduced by a "Table of contents", "Contents" or "In this
+/// article" heading right before the list or before the wrapper holding it.
+/// The heading goes with the list, as do wrappers left without any text.
+fn strip_tables_of_contents(root: &NodeRef) {
+    for list in select_nodes(root, "ul, ol") {
+        if !list.ancestors().any(|ancestor| ancestor == *root) || !is_fragment_link_list(&list) {
+            continue;
+        }
+        let Some(heading) = find_toc_heading(&list, root) else {
+            continue;
+        };
+
+        heading.detach();
+        let mut node = list;
+        while let Some(parent) = node.parent() {
+            node.detach();
+            if parent == *root || !parent.text_contents().trim().is_empty() {
+                break;
+            }
+            node = parent;
+        }
+    }
+}
+
+/// Whether nearly every item of a list links to a fragment of the page
+fn is_fragment_link_list(list: &NodeRef) -> bool {
+    let items: Vec<NodeRef> = list
+        .children()
+        .filter(|child| node_has_tag(child, "li"))
+        .collect();
+    if items.len() < MIN_TOC_LINKS {
+        return false;
+    }
+    let fragment_items = items
+        .iter()
+        .filter(|item| {
+            select_nodes(item, "a[href]")
+                .first()
+                .and_then(|link| get_attribute(link, "href"))
+                .is_some_and(|href| REGEXPS.hash_url.is_match(href.trim()))
+        })
+        .count();
+    fragment_items * 10 >= items.len() * 9
+}
+
+/// Find the heading introducing a list, before it or before its wrappers
+fn find_toc_heading(list: &NodeRef, root: &NodeRef) -> Option<NodeRef> {
+    static TOC_HEADING_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)^(?:table of contents|contents|in this article)\W*$").unwrap()
+    });
+
+    let mut node = list.clone();
+    for _ in 0..3 {
+        if let Some(sibling) = node
+            .preceding_siblings()
+            .find(|sibling| sibling.as_element().is_some())
+        {
+            return TOC_HEADING_REGEX
+                .is_match(sibling.text_contents().trim())
+                .then_some(sibling);
+        }
+        match node.parent() {
+            Some(parent) if parent != *root => node = parent,
+            _ => break,
+        }
+    }
+    None
+}
+
+/// Remove "related stories" style modules embedded in the article body
+///
+/// A module is the container around a short heading that contains one of the
+/// related-content phrases. It is only removed when more than half of its text
+/// is link text and most of its links stay on the same site, so a "Related
+/// work" section made of prose survives.
+fn remove_related_modules(root: &NodeRef, base: Option<&Url>, extra_phrases: &[String]) {
+    for heading in select_nodes(root, "h1, h2, h3, h4, h5, h6") {
+        if heading.parent().is_none() {
+            continue;
+        }
+        let heading_text = heading.text_contents().trim().to_lowercase();
+        if heading_text.is_empty() || heading_text.chars().count() > 60 {
+            continue;
+        }
+        let matches_phrase = RELATED_CONTENT_PHRASES
+            .iter()
+            .any(|phrase| heading_text.contains(phrase))
+            || extra_phrases
+                .iter()
+                .any(|phrase| heading_text.contains(&phrase.to_lowercase()));
+        if !matches_phrase {
+            continue;
+        }
+
+        // Climb out of wrappers that hold nothing but the heading
+        let mut container = heading.clone();
+        while let Some(parent) = container.parent() {
+            if parent == *root || parent.as_element().is_none() {
+                break;
+            }
+            container = parent;
+            if container.text_contents().trim().len() > heading_text.len() {
+                break;
+            }
+        }
+        if container == heading || container == *root {
+            continue;
+        }
+
+        let text_len = container.text_contents().trim().len();
+        if dom_link_density(&container, text_len) <= 0.5 {
+            continue;
+        }
+        if links_mostly_same_site(&container, base) {
+            container.detach();
+        }
+    }
+}
+
+fn links_mostly_same_site(node: &NodeRef, base: Option<&Url>) -> bool {
+    let hrefs: Vec<String> = select_nodes(node, "a[href]")
+        .iter()
+        .filter_map(|link| get_attribute(link, "href"))
+        .collect();
+    if hrefs.is_empty() {
+        return false;
+    }
+    let Some(base) = base else {
+        return true;
+    };
+
+    let site = |url: &Url| {
+        url.host_str()
+            .map(|host| host.trim_start_matches("www.").to_ascii_lowercase())
+    };
+    let base_site = site(base);
+    let same_site = hrefs
+        .iter()
+        .filter(|href| match base.join(href) {
+            Ok(url) => site(&url) == base_site,
+            Err(_) => false,
+        })
+        .count();
+    same_site * 2 >= hrefs.len()
+}
+
+/// Least amount of normalized text for a block to be treated as a pull-quote
+const MIN_PULL_QUOTE_LEN: usize = 60;
+
+/// Remove decorative pull-quotes that repeat a sentence of the article
+///
+/// Candidates are blockquotes, asides and elements whose class or id marks
+/// them as a pull-quote. One is removed only when its whole text, compared
+/// case-insensitively and ignoring punctuation, appears in a paragraph outside
+/// of it, so a quote the author goes on to discuss is kept.
+fn remove_pull_quotes(root: &NodeRef) {
+    static PULL_QUOTE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)pull[-_]?(?:quote|out)").unwrap());
+
+    let is_candidate = |node: &NodeRef| {
+        node_has_tag(node, "blockquote")
+            || node_has_tag(node, "aside")
+            || ["class", "id"].iter().any(|name| {
+                get_attribute(node, name).is_some_and(|value| PULL_QUOTE_REGEX.is_match(&value))
+            })
+    };
+    let candidates: Vec<NodeRef> = root
+        .descendants()
+        .filter(|node| node.as_element().is_some() && is_candidate(node))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let paragraphs: Vec<String> = select_nodes(root, "p")
+        .iter()
+        .filter(|p| !is_candidate(p) && !has_ancestor(p, is_candidate))
+        .map(|p| format!(" {} ", normalize_quote_text(&p.text_contents())))
+        .collect();
+
+    for candidate in candidates {
+        if !candidate.ancestors().any(|ancestor| ancestor == *root) {
+            continue;
+        }
+        let text = normalize_quote_text(&candidate.text_contents());
+        if text.len() < MIN_PULL_QUOTE_LEN {
+            continue;
+        }
+        let needle = format!(" {text} ");
+        if paragraphs
+            .iter()
+            .any(|paragraph| paragraph.contains(&needle))
+        {
+            candidate.detach();
+        }
+    }
+}
+
+/// Lowercase words of a text joined by single spaces, without punctuation
+fn normalize_quote_text(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Least amount of text that must precede a comment region before it is cut
+const MIN_PROSE_BEFORE_COMMENTS: usize = 500;
+
+/// Cut the article at a reader-comments region that follows the main prose
+///
+/// Comment regions are containers recognized by their id or class (`comments`,
+/// `comment-list`, `disqus_thread`, `respond`, ...) or a heading such as
+/// "12 Comments". The region and everything after it is removed, but only when
+/// enough prose comes before it; on forum threads the comments are the content
+/// and nothing precedes them.
+fn truncate_trailing_comments(root: &NodeRef) {
+    static COMMENTS_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)^(?:comments|comment-?list|comments[-_](?:area|list|section|wrapper|container)|disqus_thread|respond)$",
+        )
+        .unwrap()
+    });
+    static COMMENTS_HEADING_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)^(?:(?:\d+|no|one)\s+(?:comments?|responses?|replies)|comments|leave a (?:comment|reply))\W*$",
+        )
+        .unwrap()
+    });
+
+    let region = root.descendants().find(|node| {
+        let Some(element) = node.as_element() else {
+            return false;
+        };
+        let tag = element.name.local.as_ref();
+        let attrs = element.attributes.borrow();
+        let is_container = matches!(tag, "div" | "section" | "aside" | "ol" | "ul");
+        let named = is_container
+            && ["id", "class"].iter().any(|name| {
+                attrs.get(*name).is_some_and(|value| {
+                    value
+                        .split_whitespace()
+                        .any(|token| COMMENTS_REGEX.is_match(token))
+                })
+            });
+        let is_heading = matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+            && COMMENTS_HEADING_REGEX.is_match(node.text_contents().trim());
+        (named || is_heading) && text_before(root, node) >= MIN_PROSE_BEFORE_COMMENTS
+    });
+    let Some(region) = region else {
+        return;
+    };
+
+    let mut node = region.clone();
+    while node != *root {
+        let Some(parent) = node.parent() else {
+            break;
+        };
+        let following: Vec<NodeRef> = node.following_siblings().collect();
+        for sibling in following {
+            sibling.detach();
+        }
+        if node == region {
+            node.detach();
+        }
+        node = parent;
+    }
+}
+
+/// Length of the trimmed text that comes before `target` in document order
+fn text_before(root: &NodeRef, target: &NodeRef) -> usize {
+    let mut len = 0;
+    for edge in root.traverse() {
+        match edge {
+            NodeEdge::Start(node) if node == *target => break,
+            NodeEdge::Start(node) => {
+                if let Some(text) = node.as_text() {
+                    len += text.borrow().trim().len();
+                }
+            }
+            NodeEdge::End(_) => {}
+        }
+    }
+    len
+}
+
+/// Remove blocks whose whole text is an ad label such as "Advertisement"
+///
+/// The text is compared case-insensitively against `AD_LABELS` and the extra
+/// labels from the options, ignoring punctuation around it, so a sentence that
+/// merely mentions an advertisement is kept.
+fn remove_ad_labels(root: &NodeRef, extra_labels: &[String]) {
+    for node in select_nodes(root, "p, div, section, aside, span, small") {
+        if !node.ancestors().any(|ancestor| ancestor == *root) {
+            continue;
+        }
+        let text = node.text_contents();
+        let label = text
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if label.is_empty() {
+            continue;
+        }
+        let is_label = AD_LABELS.contains(&label.as_str())
+            || extra_labels
+                .iter()
+                .any(|extra| extra.trim().to_lowercase() == label);
+        if is_label {
+            node.detach();
+        }
+    }
+}
+
+/// Remove infinite-scroll placeholders, skeleton loaders and "Load more" controls
+///
+/// An element is removed when its class or id marks it as a placeholder,
+/// skeleton or spinner and it holds no text beyond a loading message, or when
+/// its whole text is a loading message or a "Load more"/"Show more" prompt.
+/// Elements holding a real image or video are kept.
+fn remove_loading_placeholders(root: &NodeRef) {
+    static LOADING_CLASS_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)placeholder|skeleton|shimmer|loading|loader|spinner").unwrap()
+    });
+    static LOADING_TEXT_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)^(?:loading|(?:load|show|see|view) more)(?:\s+(?:more\s+)?(?:stories|articles|posts|news|results|content))?\s*(?:…|\.\.\.|\.)?$",
+        )
+        .unwrap()
+    });
+    const MEDIA_TAGS: &[&str] = &[
+        "img", "picture", "video", "audio", "iframe", "object", "embed",
+    ];
+
+    let is_loading_text =
+        |text: &str| LOADING_TEXT_REGEX.is_match(text) || REGEXPS.loading_words.is_match(text);
+    let is_marked = |node: &NodeRef| {
+        ["class", "id"].iter().any(|name| {
+            get_attribute(node, name).is_some_and(|value| LOADING_CLASS_REGEX.is_match(&value))
+        })
+    };
+
+    let candidates: Vec<NodeRef> = root
+        .descendants()
+        .filter(|node| {
+            node.as_element().is_some()
+                && node != root
+                && !MEDIA_TAGS.iter().any(|tag| node_has_tag(node, tag))
+        })
+        .collect();
+    for node in candidates {
+        if !node.ancestors().any(|ancestor| ancestor == *root) {
+            continue;
+        }
+        let text = node.text_contents();
+        let text = text.trim();
+        let placeholder = is_marked(&node) && (text.is_empty() || is_loading_text(text));
+        if !placeholder && (text.is_empty() || !is_loading_text(text)) {
+            continue;
+        }
+        let has_media = select_nodes(&node, &MEDIA_TAGS.join(", "))
+            .iter()
+            .any(|media| !is_marked(media));
+        if !has_media {
+            node.detach();
+        }
+    }
+}
+
+/// Remove tracking pixels and other images that are not meant to be seen
+///
+/// Images whose `src` points at a known beacon endpoint are always dropped.
+/// Images declared or styled at 2px or less, tiny data URIs and `aria-hidden`
+/// images without alt text are dropped unless they sit inside a sentence,
+/// where they are usually emoji or icons. Lazy-load placeholders that carry
+/// the real image in another attribute are kept.
+fn remove_tracking_images(root: &NodeRef) {
+    static BEACON_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)facebook\.com/tr\b|google-analytics\.com/(?:collect|r/collect)|/(?:pixel|beacon|spacer|1x1|blank)(?:\.(?:gif|png|php))?(?:[/?#]|$)|/(?:track|tracking)/open\b",
+        )
+        .unwrap()
+    });
+
+    for image in select_nodes(root, "img") {
+        let src = get_attribute(&image, "src").unwrap_or_default();
+        if BEACON_REGEX.is_match(&src) {
+            image.detach();
+            continue;
+        }
+
+        let tiny = ["width", "height"]
+            .iter()
+            .any(|name| image_dimension(&image, name).is_some_and(|size| size <= 2.0));
+        let tiny_data_uri =
+            classify_url(&src) == UrlKind::Special(SpecialScheme::Data) && src.len() < 130;
+        let hidden = get_attribute(&image, "aria-hidden").as_deref() == Some("true")
+            && get_attribute(&image, "alt").is_none_or(|alt| alt.trim().is_empty());
+        if !(tiny || tiny_data_uri || hidden) || has_lazy_image_source(&image) {
+            continue;
+        }
+
+        if !is_in_sentence(&image) {
+            image.detach();
+        }
+    }
+}
+
+/// Whether an inline element sits next to text within its paragraph
+fn is_in_sentence(node: &NodeRef) -> bool {
+    let mut current = node.clone();
+    loop {
+        let has_text_sibling = current
+            .preceding_siblings()
+            .chain(current.following_siblings())
+            .any(|sibling| {
+                sibling
+                    .as_text()
+                    .is_some_and(|text| !text.borrow().trim().is_empty())
+            });
+        if has_text_sibling {
+            return true;
+        }
+        match current.parent() {
+            Some(parent) if node_has_inline_tag(&parent) => current = parent,
+            _ => return false,
+        }
+    }
+}
+
+fn node_has_inline_tag(node: &NodeRef) -> bool {
+    ["a", "span", "em", "strong", "b", "i", "small", "sup", "sub"]
+        .iter()
+        .any(|tag| node_has_tag(node, tag))
+}
+
+/// Whether a placeholder image carries its real source in another attribute
+fn has_lazy_image_source(image: &NodeRef) -> bool {
+    image.as_element().is_some_and(|element| {
+        element.attributes.borrow().map.iter().any(|(name, attr)| {
+            name.local.as_ref() != "src"
+                && IMAGE_EXTENSION_REGEX.is_match(&attr.value)
+                && classify_url(&attr.value) != UrlKind::Invalid
+        })
+    })
+}
+
+/// Declared or inline-styled size of an image in pixels
+fn image_dimension(image: &NodeRef, name: &str) -> Option<f64> {
+    static SIZE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\s*(\d+(?:\.\d+)?)\s*(?:px)?\s*$").unwrap());
+
+    let style = get_attribute(image, "style").unwrap_or_default();
+    let styled = style.split(';').find_map(|declaration| {
+        let (property, value) = declaration.split_once(':')?;
+        if property.trim().eq_ignore_ascii_case(name) {
+            Some(value.to_string())
+        } else {
+            None
+        }
+    });
+
+    styled
+        .or_else(|| get_attribute(image, name))
+        .and_then(|value| {
+            SIZE_REGEX
+                .captures(&value)
+                .and_then(|caps| caps[1].parse().ok())
+        })
+}
+
+/// Make native `<audio>` and `<video>` players usable in reader output
+///
+/// Lazy-loaded sources are promoted to `src`, `controls` is ensured and
+/// `autoplay`/`muted` are dropped so nothing starts playing on its own.
+fn clean_media_elements(root: &NodeRef) {
+    for media in select_nodes(root, "audio, video") {
+        for node in media.inclusive_descendants() {
+            if !(node_has_tag(&node, "source") || node == media) {
+                continue;
+            }
+            if let Some(element) = node.as_element() {
+                let mut attrs = element.attributes.borrow_mut();
+                if !attrs.contains("src") {
+                    if let Some(lazy_src) = attrs.get("data-src").map(str::to_string) {
+                        attrs.insert("src", lazy_src);
+                    }
+                }
+            }
+        }
+
+        if let Some(element) = media.as_element() {
+            let mut attrs = element.attributes.borrow_mut();
+            attrs.remove("autoplay");
+            attrs.remove("muted");
+            if !attrs.contains("controls") {
+                attrs.insert("controls", String::new());
+            }
+        }
+    }
+}
+
+/// Reduce allowed video embeds to a plain `<iframe>` in a minimal wrapper
+///
+/// Only `src`, `width`, `height` and `title` are kept, plus `allowfullscreen`.
+/// Chains of wrappers holding nothing but the embed collapse into one `<div>`.
+fn clean_video_embeds(root: &NodeRef, video_regex: &Regex) {
+    for embed in select_nodes(root, "iframe[src], embed[src]") {
+        let Some(src) = get_attribute(&embed, "src") else {
+            continue;
+        };
+        if !video_regex.is_match(&src) {
+            continue;
+        }
+        let Some(iframe) = create_element("iframe") else {
+            return;
+        };
+        if let Some(element) = iframe.as_element() {
+            let mut attrs = element.attributes.borrow_mut();
+            attrs.insert("src", src);
+            for name in ["width", "height", "title"] {
+                if let Some(value) = get_attribute(&embed, name) {
+                    attrs.insert(name, value);
+                }
+            }
+            attrs.insert("allowfullscreen", String::new());
+        }
+
+        let mut outer = embed.clone();
+        while let Some(parent) = outer.parent() {
+            let is_wrapper = node_has_tag(&parent, "div") || node_has_tag(&parent, "span");
+            if !is_wrapper || !is_only_content(&parent, &outer) {
+                break;
+            }
+            outer = parent;
+        }
+
+        if outer == embed {
+            embed.insert_before(iframe);
+            embed.detach();
+        } else if let Some(wrapper) = create_element("div") {
+            wrapper.append(iframe);
+            outer.insert_before(wrapper);
+            outer.detach();
+        }
+    }
+}
+
+/// Whether `child` is the only element or text inside `parent`
+fn is_only_content(parent: &NodeRef, child: &NodeRef) -> bool {
+    parent.children().all(|sibling| {
+        sibling == *child
+            || sibling.as_comment().is_some()
+            || sibling
+                .as_text()
+                .is_some_and(|text| text.borrow().trim().is_empty())
+    })
+}
+
+/// Final attribute cleanup, run once the article content is settled
+///
+/// This implements Mozilla's _cleanClasses: class names are removed unless
+/// `keep_classes` is set or they are listed in `classes_to_preserve`.
+/// `language-*` and `lang-*` hints on `<pre>` and `<code>` are always kept.
+/// With `strip_styles`, Mozilla's _cleanStyles removes presentational
+/// attributes as well.
+pub fn clean_attributes(html: &str, options: &ReadabilityOptions) -> String {
+    let strip_classes = !options.keep_classes && html.contains("class=");
+    let strip_data = options.strip_data_attributes && html.contains("data-");
+    if !strip_classes && !options.strip_styles && !strip_data {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let (target_node, children_only) = body_or_document(&document);
+    if strip_classes {
+        clean_classes(&target_node, &options.classes_to_preserve);
+    }
+    if options.strip_styles {
+        clean_styles(&target_node);
+        unwrap_presentational_wrappers(&target_node);
+    }
+    if strip_data {
+        clean_data_attributes(&target_node, &options.data_attributes_to_preserve);
+    }
+    serialize_node(&target_node, children_only, html.len())
+}
+
+/// Remove `data-*` attributes other than the preserved ones
+///
+/// Runs last, so passes that read data attributes (lazy image promotion,
+/// data table marking) have already consumed them.
+fn clean_data_attributes(root: &NodeRef, preserve: &[String]) {
+    for node in root.inclusive_descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        element.attributes.borrow_mut().map.retain(|name, _| {
+            let local: &str = name.local.as_ref();
+            !local.to_ascii_lowercase().starts_with("data-")
+                || preserve.iter().any(|keep| keep.eq_ignore_ascii_case(local))
+        });
+    }
+}
+
+/// Remove presentational attributes, leaving SVG subtrees alone
+fn clean_styles(root: &NodeRef) {
+    for node in root.descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        if node_has_tag(&node, "svg")
+            || has_ancestor(&node, |ancestor| node_has_tag(ancestor, "svg"))
+        {
+            continue;
+        }
+
+        let strip_size = DEPRECATED_SIZE_ATTRIBUTE_ELEMS.contains(&element.name.local.as_ref());
+        let mut attrs = element.attributes.borrow_mut();
+        for name in PRESENTATIONAL_ATTRIBUTES {
+            attrs.remove(*name);
+        }
+        if strip_size {
+            attrs.remove("width");
+            attrs.remove("height");
+        }
+    }
+}
+
+/// Unwrap `<span>` and `<font>` wrappers left without meaningful attributes
+///
+/// Runs after style and class cleanup, so a wrapper with only presentational
+/// attributes (or none) is replaced by its children. Wrappers that keep an
+/// `id`, `lang`, `title`, preserved class and so on stay in place.
+fn unwrap_presentational_wrappers(root: &NodeRef) {
+    for node in select_nodes(root, &PRESENTATIONAL_WRAPPER_ELEMS.join(", ")) {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let is_presentational = element.attributes.borrow().map.keys().all(|name| {
+            let local: &str = name.local.as_ref();
+            PRESENTATIONAL_ATTRIBUTES.contains(&local) || FONT_ATTRIBUTES.contains(&local)
+        });
+        if is_presentational {
+            unwrap_node(&node);
+        }
+    }
+}
+
+/// Remove scripting from the article content
+///
+/// Drops `<script>` and `<style>` elements, inline event handler attributes
+/// and URL attributes that would run code (`javascript:`, `vbscript:` and
+/// `data:text/html`), so the output can be embedded safely. URL attributes
+/// include `srcset` candidates, `<object data>` and the values an SVG
+/// `<animate>` or `<set>` writes into another attribute.
+pub fn sanitize_content(html: &str) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let (target_node, children_only) = body_or_document(&document);
+
+    for node in select_nodes(&target_node, "script, style") {
+        node.detach();
+    }
+
+    for node in target_node.inclusive_descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let tag = element.name.local.to_ascii_lowercase();
+        element.attributes.borrow_mut().map.retain(|name, attr| {
+            let local = name.local.to_ascii_lowercase();
+            if local.starts_with("on") {
+                return false;
+            }
+            let value = attr.value.as_str();
+            let runs_code = match (tag.as_ref(), local.as_ref()) {
+                (_, "href" | "src" | "action" | "formaction" | "poster") | ("object", "data") => {
+                    is_scripting_url(value)
+                }
+                (_, "srcset") => value.split(',').any(is_scripting_url),
+                ("animate" | "set", "to" | "from") => is_scripting_url(value),
+                ("animate" | "set", "values") => value.split(';').any(is_scripting_url),
+                _ => false,
+            };
+            !runs_code
+        });
+    }
+
+    serialize_node(&target_node, children_only, html.len())
+}
+
+/// Whether a URL would run code when followed or loaded
+fn is_scripting_url(url: &str) -> bool {
+    // Browsers ignore whitespace and control characters inside the scheme
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    url.starts_with("javascript:")
+        || url.starts_with("vbscript:")
+        || url.starts_with("data:text/html")
+}
+
+/// How many leading blocks of the content may hold a repeated byline
+const MAX_BYLINE_DUPLICATE_BLOCKS: usize = 12;
+
+/// Remove a block near the top of the content that only repeats the byline
+///
+/// The first few blocks are checked with `is_byline_duplicate`, so a byline
+/// followed by a date ("By Jane Doe, March 3") goes, while a sentence naming
+/// the author stays. At most one block is removed.
+pub fn remove_byline_duplicate(html: &str, byline: &str, words: &BylineWords) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let (target_node, children_only) = body_or_document(&document);
+
+    let blocks = select_nodes(&target_node, "p, div, span, address, h4, h5, h6");
+    if let Some(duplicate) = blocks
+        .into_iter()
+        .filter(|block| !block.text_contents().trim().is_empty())
+        .take(MAX_BYLINE_DUPLICATE_BLOCKS)
+        .find(|block| is_byline_duplicate(&block.text_contents(), byline, words))
+    {
+        duplicate.detach();
+    }
+
+    serialize_node(&target_node, children_only, html.len())
+}
+
+/// Normalize the heading outline of the article content
+///
+/// A heading that opens the content and repeats `title` (ignoring case and
+/// punctuation, with the fuzziness of Mozilla's _headerDuplicatesTitle) is
+/// removed. The remaining headings are shifted so the top level used is `<h2>`,
+/// preserving their relative depth. Headings inside blockquotes and tables
+/// are not part of the outline and are left alone.
+pub fn normalize_headings(html: &str, title: Option<&str>) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let (target_node, children_only) = body_or_document(&document);
+
+    let outline = |root: &NodeRef| -> Vec<NodeRef> {
+        select_nodes(root, "h1, h2, h3, h4, h5, h6")
+            .into_iter()
+            .filter(|heading| {
+                !has_ancestor(heading, |ancestor| {
+                    node_has_tag(ancestor, "blockquote") || node_has_tag(ancestor, "table")
+                })
+            })
+            .collect()
+    };
+
+    if let (Some(title), Some(first)) = (title, outline(&target_node).first()) {
+        if text_before(&target_node, first) == 0
+            && text_similarity(title, &first.text_contents()) > 0.75
+        {
+            first.detach();
+        }
+    }
+
+    let headings = outline(&target_node);
+    let Some(top) = headings.iter().filter_map(heading_level).min() else {
+        return serialize_node(&target_node, children_only, html.len());
+    };
+    if top != 2 {
+        for heading in &headings {
+            let Some(level) = heading_level(heading) else {
+                continue;
+            };
+            let new_level = (level + 2).saturating_sub(top).clamp(2, 6);
+            if let Some(replacement) = create_element(&format!("h{new_level}")) {
+                if let (Some(old), Some(new)) = (heading.as_element(), replacement.as_element()) {
+                    *new.attributes.borrow_mut() = old.attributes.borrow().clone();
+                }
+                for child in heading.children() {
+                    replacement.append(child);
+                }
+                heading.insert_before(replacement);
+                heading.detach();
+            }
+        }
+    }
+
+    serialize_node(&target_node, children_only, html.len())
+}
+
+fn heading_level(node: &NodeRef) -> Option<usize> {
+    let element = node.as_element()?;
+    let name = element.name.local.as_ref();
+    name.strip_prefix('h')?.parse().ok()
+}
+
+/// Similarity of two texts, as in Mozilla's _textSimilarity
+///
+/// Both texts are lowercased and split on non-word characters; the result is
+/// one minus the share of `b`'s text made up of tokens that are not in `a`.
+fn text_similarity(a: &str, b: &str) -> f64 {
+    let tokenize = |text: &str| -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    let tokens_a = tokenize(a);
+    let tokens_b = tokenize(b);
+    if tokens_a.is_empty() || tokens_b.is_empty() {
+        return 0.0;
+    }
+
+    let unique_b: Vec<&String> = tokens_b
+        .iter()
+        .filter(|token| !tokens_a.contains(token))
+        .collect();
+    let unique_len =
+        unique_b.iter().map(|token| token.len()).sum::<usize>() + unique_b.len().saturating_sub(1);
+    let total_len = tokens_b.iter().map(|token| token.len()).sum::<usize>() + tokens_b.len() - 1;
+    1.0 - unique_len as f64 / total_len as f64
+}
+
+fn clean_classes(root: &NodeRef, classes_to_preserve: &[String]) {
+    for node in root.descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let is_code = matches!(element.name.local.as_ref(), "pre" | "code");
+        let mut attrs = element.attributes.borrow_mut();
+        let Some(class_attr) = attrs.get("class") else {
+            continue;
+        };
+
+        let kept = class_attr
+            .split_whitespace()
+            .filter(|class| {
+                classes_to_preserve
+                    .iter()
+                    .any(|preserved| preserved == class)
+                    || (is_code && is_language_class(class))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if kept.is_empty() {
+            attrs.remove("class");
+        } else {
+            attrs.insert("class", kept);
+        }
+    }
+}
+
+fn is_language_class(class: &str) -> bool {
+    class.starts_with("language-") || class.starts_with("lang-")
+}
+
+/// Flatten syntax-highlighter markup inside `<pre>` blocks
+///
+/// Token spans are unwrapped to their text and `<br>` line breaks become
+/// newlines, so the block keeps its exact whitespace and line structure.
+/// A `highlight-source-*` hint on a wrapping element (GitHub READMEs) is
+/// carried over as a `language-*` class.
+fn normalize_code_blocks(root: &NodeRef) {
+    static SOURCE_HINT_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?:^|\s)highlight-source-([\w+#-]+)").unwrap());
+
+    for pre in select_nodes(root, "pre") {
+        for br in select_nodes(&pre, "br") {
+            br.insert_before(NodeRef::new_text("\n"));
+            br.detach();
+        }
+        for span in select_nodes(&pre, "span") {
+            unwrap_node(&span);
+        }
+
+        let code = pre
+            .children()
+            .find(|child| node_has_tag(child, "code"))
+            .unwrap_or_else(|| pre.clone());
+        if has_language_class(&pre) || has_language_class(&code) {
+            continue;
+        }
+        let hint = pre.parent().and_then(|parent| {
+            let element = parent.as_element()?;
+            let attrs = element.attributes.borrow();
+            let class_attr = attrs.get("class")?;
+            SOURCE_HINT_REGEX
+                .captures(class_attr)
+                .map(|caps| format!("language-{}", &caps[1]))
+        });
+        if let (Some(hint), Some(element)) = (hint, code.as_element()) {
+            let mut attrs = element.attributes.borrow_mut();
+            let class_attr = match attrs.get("class") {
+                Some(existing) if !existing.trim().is_empty() => format!("{existing} {hint}"),
+                _ => hint,
+            };
+            attrs.insert("class", class_attr);
+        }
+    }
+}
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+const XMLNS_NAMESPACE: &str = "http://www.w3.org/2000/xmlns/";
+
+/// Keep inline SVG diagrams as standalone, script-free markup
+///
+/// Scripts and event handler attributes are removed and the namespace
+/// declarations are added so the subtree is valid SVG on its own. SVGs larger
+/// than `MAX_INLINE_SVG_ELEMENTS` are replaced by their `<title>` or
+/// `aria-label` text.
+fn clean_inline_svgs(root: &NodeRef) {
+    for svg in select_nodes(root, "svg") {
+        if has_ancestor(&svg, |ancestor| node_has_tag(ancestor, "svg")) {
+            continue;
+        }
+
+        if svg.descendants().elements().count() > MAX_INLINE_SVG_ELEMENTS {
+            let label = get_attribute(&svg, "aria-label").or_else(|| {
+                svg.children()
+                    .find(|child| node_has_tag(child, "title"))
+                    .map(|title| title.text_contents())
+            });
+            if let Some(label) = label.filter(|label| !label.trim().is_empty()) {
+                svg.insert_before(NodeRef::new_text(label.trim()));
+            }
+            svg.detach();
+            continue;
+        }
+
+        for script in select_nodes(&svg, "script") {
+            script.detach();
+        }
+
+        let mut uses_xlink = false;
+        for node in svg.inclusive_descendants() {
+            let Some(element) = node.as_element() else {
+                continue;
+            };
+            let mut attrs = element.attributes.borrow_mut();
+            attrs
+                .map
+                .retain(|name, _| !name.local.to_ascii_lowercase().starts_with("on"));
+            uses_xlink |= attrs
+                .map
+                .keys()
+                .any(|name| name.ns.as_ref() == XLINK_NAMESPACE);
+        }
+
+        if let Some(element) = svg.as_element() {
+            // The parser files source declarations under the xmlns namespace, as
+            // `xmlns` and `xlink`, rather than under their written names
+            let mut attrs = element.attributes.borrow_mut();
+            let has_xmlns = attrs.map.keys().any(|name| name.local.as_ref() == "xmlns");
+            let has_xlink = attrs.map.keys().any(|name| {
+                name.local.as_ref() == "xmlns:xlink"
+                    || (name.ns.as_ref() == XMLNS_NAMESPACE && name.local.as_ref() == "xlink")
+            });
+            if !has_xmlns {
+                attrs.insert("xmlns", SVG_NAMESPACE.to_string());
+            }
+            if uses_xlink && !has_xlink {
+                attrs.insert("xmlns:xlink", XLINK_NAMESPACE.to_string());
+            }
+        }
+    }
+}
+
+fn has_language_class(node: &NodeRef) -> bool {
+    node.as_element().is_some_and(|element| {
+        element
+            .attributes
+            .borrow()
+            .get("class")
+            .is_some_and(|class_attr| class_attr.split_whitespace().any(is_language_class))
+    })
+}
+
+/// Replace a node with its children
+///
+/// Semantic inline elements such as `<sup>` or `<abbr>` are left in place.
+fn unwrap_node(node: &NodeRef) {
+    if node
+        .as_element()
+        .is_some_and(|element| SEMANTIC_INLINE_ELEMS.contains(&element.name.local.as_ref()))
+    {
+        return;
+    }
+    for child in node.children().collect::<Vec<_>>() {
+        node.insert_before(child);
+    }
+    node.detach();
+}
+
+fn body_or_document(document: &NodeRef) -> (NodeRef, bool) {
+    match document.select_first("body") {
+        Ok(body) => (body.as_node().clone(), true),
+        Err(()) => (document.clone(), false),
+    }
+}
+
+/// Serialize a node, or only its children, to HTML
+///
+/// `size_hint` is the byte length of the markup the node was parsed from.
+/// Cleaning mostly removes markup, so sizing the buffer from it up front
+/// spares large articles the repeated regrowth of an empty buffer.
+fn serialize_node(node: &NodeRef, children_only: bool, size_hint: usize) -> String {
+    let mut buffer = Vec::with_capacity(size_hint);
+
+    if children_only {
+        let children: Vec<_> = node.children().collect();
+        for child in children {
+            if child.serialize(&mut buffer).is_err() {
+                return node.text_contents();
+            }
+        }
+    } else if node.serialize(&mut buffer).is_err() {
+        return node.text_contents();
+    }
+
+    String::from_utf8(buffer).unwrap_or_else(|_| node.text_contents())
+}
+
+fn select_nodes(root: &NodeRef, selector: &str) -> Vec<NodeRef> {
+    root.select(selector)
+        .map(|matches| matches.map(|m| m.as_node().clone()).collect())
+        .unwrap_or_default()
+}
+
+/// Create a detached element by letting the parser build it
+fn create_element(tag: &str) -> Option<NodeRef> {
+    let document = kuchikikiki::parse_html().one(format!("<{tag}></{tag}>"));
+    let element = document.select_first(tag).ok()?.as_node().clone();
+    element.detach();
+    Some(element)
+}
+
+static CAPTION_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(^|[\s_-])(caption|credit)").unwrap());
+
+/// Wrap a standalone image and the caption paragraph right after it into a
+/// `<figure>` so the two stay together through conditional cleaning.
+fn wrap_captioned_images(root: &NodeRef) {
+    for image in select_nodes(root, "img") {
+        if image.parent().is_none()
+            || has_ancestor(&image, |ancestor| node_has_tag(ancestor, "figure"))
+        {
+            continue;
+        }
 
-    let mut result = NAV_REGEX.replace_all(html, "").to_string();
+        let mut holder = image.clone();
+        while let Some(parent) = holder.parent() {
+            if parent.as_element().is_none()
+                || node_has_tag(&parent, "body")
+                || !is_single_image(&parent)
+            {
+                break;
+            }
+            holder = parent;
+        }
 
-    let tags = ["div", "section", "ul", "ol"];
-    let keywords = ["nav", "navbar", "menu", "breadcrumbs", "sidebar", "widget"];
+        let Some(caption) = holder
+            .following_siblings()
+            .find(|sibling| sibling.as_element().is_some())
+        else {
+            continue;
+        };
+        if !is_caption_paragraph(&caption) {
+            continue;
+        }
 
-    for tag in tags {
-        for keyword in keywords {
-            let class_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?class="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&class_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
+        let (Some(figure), Some(figcaption)) =
+            (create_element("figure"), create_element("figcaption"))
+        else {
+            return;
+        };
+        holder.insert_before(figure.clone());
+        image.detach();
+        figure.append(image);
+        holder.detach();
 
-            let id_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?id="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&id_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
+        for child in caption.children().collect::<Vec<_>>() {
+            figcaption.append(child);
         }
+        caption.detach();
+        figure.append(figcaption);
     }
-
-    result
 }
 
-fn remove_conditionally(html: &str) -> String {
-    remove_conditionally_dom(html).unwrap_or_else(|| remove_conditionally_regex(html))
+fn is_caption_paragraph(node: &NodeRef) -> bool {
+    if !node_has_tag(node, "p") && !node_has_tag(node, "div") {
+        return false;
+    }
+    let text_len = node.text_contents().trim().chars().count();
+    if text_len == 0 || text_len > 300 || node.select_first("img").is_ok() {
+        return false;
+    }
+    node.as_element().is_some_and(|element| {
+        let attrs = element.attributes.borrow();
+        ["class", "id"]
+            .iter()
+            .filter_map(|name| attrs.get(*name))
+            .any(|value| CAPTION_REGEX.is_match(value))
+    })
 }
 
-fn remove_conditionally_dom(html: &str) -> Option<String> {
-    let document = kuchikikiki::parse_html().one(html);
-    let body_node = document
-        .select("body")
-        .ok()
-        .and_then(|mut iter| iter.next())
-        .map(|node| node.as_node().clone());
+/// Whether the node wraps a figure with media whose caption makes up its text
+fn holds_captioned_figure(node: &NodeRef, text_len: usize) -> bool {
+    let figures: Vec<NodeRef> = select_nodes(node, "figure")
+        .into_iter()
+        .filter(|figure| figure.select_first("img, picture, video, svg").is_ok())
+        .collect();
+    if figures.is_empty() {
+        return false;
+    }
+    let figure_text_len: usize = figures
+        .iter()
+        .map(|figure| figure.text_contents().trim().len())
+        .sum();
+    figure_text_len as f64 >= text_len as f64 * 0.9
+}
 
-    let (target_node, children_only) = if let Some(body) = body_node.clone() {
-        (body, true)
-    } else {
-        (document.clone(), false)
-    };
-    mark_data_tables(&target_node);
+/// Whether the node groups a term with a non-empty description inside a `<dl>`
+fn is_definition_group(node: &NodeRef) -> bool {
+    node.parent()
+        .is_some_and(|parent| node_has_tag(&parent, "dl"))
+        && select_nodes(node, "dd")
+            .iter()
+            .any(|dd| !dd.text_contents().trim().is_empty())
+}
 
-    let cleanup_tags = ["form", "fieldset", "table", "ul", "ol", "div", "section"];
-    for tag in cleanup_tags {
-        clean_conditionally_tag(&target_node, tag);
+/// Whether the node holds the `<cite>` attribution of a quote it is in or follows
+fn is_quote_attribution(node: &NodeRef, text_len: usize) -> bool {
+    let cite_len: usize = select_nodes(node, "cite")
+        .iter()
+        .map(|cite| cite.text_contents().trim().len())
+        .sum();
+    if cite_len == 0 || (cite_len as f64) < text_len as f64 * 0.9 {
+        return false;
     }
-
-    Some(serialize_node(&target_node, children_only))
+    has_ancestor(node, |ancestor| node_has_tag(ancestor, "blockquote"))
+        || node
+            .preceding_siblings()
+            .find(|sibling| sibling.as_element().is_some())
+            .is_some_and(|sibling| {
+                node_has_tag(&sibling, "blockquote")
+                    || !select_nodes(&sibling, "blockquote").is_empty()
+            })
 }
 
-fn serialize_node(node: &NodeRef, children_only: bool) -> String {
-    let mut buffer = Vec::new();
+/// Whether the node is, or mostly consists of, a footnote or endnote list
+fn holds_footnote_list(node: &NodeRef, text_len: usize) -> bool {
+    let mut lists = select_nodes(node, "ol, ul");
+    if node_has_tag(node, "ol") || node_has_tag(node, "ul") {
+        lists.push(node.clone());
+    }
+    lists
+        .iter()
+        .any(|list| list.text_contents().trim().len() * 2 >= text_len && is_footnote_list(list))
+}
 
-    if children_only {
-        let children: Vec<_> = node.children().collect();
-        for child in children {
-            if child.serialize(&mut buffer).is_err() {
-                return node.text_contents();
-            }
-        }
-    } else if node.serialize(&mut buffer).is_err() {
-        return node.text_contents();
+/// Check for a list of notes that link back into the text and hold prose
+///
+/// Most items must either carry an `id` or link to an in-page anchor, and have
+/// some text besides those back-links. A table of contents made only of
+/// anchor links does not qualify.
+fn is_footnote_list(list: &NodeRef) -> bool {
+    let items: Vec<NodeRef> = list
+        .children()
+        .filter(|child| node_has_tag(child, "li"))
+        .collect();
+    if items.is_empty() {
+        return false;
     }
 
-    String::from_utf8(buffer).unwrap_or_else(|_| node.text_contents())
+    let notes = items
+        .iter()
+        .filter(|item| {
+            let back_links = select_nodes(item, "a[href^='#']");
+            if get_attribute(item, "id").is_none() && back_links.is_empty() {
+                return false;
+            }
+            let back_link_len: usize = back_links
+                .iter()
+                .map(|link| link.text_contents().trim().len())
+                .sum();
+            item.text_contents()
+                .trim()
+                .len()
+                .saturating_sub(back_link_len)
+                >= 10
+        })
+        .count();
+    notes * 2 >= items.len()
 }
 
-fn clean_conditionally_tag(root: &NodeRef, tag: &str) {
+fn clean_conditionally_tag(root: &NodeRef, tag: &str, video_regex: &Regex) {
     if let Ok(matches) = root.select(tag) {
         let nodes: Vec<_> = matches
             .map(|css_match| css_match.as_node().clone())
             .collect();
         for node in nodes {
-            if should_remove_dom_node(&node, tag) {
+            if should_remove_dom_node(&node, tag, video_regex) {
                 node.detach();
             }
         }
     }
 }
 
-fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
+fn should_remove_dom_node(node: &NodeRef, tag: &str, video_regex: &Regex) -> bool {
     let trimmed = node.text_contents().trim().to_string();
     if trimmed.len() > 600 {
         return false;
@@ -173,12 +1847,21 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
         return true;
     }
 
+    if holds_captioned_figure(node, trimmed.len())
+        || holds_footnote_list(node, trimmed.len())
+        || is_quote_attribution(node, trimmed.len())
+        || is_definition_group(node)
+    {
+        return false;
+    }
+
     if trimmed.matches(',').count() >= 10 {
         return false;
     }
 
     let p = count_descendants(node, "p");
     let img = count_descendants(node, "img");
+    let inline_media = count_descendants(node, "math, svg, audio, video");
     let li = count_descendants(node, "li").saturating_sub(100);
     let input = count_descendants(node, "input");
     let heading_density = get_text_density(node, &["h1", "h2", "h3", "h4", "h5", "h6"]);
@@ -187,7 +1870,7 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
     if let Ok(embeds) = node.select("object, embed, iframe") {
         for embed in embeds {
             let embed_node = embed.as_node();
-            if node_has_allowed_video(embed_node) {
+            if node_has_allowed_video(embed_node, video_regex) {
                 return false;
             }
             embed_count += 1;
@@ -236,7 +1919,7 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
     if (embed_count == 1 && content_length < 75) || embed_count > 1 {
         should_remove = true;
     }
-    if img == 0 && text_density == 0.0 {
+    if img == 0 && inline_media == 0 && text_density == 0.0 {
         should_remove = true;
     }
 
@@ -264,15 +1947,22 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
 
 fn dom_link_density(node: &NodeRef, text_len: usize) -> f64 {
     if text_len == 0 {
-        return 1.0;
+        return 0.0;
     }
 
     if let Ok(links) = node.select("a") {
-        let mut link_length = 0;
+        let mut link_length = 0.0;
         for link in links {
-            link_length += link.as_node().text_contents().len();
+            // In-page anchors count for less, as in Mozilla's _getLinkDensity
+            let coefficient = link
+                .attributes
+                .borrow()
+                .get("href")
+                .filter(|href| REGEXPS.hash_url.is_match(href))
+                .map_or(1.0, |_| 0.3);
+            link_length += link.as_node().text_contents().len() as f64 * coefficient;
         }
-        link_length as f64 / text_len as f64
+        link_length / text_len as f64
     } else {
         0.0
     }
@@ -292,42 +1982,43 @@ fn mark_data_tables(root: &NodeRef) {
     }
 }
 
+/// Implements Mozilla's _markDataTables heuristic for a single table.
+///
+/// Only the table's own rows and cells are considered, so a layout table
+/// wrapping a data table is not mistaken for one.
 fn detect_data_table(table: &NodeRef) -> bool {
     if let Some(element) = table.as_element() {
         let attrs = element.attributes.borrow();
-        if matches!(attrs.get("role"), Some(role) if role == "presentation") {
-            return false;
+        match attrs.get("role") {
+            Some("presentation") | Some("none") => return false,
+            Some("table") | Some("grid") | Some("treegrid") => return true,
+            _ => {}
         }
-        if matches!(attrs.get("datatable"), Some(val) if val == "0") {
-            return false;
+        match attrs.get("datatable") {
+            Some("0") => return false,
+            Some(_) => return true,
+            None => {}
         }
         if attrs.get("summary").is_some() {
             return true;
         }
     }
 
-    if table
-        .select("caption")
-        .ok()
-        .and_then(|mut c| c.next())
-        .is_some()
-    {
+    let has_caption = own_table_descendants(table, "caption")
+        .iter()
+        .any(|caption| caption.first_child().is_some());
+    if has_caption {
         return true;
     }
 
     let has_data_descendant = ["col", "colgroup", "tfoot", "thead", "th"]
         .iter()
-        .any(|tag| table.select(tag).ok().and_then(|mut c| c.next()).is_some());
+        .any(|tag| !own_table_descendants(table, tag).is_empty());
     if has_data_descendant {
         return true;
     }
 
-    if table
-        .select("table")
-        .ok()
-        .and_then(|mut c| c.next())
-        .is_some()
-    {
+    if table.select_first("table").is_ok() {
         return false;
     }
 
@@ -344,28 +2035,142 @@ fn detect_data_table(table: &NodeRef) -> bool {
     rows * columns > 10
 }
 
+/// Descendants matching `selector` whose closest table is `table` itself
+fn own_table_descendants(table: &NodeRef, selector: &str) -> Vec<NodeRef> {
+    select_nodes(table, selector)
+        .into_iter()
+        .filter(|node| node.ancestors().find(is_table).as_ref() == Some(table))
+        .collect()
+}
+
+fn own_table_cells(row: &NodeRef) -> Vec<NodeRef> {
+    row.children()
+        .filter(|child| node_has_tag(child, "td") || node_has_tag(child, "th"))
+        .collect()
+}
+
 fn get_row_and_column_count(table: &NodeRef) -> (usize, usize) {
-    let mut rows = 0;
-    let mut columns = 0;
-    if let Ok(trs) = table.select("tr") {
-        for tr in trs {
-            rows += 1;
-            let cols = tr
-                .as_node()
-                .children()
-                .filter(|child| {
-                    if let Some(elem) = child.as_element() {
-                        let name = elem.name.local.as_ref().to_ascii_lowercase();
-                        name == "td" || name == "th"
-                    } else {
-                        false
-                    }
-                })
-                .count();
-            columns = columns.max(cols);
+    let rows = own_table_descendants(table, "tr");
+    let columns = rows
+        .iter()
+        .map(|row| own_table_cells(row).len())
+        .max()
+        .unwrap_or(0);
+    (rows.len(), columns)
+}
+
+/// Collapse runs of three or more `<br>` into a paragraph break
+///
+/// Inside a `<p>` the run splits the paragraph in two; elsewhere it is
+/// reduced to the `<br><br>` pair that marks a paragraph break.
+fn collapse_br_runs(root: &NodeRef) {
+    for br in select_nodes(root, "br") {
+        if br.parent().is_none() {
+            continue;
+        }
+        let previous = br
+            .preceding_siblings()
+            .find(|sibling| !is_whitespace_text(sibling));
+        if previous.is_some_and(|previous| node_has_tag(&previous, "br")) {
+            continue;
+        }
+
+        let mut run = vec![br.clone()];
+        let mut gaps = Vec::new();
+        for sibling in br.following_siblings() {
+            if is_whitespace_text(&sibling) {
+                gaps.push(sibling);
+            } else if node_has_tag(&sibling, "br") {
+                run.push(sibling);
+            } else {
+                break;
+            }
+        }
+        if run.len() < 3 {
+            continue;
+        }
+
+        let last = run[run.len() - 1].clone();
+        let parent = br.parent();
+        match (parent, create_element("p")) {
+            (Some(paragraph), Some(rest)) if node_has_tag(&paragraph, "p") => {
+                for sibling in last.following_siblings().collect::<Vec<_>>() {
+                    rest.append(sibling);
+                }
+                paragraph.insert_after(rest);
+                for node in run.iter().chain(&gaps) {
+                    node.detach();
+                }
+            }
+            _ => {
+                for node in run.iter().skip(2).chain(&gaps) {
+                    node.detach();
+                }
+            }
+        }
+    }
+}
+
+fn is_whitespace_text(node: &NodeRef) -> bool {
+    node.as_text()
+        .is_some_and(|text| text.borrow().trim().is_empty())
+}
+
+/// Remove paragraphs without text or media
+///
+/// Text counts as empty once entities are decoded and whitespace and soft
+/// spaces (`&nbsp;`, zero-width spaces) are trimmed, so `<p>&nbsp;</p>` and
+/// `<p><br></p>` go while `<p><img></p>` stays.
+fn remove_empty_paragraphs(root: &NodeRef) {
+    for paragraph in select_nodes(root, "p") {
+        let text = unescape_html_entities_owned(paragraph.text_contents());
+        if !trim_soft_space(text.trim()).trim().is_empty() {
+            continue;
+        }
+        if paragraph
+            .select_first("img, picture, iframe, video, audio, embed, object, svg, math, canvas")
+            .is_err()
+        {
+            paragraph.detach();
+        }
+    }
+}
+
+/// Replace layout tables with their cell contents, one `<div>` per cell.
+///
+/// Data tables keep their structure; the internal marker attribute is
+/// dropped from them once cleaning is done.
+fn unwrap_layout_tables(root: &NodeRef) {
+    for table in select_nodes(root, "table") {
+        if is_data_table(&table) {
+            if let Some(element) = table.as_element() {
+                element
+                    .attributes
+                    .borrow_mut()
+                    .remove("data-readability-datatable");
+            }
+            continue;
+        }
+        if table.parent().is_none() {
+            continue;
         }
+
+        for row in own_table_descendants(&table, "tr") {
+            for cell in own_table_cells(&row) {
+                if cell.text_contents().trim().is_empty() && cell.select_first("img").is_err() {
+                    continue;
+                }
+                let Some(wrapper) = create_element("div") else {
+                    return;
+                };
+                for child in cell.children().collect::<Vec<_>>() {
+                    wrapper.append(child);
+                }
+                table.insert_before(wrapper);
+            }
+        }
+        table.detach();
     }
-    (rows, columns)
 }
 
 fn set_data_table_flag(node: &NodeRef, is_data: bool) {
@@ -431,16 +2236,16 @@ fn count_descendants(node: &NodeRef, selector: &str) -> usize {
     node.select(selector).map(|iter| iter.count()).unwrap_or(0)
 }
 
-fn node_has_allowed_video(node: &NodeRef) -> bool {
+fn node_has_allowed_video(node: &NodeRef, video_regex: &Regex) -> bool {
     if let Some(element) = node.as_element() {
         let attrs = element.attributes.borrow();
         for (_, attribute) in attrs.map.iter() {
-            if REGEXPS.videos.is_match(&attribute.value) {
+            if video_regex.is_match(&attribute.value) {
                 return true;
             }
         }
     }
-    if node_has_tag(node, "object") && REGEXPS.videos.is_match(&node.text_contents()) {
+    if node_has_tag(node, "object") && video_regex.is_match(&node.text_contents()) {
         return true;
     }
     false
@@ -475,19 +2280,12 @@ fn get_dom_class_weight(node: &NodeRef) -> i32 {
     let mut weight = 0;
     if let Some(element) = node.as_element() {
         let attrs = element.attributes.borrow();
-        if let Some(class) = attrs.get("class") {
-            if REGEXPS.negative.is_match(class) {
-                weight -= 25;
-            }
-            if REGEXPS.positive.is_match(class) {
-                weight += 25;
-            }
-        }
-        if let Some(id) = attrs.get("id") {
-            if REGEXPS.negative.is_match(id) {
+        for value in [attrs.get("class"), attrs.get("id")].into_iter().flatten() {
+            let matches = REGEXPS.match_class_id(truncate_class_id(value));
+            if matches.negative {
                 weight -= 25;
             }
-            if REGEXPS.positive.is_match(id) {
+            if matches.positive {
                 weight += 25;
             }
         }
@@ -496,20 +2294,25 @@ fn get_dom_class_weight(node: &NodeRef) -> i32 {
 }
 
 fn remove_conditionally_regex(html: &str) -> String {
-    let mut result = html.to_string();
-    let cleanup_tags = ["table", "ul", "ol", "div", "section"];
+    static BLOCK_REGEXES: Lazy<Vec<(&str, Regex)>> = Lazy::new(|| {
+        ["table", "ul", "ol", "div", "section"]
+            .into_iter()
+            .map(|tag| {
+                let pattern = format!(r"(?is)<{tag}\b[^>]*?>.*?</{tag}>");
+                (tag, Regex::new(&pattern).unwrap())
+            })
+            .collect()
+    });
 
-    for tag in cleanup_tags {
-        result = remove_blocks_for_tag(&result, tag);
+    let mut result = html.to_string();
+    for (tag, re) in BLOCK_REGEXES.iter() {
+        result = remove_blocks_for_tag(&result, tag, re);
     }
 
     result
 }
 
-fn remove_blocks_for_tag(html: &str, tag: &str) -> String {
-    let pattern = format!(r"(?is)<{tag}\b[^>]*?>.*?</{tag}>");
-    let re = Regex::new(&pattern).unwrap();
-
+fn remove_blocks_for_tag(html: &str, tag: &str, re: &Regex) -> String {
     re.replace_all(html, |caps: &Captures| {
         let block = caps.get(0).map(|m| m.as_str()).unwrap_or_default();
         if should_remove_block(block, tag) {
@@ -727,6 +2530,10 @@ pub fn replace_brs(html: &str) -> String {
         if let Some((tag_name, attributes, inner_content, closing_tag)) = parse_element(trimmed) {
             if closing_tag == tag_name {
                 let processed_inner = replace_brs_in_content(inner_content);
+                // Paragraphs can't nest, so a split paragraph is replaced by its parts
+                if tag_name.eq_ignore_ascii_case("p") && processed_inner != inner_content {
+                    return processed_inner;
+                }
                 if attributes.is_empty() {
                     return format!("<{tag_name}>{processed_inner}</{tag_name}>");
                 } else {
@@ -761,53 +2568,598 @@ fn parse_element(html: &str) -> Option<(&str, &str, &str, &str)> {
 }
 
 /// Replace BRs in text/content (no wrapping element)
+///
+/// Only runs of two or more BRs at the top level of `content` split it into
+/// paragraphs; a single BR is an intentional line break, as in poetry or song
+/// lyrics, and is kept. Block children are processed recursively, while
+/// preformatted blocks and inline elements are left untouched so a break
+/// inside them never produces broken nesting.
 fn replace_brs_in_content(content: &str) -> String {
-    let br_regex = regex::Regex::new(r"(?i)(<br\s*/?>(\s|&nbsp;?)*){2,}").unwrap();
-    if !br_regex.is_match(content) {
+    static DOUBLE_BR_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)(<br\s*/?>(\s|&nbsp;?)*){2,}").unwrap());
+
+    if !DOUBLE_BR_REGEX.is_match(content) {
         return content.to_string();
     }
 
-    let parts: Vec<&str> = br_regex.split(content).collect();
-    let paragraphs: Vec<String> = parts
-        .iter()
-        .map(|p| p.trim())
-        .filter(|p| !p.is_empty())
-        .map(|p| format!("<p>{p}</p>"))
-        .collect();
+    let segments = split_top_level(content);
+    let is_separator = |segment: &BrSegment| match segment {
+        BrSegment::Text(text) => text.replace("&nbsp;", "").trim().is_empty(),
+        BrSegment::Br(_) => true,
+        BrSegment::Element { .. } => false,
+    };
+
+    // Group the segments into runs, each ending at a top-level double BR
+    let mut runs: Vec<&[BrSegment]> = Vec::new();
+    let mut run_start = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        let separator_len = segments[i..]
+            .iter()
+            .take_while(|segment| is_separator(segment))
+            .count();
+        let brs = segments[i..i + separator_len]
+            .iter()
+            .filter(|segment| matches!(segment, BrSegment::Br(_)))
+            .count();
+        if brs >= 2 && matches!(segments[i], BrSegment::Br(_)) {
+            runs.push(&segments[run_start..i]);
+            i += separator_len;
+            run_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    runs.push(&segments[run_start..]);
+    let split = runs.len() > 1;
+
+    let mut blocks: Vec<String> = Vec::new();
+    for run in runs {
+        let mut inline = String::new();
+        for segment in run {
+            match segment {
+                BrSegment::Text(html) | BrSegment::Br(html) => inline.push_str(html),
+                BrSegment::Element { tag, html } if is_phrasing_tag(tag) => inline.push_str(html),
+                BrSegment::Element { tag, html } => {
+                    flush_inline(&mut inline, &mut blocks, split);
+                    if tag == "pre" || !DOUBLE_BR_REGEX.is_match(html) {
+                        blocks.push(html.to_string());
+                    } else {
+                        blocks.push(replace_brs(html));
+                    }
+                }
+            }
+        }
+        flush_inline(&mut inline, &mut blocks, split);
+    }
+
+    blocks.join("\n")
+}
+
+/// Move a run of inline content into `blocks`, as a paragraph when splitting
+fn flush_inline(inline: &mut String, blocks: &mut Vec<String>, split: bool) {
+    if split {
+        let text = inline.trim();
+        if !text.is_empty() {
+            blocks.push(format!("<p>{text}</p>"));
+        }
+    } else if !inline.is_empty() {
+        blocks.push(inline.clone());
+    }
+    inline.clear();
+}
+
+/// Check whether a lowercase tag name is phrasing content
+fn is_phrasing_tag(tag: &str) -> bool {
+    matches!(tag, "a" | "del" | "ins" | "font" | "s" | "u" | "bdi")
+        || PHRASING_ELEMS.contains(&tag.to_ascii_uppercase().as_str())
+}
+
+/// A top-level piece of an HTML fragment
+enum BrSegment<'a> {
+    Text(&'a str),
+    Br(&'a str),
+    Element { tag: String, html: &'a str },
+}
+
+/// Split an HTML fragment into its top-level text runs, BRs and elements
+fn split_top_level(content: &str) -> Vec<BrSegment<'_>> {
+    static TAG_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?s)<!--.*?-->|<(/?)([a-zA-Z][\w:-]*)(?:\s[^>]*?)?(/?)>").unwrap()
+    });
+
+    let mut segments = Vec::new();
+    let mut depth = 0usize;
+    let mut element_start = 0;
+    let mut element_tag = String::new();
+    let mut text_start = 0;
+
+    for caps in TAG_REGEX.captures_iter(content) {
+        let whole = caps.get(0).unwrap();
+        if depth == 0 && whole.start() > text_start {
+            segments.push(BrSegment::Text(&content[text_start..whole.start()]));
+        }
+        let Some(name) = caps.get(2) else {
+            if depth == 0 {
+                segments.push(BrSegment::Text(whole.as_str()));
+                text_start = whole.end();
+            }
+            continue;
+        };
+        let tag = name.as_str().to_ascii_lowercase();
+        let closing = !caps[1].is_empty();
+        let self_closing = !caps[3].is_empty() || is_void_element(&tag);
+
+        if depth == 0 {
+            text_start = whole.end();
+            if closing {
+                segments.push(BrSegment::Text(whole.as_str()));
+            } else if tag == "br" {
+                segments.push(BrSegment::Br(whole.as_str()));
+            } else if self_closing {
+                segments.push(BrSegment::Element {
+                    tag,
+                    html: whole.as_str(),
+                });
+            } else {
+                depth = 1;
+                element_start = whole.start();
+                element_tag = tag;
+            }
+        } else if !self_closing {
+            if closing {
+                depth -= 1;
+            } else {
+                depth += 1;
+            }
+            if depth == 0 {
+                segments.push(BrSegment::Element {
+                    tag: std::mem::take(&mut element_tag),
+                    html: &content[element_start..whole.end()],
+                });
+                text_start = whole.end();
+            }
+        }
+    }
+
+    if depth > 0 {
+        segments.push(BrSegment::Text(&content[element_start..]));
+    } else if text_start < content.len() {
+        segments.push(BrSegment::Text(&content[text_start..]));
+    }
+
+    segments
+}
+
+/// Replace lazy-load placeholders with the real image from a `<noscript>` fallback
+///
+/// This implements Mozilla's _unwrapNoscriptImages: images without any source
+/// are dropped, and a noscript holding a single image replaces the single-image
+/// placeholder right before it. Attributes of the placeholder that point to an
+/// image are carried over, under a `data-old-` prefix if the name is taken.
+fn unwrap_noscript_images(document: &NodeRef) -> bool {
+    let mut changed = remove_sourceless_images(document);
+
+    for noscript in select_nodes(document, "noscript") {
+        let fragment = kuchikikiki::parse_html().one(noscript.text_contents());
+        let Some(container) = fragment
+            .select_first("body")
+            .ok()
+            .map(|body| body.as_node().clone())
+        else {
+            continue;
+        };
+        if !is_single_image(&container) {
+            continue;
+        }
+
+        let Some(previous) = noscript
+            .preceding_siblings()
+            .find(|sibling| sibling.as_element().is_some())
+        else {
+            continue;
+        };
+        if !is_single_image(&previous) {
+            continue;
+        }
+
+        let (Some(old_image), Some(new_image)) = (first_image(&previous), first_image(&container))
+        else {
+            continue;
+        };
+        copy_image_attributes(&old_image, &new_image);
+
+        let Some(replacement) = container
+            .children()
+            .find(|child| child.as_element().is_some())
+        else {
+            continue;
+        };
+        replacement.detach();
+        previous.insert_before(replacement);
+        previous.detach();
+        noscript.detach();
+        changed = true;
+    }
+
+    changed
+}
+
+static SIGNUP_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:newsletters?|subscribe|subscription|sign(?:ing)?[ -]?up|mailing list|your inbox)\b")
+        .unwrap()
+});
+
+static SIGNUP_PROMPT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\W*(?:sign up|subscribe|join|get)\b[^.!?]{0,80}\b(?:newsletters?|mailing list)\b[^.!?]{0,60}[.!?]?\W*$",
+    )
+    .unwrap()
+});
+
+/// Longest text a signup box may hold before it counts as real content
+const MAX_SIGNUP_TEXT_LEN: usize = 300;
+
+/// Remove newsletter signup boxes and standalone subscription prompts
+///
+/// A signup box is the largest wrapper around an email input or a submit
+/// button labelled like "Subscribe" that still holds only a little text,
+/// talks about subscribing, and has no paragraph other than a signup prompt.
+/// Blocks whose whole text is a prompt such as "Subscribe to our newsletter"
+/// are removed as well. Other forms are left for the regular form removal, so
+/// surrounding prose is unaffected.
+fn remove_newsletter_prompts(document: &NodeRef) -> bool {
+    let mut changed = false;
+
+    let controls = select_nodes(
+        document,
+        "input[type=email], button:not([type]), button[type=submit], input[type=submit]",
+    );
+    for control in controls {
+        if control.parent().is_none() {
+            continue;
+        }
+        let is_email = node_has_tag(&control, "input")
+            && get_attribute(&control, "type")
+                .is_some_and(|kind| kind.eq_ignore_ascii_case("email"));
+        let label = if node_has_tag(&control, "input") {
+            get_attribute(&control, "value").unwrap_or_default()
+        } else {
+            control.text_contents()
+        };
+        if !is_email && !SIGNUP_REGEX.is_match(&label) {
+            continue;
+        }
+
+        let mut container = control.clone();
+        while let Some(parent) = container.parent() {
+            if parent.as_element().is_none() || node_has_tag(&parent, "body") {
+                break;
+            }
+            if parent.text_contents().trim().len() > MAX_SIGNUP_TEXT_LEN {
+                break;
+            }
+            container = parent;
+        }
+        if container.text_contents().trim().len() > MAX_SIGNUP_TEXT_LEN || holds_prose(&container) {
+            continue;
+        }
+
+        let placeholders = select_nodes(&container, "input[placeholder]")
+            .iter()
+            .filter_map(|input| get_attribute(input, "placeholder"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if SIGNUP_REGEX.is_match(&container.text_contents()) || SIGNUP_REGEX.is_match(&placeholders)
+        {
+            container.detach();
+            changed = true;
+        }
+    }
+
+    for block in select_nodes(document, "p, div, section, aside") {
+        if block.parent().is_some() && SIGNUP_PROMPT_REGEX.is_match(block.text_contents().trim()) {
+            block.detach();
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// Whether a paragraph in the node says anything besides a signup prompt
+fn holds_prose(node: &NodeRef) -> bool {
+    select_nodes(node, "p").iter().any(|paragraph| {
+        let text = paragraph.text_contents();
+        let text = text.trim();
+        !text.is_empty() && !SIGNUP_PROMPT_REGEX.is_match(text)
+    })
+}
+
+static IMAGE_EXTENSION_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\.(jpg|jpeg|png|webp)").unwrap());
+
+fn remove_sourceless_images(root: &NodeRef) -> bool {
+    let mut removed = false;
+    for image in select_nodes(root, "img") {
+        let has_source = image.as_element().is_some_and(|element| {
+            let attrs = element.attributes.borrow();
+            attrs.map.iter().any(|(name, attribute)| {
+                matches!(
+                    name.local.as_ref(),
+                    "src" | "srcset" | "data-src" | "data-srcset"
+                ) || IMAGE_EXTENSION_REGEX.is_match(&attribute.value)
+            })
+        });
+        if !has_source {
+            image.detach();
+            removed = true;
+        }
+    }
+    removed
+}
+
+/// Whether the node is an image, or wraps exactly one image and no text
+fn is_single_image(node: &NodeRef) -> bool {
+    let mut current = node.clone();
+    loop {
+        if node_has_tag(&current, "img") {
+            return true;
+        }
+        let mut elements = current.children().filter(|c| c.as_element().is_some());
+        let (Some(child), None) = (elements.next(), elements.next()) else {
+            return false;
+        };
+        if !current.text_contents().trim().is_empty() {
+            return false;
+        }
+        current = child;
+    }
+}
+
+fn first_image(node: &NodeRef) -> Option<NodeRef> {
+    if node_has_tag(node, "img") {
+        return Some(node.clone());
+    }
+    node.select_first("img")
+        .ok()
+        .map(|img| img.as_node().clone())
+}
+
+fn copy_image_attributes(from: &NodeRef, to: &NodeRef) {
+    let (Some(source), Some(target)) = (from.as_element(), to.as_element()) else {
+        return;
+    };
+    let source_attrs = source.attributes.borrow();
+    let mut target_attrs = target.attributes.borrow_mut();
+
+    for (name, attribute) in source_attrs.map.iter() {
+        let name = name.local.as_ref();
+        let value = &attribute.value;
+        if value.is_empty() {
+            continue;
+        }
+        if name != "src" && name != "srcset" && !IMAGE_EXTENSION_REGEX.is_match(value) {
+            continue;
+        }
+        if target_attrs.get(name) == Some(value.as_str()) {
+            continue;
+        }
+        let target_name = if target_attrs.contains(name) {
+            format!("data-old-{name}")
+        } else {
+            name.to_string()
+        };
+        target_attrs.insert(target_name, value.clone());
+    }
+}
+
+/// Turn MathJax scaffolding into markup that survives script removal
+///
+/// Rendered MathJax output is replaced by its MathML, taken from `data-mathml`
+/// or an assistive `<math>` copy, and the matching TeX script is dropped. Remaining
+/// `script[type="math/tex"]` sources become `\( ... \)` or `\[ ... \]` text.
+fn convert_math_markup(html: &str) -> String {
+    static MATH_SOURCE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)math/tex|data-mathml|class=.MathJax").unwrap());
+    if !MATH_SOURCE_REGEX.is_match(html) {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let mut changed = false;
+    let mut replaced_sources = Vec::new();
+
+    for rendered in select_nodes(&document, "[data-mathml]") {
+        let Some(mathml) = get_attribute(&rendered, "data-mathml") else {
+            continue;
+        };
+        let fragment = kuchikikiki::parse_html().one(mathml);
+        let Ok(math) = fragment.select_first("math") else {
+            continue;
+        };
+        let math = math.as_node().clone();
+        math.detach();
+        rendered.insert_before(math);
+        if let Some(id) = get_attribute(&rendered, "id") {
+            replaced_sources.push(id.trim_end_matches("-Frame").to_string());
+        }
+        rendered.detach();
+        changed = true;
+    }
+
+    for node in select_nodes(&document, "[class*=MathJax]") {
+        let Some(class_attr) = get_attribute(&node, "class") else {
+            continue;
+        };
+        let tokens: Vec<&str> = class_attr.split_whitespace().collect();
+        if tokens.iter().any(|token| token.ends_with("_Display")) {
+            unwrap_node(&node);
+        } else if tokens.iter().any(|token| {
+            matches!(
+                *token,
+                "MathJax" | "MathJax_Preview" | "MathJax_SVG" | "MathJax_CHTML"
+            )
+        }) {
+            // MathJax 3 keeps an assistive MathML copy next to the rendered glyphs
+            for math in select_nodes(&node, "math") {
+                math.detach();
+                node.insert_before(math);
+            }
+            node.detach();
+        } else {
+            continue;
+        }
+        changed = true;
+    }
+
+    for script in select_nodes(&document, "script[type^='math/tex']") {
+        let is_replaced =
+            get_attribute(&script, "id").is_some_and(|id| replaced_sources.contains(&id));
+        let display =
+            get_attribute(&script, "type").is_some_and(|kind| kind.contains("mode=display"));
+        let tex = script.text_contents();
+        if !is_replaced && !tex.trim().is_empty() {
+            let (open, close) = if display {
+                ("\\[", "\\]")
+            } else {
+                ("\\(", "\\)")
+            };
+            if let Some(span) = create_element("span") {
+                span.append(NodeRef::new_text(format!("{open}{}{close}", tex.trim())));
+                script.insert_before(span);
+            }
+        }
+        script.detach();
+        changed = true;
+    }
+
+    if changed {
+        document.to_string()
+    } else {
+        html.to_string()
+    }
+}
+
+fn get_attribute(node: &NodeRef, name: &str) -> Option<String> {
+    let element = node.as_element()?;
+    let attrs = element.attributes.borrow();
+    attrs.get(name).map(str::to_string)
+}
+
+/// Empty the bodies of `<script>` and `<style>` elements longer than `max_bytes`,
+/// before the markup reaches the HTML parser.
+///
+/// JSON-LD and TeX scripts are kept since metadata and math conversion read them.
+/// Comments are skipped so a commented-out tag can't swallow real markup.
+/// With `max_bytes` of 0 the input is returned unchanged.
+pub fn strip_oversized_raw_text(html: &str, max_bytes: usize) -> Cow<'_, str> {
+    if max_bytes == 0 || html.len() <= max_bytes {
+        return Cow::Borrowed(html);
+    }
+
+    let lower = html.to_ascii_lowercase();
+    let mut out = String::new();
+    let mut copied = 0;
+    let mut pos = 0;
+    while let Some(offset) = lower[pos..].find('<') {
+        let start = pos + offset;
+        let rest = &lower[start..];
+        if rest.starts_with("<!--") {
+            pos = rest
+                .find("-->")
+                .map_or(lower.len(), |end| start + end + "-->".len());
+            continue;
+        }
+        let Some(tag) = ["script", "style"].into_iter().find(|tag| {
+            rest[1..].starts_with(tag)
+                && rest[1 + tag.len()..]
+                    .starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        }) else {
+            pos = start + 1;
+            continue;
+        };
+        let Some(open_end) = find_tag_end(rest).map(|end| start + end) else {
+            break;
+        };
+        let close = format!("</{tag}");
+        let body_end = lower[open_end..]
+            .find(&close)
+            .map_or(lower.len(), |end| open_end + end);
+        let open_tag = &lower[start..open_end];
+        let keep = open_tag.contains("ld+json") || open_tag.contains("math/");
+        if !keep && body_end - open_end > max_bytes {
+            out.push_str(&html[copied..open_end]);
+            copied = body_end;
+        }
+        pos = body_end;
+    }
 
-    if paragraphs.is_empty() {
-        String::new()
-    } else {
-        paragraphs.join("\n    ")
+    if copied == 0 {
+        return Cow::Borrowed(html);
+    }
+    out.push_str(&html[copied..]);
+    Cow::Owned(out)
+}
+
+/// Byte offset just past the `>` closing the start tag at the beginning of
+/// `tag`, ignoring any `>` inside quoted attribute values
+fn find_tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index + 1),
+            _ => {}
+        }
     }
+    None
 }
 
 /// Prepare document for readability processing
 ///
 /// This function implements Mozilla's _prepDocument functionality:
+/// - Convert MathJax markup and TeX scripts into MathML or TeX text
 /// - Remove script and style elements
 /// - Replace font tags with span
-/// - Unwrap noscript tags to reveal lazy-loaded images
+/// - Swap lazy-load placeholders for their noscript fallback images
+/// - Unwrap remaining noscript tags that hold images
+/// - Remove newsletter signup boxes and subscription prompts
 /// - Remove form elements
 ///
 /// This should be called BEFORE content extraction
 pub fn prep_document(html: &str) -> String {
-    let mut html = html.to_string();
-
-    let script_regex = regex::Regex::new(r"(?i)<script\b[^>]*>[\s\S]*?</script>").unwrap();
-    html = script_regex.replace_all(&html, "").to_string();
+    static SCRIPT_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)<script\b[^>]*>[\s\S]*?</script>").unwrap());
+    static STYLE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)<style\b[^>]*>[\s\S]*?</style>").unwrap());
+    static FONT_OPEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<font\b").unwrap());
+    static FONT_CLOSE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"</font>").unwrap());
+    static NOSCRIPT_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?is)<noscript\b[^>]*>(.*?)</noscript>").unwrap());
+    static NOSCRIPT_OPEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<noscript\b").unwrap());
+    static FORM_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)<form\b[^>]*>[\s\S]*?</form>").unwrap());
 
-    let style_regex = regex::Regex::new(r"(?i)<style\b[^>]*>[\s\S]*?</style>").unwrap();
-    html = style_regex.replace_all(&html, "").to_string();
+    let mut html = convert_math_markup(html);
 
-    let font_open_regex = regex::Regex::new(r"<font\b").unwrap();
-    html = font_open_regex.replace_all(&html, "<span").to_string();
+    html = SCRIPT_REGEX.replace_all(&html, "").to_string();
+    html = STYLE_REGEX.replace_all(&html, "").to_string();
+    html = FONT_OPEN_REGEX.replace_all(&html, "<span").to_string();
+    html = FONT_CLOSE_REGEX.replace_all(&html, "</span>").to_string();
 
-    let font_close_regex = regex::Regex::new(r"</font>").unwrap();
-    html = font_close_regex.replace_all(&html, "</span>").to_string();
+    // Both passes work on one parse of the document
+    let has_noscript = NOSCRIPT_OPEN_REGEX.is_match(&html);
+    if has_noscript || SIGNUP_REGEX.is_match(&html) {
+        let document = kuchikikiki::parse_html().one(html.as_str());
+        let unwrapped = has_noscript && unwrap_noscript_images(&document);
+        if remove_newsletter_prompts(&document) || unwrapped {
+            html = document.to_string();
+        }
+    }
 
-    let noscript_regex = regex::Regex::new(r"(?is)<noscript\b[^>]*>(.*?)</noscript>").unwrap();
-    html = noscript_regex
+    html = NOSCRIPT_REGEX
         .replace_all(&html, |caps: &regex::Captures| {
             let inner = &caps[1];
             if inner.contains("<img") {
@@ -818,8 +3170,7 @@ pub fn prep_document(html: &str) -> String {
         })
         .to_string();
 
-    let form_regex = regex::Regex::new(r"(?i)<form\b[^>]*>[\s\S]*?</form>").unwrap();
-    html = form_regex.replace_all(&html, "").to_string();
+    html = FORM_REGEX.replace_all(&html, "").to_string();
 
     html
 }
@@ -828,6 +3179,10 @@ pub fn prep_document(html: &str) -> String {
 mod tests {
     use super::*;
 
+    fn clean_dom(html: &str) -> String {
+        remove_conditionally(html, None, &ReadabilityOptions::default())
+    }
+
     #[test]
     fn test_remove_nav_like_sections() {
         let html = r#"
@@ -846,6 +3201,19 @@ mod tests {
         assert!(!cleaned.contains("sidebar"));
     }
 
+    #[test]
+    fn test_in_page_toc_nav_kept() {
+        let html = r##"
+            <nav class="toc"><a href="#intro">Intro</a> <a href="#usage">Usage</a> <a href="#faq">FAQ</a></nav>
+            <nav><a href="/">Home</a> <a href="#usage">Usage</a> <a href="#faq">FAQ</a></nav>
+            <p>Main article text</p>
+        "##;
+
+        let cleaned = remove_nav_like_sections(html);
+        assert!(cleaned.contains(r##"<a href="#intro">"##));
+        assert!(!cleaned.contains(r#"<a href="/">"#));
+    }
+
     #[test]
     fn test_remove_conditionally_removes_nav_table() {
         let html = r##"
@@ -858,11 +3226,825 @@ mod tests {
             </article>
         "##;
 
-        let cleaned = remove_conditionally(html);
+        let cleaned = clean_dom(html);
         assert!(!cleaned.contains("nav-table"));
         assert!(cleaned.contains("Main story starts here"));
     }
 
+    #[test]
+    fn test_remove_conditionally_keeps_figure_with_short_caption() {
+        let html = r#"<body><div class="photo"><figure><img src="a.jpg"><figcaption>Photo: <a href="/jane">Jane Doe</a></figcaption></figure></div></body>"#;
+        let result = clean_dom(html);
+        assert!(result.contains("<figcaption>Photo: <a href=\"/jane\">Jane Doe</a></figcaption>"));
+        assert!(result.contains(r#"<img src="a.jpg">"#));
+    }
+
+    #[test]
+    fn test_remove_conditionally_wraps_caption_paragraph() {
+        let html = r#"<body><div><img src="b.jpg"></div><p class="caption">Second shot</p><p>Text after</p></body>"#;
+        let result = clean_dom(html);
+        assert!(result
+            .contains(r#"<figure><img src="b.jpg"><figcaption>Second shot</figcaption></figure>"#));
+        assert!(result.contains("<p>Text after</p>"));
+    }
+
+    #[test]
+    fn test_layout_table_unwrapped_around_data_table() {
+        let html = r#"<body><table class="layout"><tr><td>
+            <p>Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.</p>
+            <table class="wikitable"><tr><th>Model</th><th>Speed</th></tr><tr><td>A</td><td>10</td></tr><tr><td>B</td><td>12</td></tr></table>
+        </td></tr></table></body>"#;
+        let result = clean_dom(html);
+        assert!(!result.contains("layout"));
+        assert!(result.contains(r#"<table class="wikitable">"#));
+        assert!(result.contains("<th>Model</th>"));
+        assert!(!result.contains("data-readability-datatable"));
+    }
+
+    #[test]
+    fn test_detect_data_table_counts_own_rows_only() {
+        let document = kuchikikiki::parse_html().one(
+            r#"<table id="outer"><tr><td><table id="inner" role="grid"><tr><td>1</td></tr></table></td></tr></table>"#,
+        );
+        let outer = document.select_first("#outer").unwrap().as_node().clone();
+        let inner = document.select_first("#inner").unwrap().as_node().clone();
+        assert!(!detect_data_table(&outer));
+        assert!(detect_data_table(&inner));
+        assert_eq!(get_row_and_column_count(&outer), (1, 1));
+    }
+
+    #[test]
+    fn test_highlighted_code_block_keeps_whitespace_and_language() {
+        let html = r#"<body><pre><code class="hljs language-rust"><span class="hljs-keyword">fn</span> <span class="hljs-title function_">main</span>() {
+    <span class="hljs-keyword">let</span>   x  = <span class="hljs-number">1</span>;
+
+    <span class="hljs-built_in">println!</span>(<span class="hljs-string">"{x}"</span>);
+}</code></pre></body>"#;
+        let cleaned = clean_dom(html);
+        let result = clean_attributes(&cleaned, &ReadabilityOptions::default());
+        assert_eq!(
+            result,
+            "<pre><code class=\"language-rust\">fn main() {\n    let   x  = 1;\n\n    println!(\"{x}\");\n}</code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_readme_code_block_keeps_lines() {
+        let html = r#"<div class="highlight highlight-source-shell"><pre><span class="pl-c1">cargo</span> add readabilityrs<br><br><span class="pl-c1">cargo</span>   build</pre></div>"#;
+        let cleaned = clean_dom(&replace_brs(html));
+        let result = clean_attributes(&cleaned, &ReadabilityOptions::default());
+        assert_eq!(
+            result,
+            "<div><pre class=\"language-shell\">cargo add readabilityrs\n\ncargo   build</pre></div>"
+        );
+    }
+
+    #[test]
+    fn test_clean_attributes_honors_class_options() {
+        let html = r#"<div class="page wrapper"><p class="lead">Text</p></div>"#;
+        let result = clean_attributes(html, &ReadabilityOptions::default());
+        assert_eq!(result, r#"<div class="page"><p>Text</p></div>"#);
+
+        let options = ReadabilityOptions::builder().keep_classes(true).build();
+        assert_eq!(clean_attributes(html, &options), html);
+    }
+
+    #[test]
+    fn test_prep_document_converts_mathjax_sources() {
+        let html = r#"<html><body><p>Inline <span class="MathJax_Preview"></span><span class="MathJax" id="MathJax-Element-1-Frame" data-mathml="<math><mi>x</mi></math>"><span>x</span></span><script type="math/tex" id="MathJax-Element-1">x</script> and</p>
+            <script type="math/tex; mode=display">e^{i\pi} + 1 = 0</script></body></html>"#;
+        let result = prep_document(html);
+        assert!(result.contains("<math><mi>x</mi></math>"));
+        assert!(result.contains(r"<span>\[e^{i\pi} + 1 = 0\]</span>"));
+        assert!(!result.contains("MathJax"));
+        assert!(!result.contains("<script"));
+    }
+
+    #[test]
+    fn test_prep_document_keeps_mathjax3_assistive_mathml() {
+        let html = r#"<html><body><p>When <mjx-container class="MathJax" jax="CHTML"><mjx-math aria-hidden="true"><mjx-mi><mjx-c class="mjx-c1D44E"></mjx-c></mjx-mi></mjx-math><mjx-assistive-mml><math><mi>a</mi></math></mjx-assistive-mml></mjx-container> holds</p></body></html>"#;
+        let result = prep_document(html);
+        assert!(result.contains("<p>When <math><mi>a</mi></math> holds</p>"));
+        assert!(!result.contains("mjx-"));
+    }
+
+    #[test]
+    fn test_remove_conditionally_keeps_math_only_div() {
+        let html = r#"<body><div><math display="block"><mi>a</mi><mo>=</mo><mi>b</mi></math></div></body>"#;
+        let result = clean_dom(html);
+        assert!(result.contains(r#"<math display="block">"#));
+    }
+
+    #[test]
+    fn test_inline_svg_kept_without_scripts() {
+        let html = r##"<body><div><svg viewBox="0 0 10 10" onload="alert(1)"><title>Chart</title><script>alert(2)</script><defs><linearGradient id="g"></linearGradient></defs><path d="M0 0 L10 10" onclick="x()"/><use xlink:href="#g"/></svg></div></body>"##;
+        let result = clean_dom(html);
+        assert!(result.contains(r#"viewBox="0 0 10 10""#));
+        assert!(result.contains(r#"xmlns="http://www.w3.org/2000/svg""#));
+        assert!(result.contains(r#"xmlns:xlink="http://www.w3.org/1999/xlink""#));
+        assert!(result.contains(r#"<path d="M0 0 L10 10"></path>"#));
+        assert!(result.contains(r##"<use xlink:href="#g"></use>"##));
+        assert!(!result.contains("alert"));
+        assert!(!result.contains("onclick"));
+    }
+
+    #[test]
+    fn test_inline_svg_keeps_declared_namespaces_once() {
+        let html = r##"<body><div><svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 10 10"><defs><circle id="c" r="1"/></defs><use xlink:href="#c"/></svg></div></body>"##;
+        let result = clean_dom(html);
+        assert_eq!(result.matches("xmlns=").count(), 1);
+        assert_eq!(result.matches("xmlns:xlink=").count(), 1);
+        assert!(!result.contains(":xmlns="));
+    }
+
+    #[test]
+    fn test_oversized_inline_svg_replaced_by_title() {
+        let dots = "<circle r=\"1\"/>".repeat(MAX_INLINE_SVG_ELEMENTS + 1);
+        let html = format!(
+            "<body><p>Figure: <svg><title>Every store on a map</title>{dots}</svg></p></body>"
+        );
+        let result = clean_dom(&html);
+        assert_eq!(result, "<p>Figure: Every store on a map</p>");
+    }
+
+    #[test]
+    fn test_video_embed_reduced_to_plain_iframe() {
+        let html = r#"<body><div class="video-container"><div class="embed-responsive"><iframe src="//www.youtube-nocookie.com/embed/abc?rel=0" width="560" height="315" frameborder="0" style="border:0" allow="autoplay"></iframe></div></div></body>"#;
+        let result = remove_conditionally(
+            html,
+            Some("https://example.com/posts/1"),
+            &ReadabilityOptions::default(),
+        );
+        assert!(result.starts_with("<div><iframe "));
+        assert!(result.ends_with("></iframe></div>"));
+        assert!(result.contains(r#"src="https://www.youtube-nocookie.com/embed/abc?rel=0""#));
+        assert!(result.contains(r#"width="560""#) && result.contains(r#"height="315""#));
+        assert!(result.contains(r#"allowfullscreen="""#));
+        assert!(!result.contains("frameborder") && !result.contains("style"));
+    }
+
+    #[test]
+    fn test_video_element_cleaned() {
+        let html = r#"<body><div class="player"><video poster="stills/cover.jpg" autoplay muted><source data-src="clips/intro.webm" type="video/webm"></video></div></body>"#;
+        let result = remove_conditionally(
+            html,
+            Some("https://example.com/watch/"),
+            &ReadabilityOptions::default(),
+        );
+        assert!(result.contains(r#"poster="https://example.com/watch/stills/cover.jpg""#));
+        assert!(result.contains(r#"src="https://example.com/watch/clips/intro.webm""#));
+        assert!(result.contains("controls"));
+        assert!(!result.contains("autoplay") && !result.contains("muted"));
+    }
+
+    #[test]
+    fn test_related_module_removed() {
+        let html = r#"<body><p>Story text that goes on for a while.</p>
+            <div class="module"><header><h3>Read next</h3></header><ul>
+                <li><a href="/2024/other-story">Another story from the same newsroom</a></li>
+                <li><a href="https://www.example.com/2024/third">A third story worth a click</a></li>
+            </ul></div>
+            <p>Closing paragraph.</p></body>"#;
+        let result = remove_conditionally(
+            html,
+            Some("https://example.com/2024/story"),
+            &ReadabilityOptions::default(),
+        );
+        assert!(!result.contains("Read next"));
+        assert!(!result.contains("Another story"));
+        assert!(result.contains("<p>Closing paragraph.</p>"));
+    }
+
+    #[test]
+    fn test_related_work_section_kept() {
+        let html = r#"<body><section><h2>Related work</h2>
+            <p>Earlier systems such as <a href="/papers/1">Foo</a> rely on hand-written rules, while our approach learns the rules from a small annotated corpus and generalizes across sites.</p>
+        </section></body>"#;
+        let result = remove_conditionally(
+            html,
+            Some("https://example.com/paper"),
+            &ReadabilityOptions::default(),
+        );
+        assert!(result.contains("<h2>Related work</h2>"));
+    }
+
+    #[test]
+    fn test_related_module_extra_phrases() {
+        let html =
+            r#"<body><div><h4>Lisez aussi</h4><a href="/autre">Un autre article</a></div></body>"#;
+        let base = Url::parse("https://example.fr/article").unwrap();
+
+        let document = kuchikikiki::parse_html().one(html);
+        remove_related_modules(&document, Some(&base), &[]);
+        assert!(document.to_string().contains("Lisez aussi"));
+
+        remove_related_modules(&document, Some(&base), &["Lisez aussi".to_string()]);
+        assert!(!document.to_string().contains("Lisez aussi"));
+    }
+
+    #[test]
+    fn test_prep_document_removes_newsletter_box() {
+        let html = r#"<html><body><article>
+            <p>The council approved the budget on Tuesday after a long debate.</p>
+            <div class="inline-promo"><div><h3>Get the morning briefing</h3><p>Sign up for our newsletter.</p>
+                <form action="/subscribe"><input type="email" placeholder="Your email"><button>Go</button></form></div></div>
+            <p>Subscribe to our newsletter!</p>
+            <p>Members of the newsletter committee spoke against the plan, arguing that the city could not afford it this year.</p>
+        </article></body></html>"#;
+
+        let result = prep_document(html);
+
+        assert!(result.contains("approved the budget"));
+        assert!(result.contains("newsletter committee"));
+        assert!(!result.contains("morning briefing"));
+        assert!(!result.contains("Subscribe to our newsletter"));
+    }
+
+    #[test]
+    fn test_prep_document_keeps_signup_form_how_to() {
+        // The form is what the step is about, so its explanation stays
+        let html = r#"<html><body><article><h1>How to Add a Newsletter Signup Form</h1>
+            <div class="step"><h3>Step 2</h3>
+                <p>Add an email field and a button, so readers can join your mailing list.</p>
+                <form><input type="email" placeholder="you@example.com"><button>Subscribe</button></form></div>
+            <div class="step"><h3>Step 3</h3>
+                <form><label>Email <input type="email"></label><button type="submit">Log in</button></form></div>
+        </article></body></html>"#;
+
+        let result = prep_document(html);
+
+        assert!(result.contains("Step 2"));
+        assert!(result.contains("readers can join your mailing list"));
+        assert!(result.contains("Step 3"));
+    }
+
+    #[test]
+    fn test_prep_document_keeps_contact_form_text() {
+        let html = r#"<html><body><h1>Contact us</h1>
+            <p>Questions about your order? Send us a message.</p>
+            <form><input type="email" name="email"><textarea name="message"></textarea><button>Send</button></form>
+        </body></html>"#;
+
+        let result = prep_document(html);
+
+        assert!(result.contains("<h1>Contact us</h1>"));
+        assert!(result.contains("Send us a message."));
+        assert!(!result.contains("<form"));
+    }
+
+    #[test]
+    fn test_trailing_comments_truncated() {
+        let prose = "<p>The river rose three feet overnight, flooding the lower town and closing the bridge for most of the morning.</p>".repeat(6);
+        let html = format!(
+            r#"<body><div class="entry-content">{prose}</div>
+            <h3>12 Comments</h3>
+            <div class="comment-content"><p>First! Great article, thanks for writing it up.</p></div>
+            <div class="comment-content"><p>I live there and it was much worse than reported.</p></div></body>"#
+        );
+
+        let result = clean_dom(&html);
+
+        assert!(result.contains("closing the bridge"));
+        assert!(!result.contains("12 Comments"));
+        assert!(!result.contains("much worse than reported"));
+    }
+
+    #[test]
+    fn test_leading_comments_region_kept() {
+        let reply = "<div class=\"post\"><p>Has anyone managed to get the driver working on the new kernel? Mine keeps crashing after resume.</p></div>".repeat(6);
+        let html = format!(
+            r#"<body><h1>Driver crashes after resume</h1><div id="comments">{reply}</div></body>"#
+        );
+
+        let document = kuchikikiki::parse_html().one(html);
+        truncate_trailing_comments(&document);
+
+        assert_eq!(select_nodes(&document, "div.post").len(), 6);
+    }
+
+    #[test]
+    fn test_footnote_list_kept() {
+        let notes: String = (1..=4)
+            .map(|i| format!(r##"<li id="fn{i}"><a href="#fnref{i}">↩</a> <a href="https://archive.example.org/doc/{i}">Smith, The Archive</a>, p. {i}.</li>"##))
+            .collect();
+        let html = format!(
+            r#"<body><div class="footnotes"><h3>Notes and sources</h3><ol>{notes}</ol></div></body>"#
+        );
+
+        let result = clean_dom(&html);
+
+        assert!(result.contains(r##"<li id="fn3"><a href="#fnref3">↩</a>"##));
+    }
+
+    #[test]
+    fn test_anchor_only_list_not_footnotes() {
+        let html = r##"<ol><li><a href="#intro">Introduction</a></li><li><a href="#method">Method</a></li></ol>"##;
+        let document = kuchikikiki::parse_html().one(html);
+        let list = document.select_first("ol").unwrap();
+        assert!(!is_footnote_list(list.as_node()));
+    }
+
+    #[test]
+    fn test_quote_attribution_kept() {
+        let html = r#"<body><blockquote><p>Stay hungry, stay foolish.</p></blockquote><div class="source"><cite><a href="https://example.org/whole-earth">Whole Earth Catalog</a></cite></div><div class="source"><a href="/more">More</a></div></body>"#;
+
+        let result = clean_dom(html);
+
+        assert!(result.contains(
+            "<cite><a href=\"https://example.org/whole-earth\">Whole Earth Catalog</a></cite>"
+        ));
+        assert!(!result.contains("/more"));
+    }
+
+    #[test]
+    fn test_definition_groups_kept() {
+        let html = r##"<body><dl><div><dt><a href="#q1">Refunds?</a></dt><dd>Within 30 days.</dd></div><div><dt><a href="#q2">Shipping?</a></dt><dd>Free.</dd></div></dl></body>"##;
+
+        let result = clean_dom(html);
+
+        assert!(result.contains(
+            r##"<div><dt><a href="#q1">Refunds?</a></dt><dd>Within 30 days.</dd></div>"##
+        ));
+        assert!(result.contains(r##"<dt><a href="#q2">Shipping?</a></dt><dd>Free.</dd>"##));
+    }
+
+    fn outline(html: &str) -> Vec<String> {
+        let document = kuchikikiki::parse_html().one(html);
+        select_nodes(&document, "h1, h2, h3, h4, h5, h6")
+            .iter()
+            .map(|heading| {
+                let tag = heading.as_element().unwrap().name.local.to_string();
+                format!("{tag}:{}", heading.text_contents())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_headings() {
+        let html = r#"<body><h1>The Quiet Harbour!</h1><p>Intro.</p><h3>Morning</h3><p>Text.</p><h5>Boats</h5><p>Text.</p><h3>Evening</h3><blockquote><h1>Quoted</h1></blockquote></body>"#;
+
+        let result = normalize_headings(html, Some("The quiet harbour"));
+
+        assert_eq!(
+            outline(&result),
+            vec!["h2:Morning", "h4:Boats", "h2:Evening", "h1:Quoted"]
+        );
+    }
+
+    #[test]
+    fn test_normalize_headings_keeps_non_leading_title() {
+        let html = r#"<body><p>Intro.</p><h1>Report</h1><h2>Details</h2></body>"#;
+
+        let result = normalize_headings(html, Some("Report"));
+
+        assert_eq!(outline(&result), vec!["h2:Report", "h3:Details"]);
+    }
+
+    #[test]
+    fn test_empty_paragraphs_removed() {
+        let html = "<body><p>First.</p><p></p><p>&nbsp;</p><p> \u{200b} </p><p><br></p><p><img src=\"a.png\"></p><p><iframe src=\"https://www.youtube.com/embed/x\"></iframe></p><p>Last.</p></body>";
+
+        let document = kuchikikiki::parse_html().one(html);
+        remove_empty_paragraphs(&document);
+
+        let paragraphs = select_nodes(&document, "p");
+        assert_eq!(paragraphs.len(), 4);
+        assert!(paragraphs.iter().all(|p| {
+            !p.text_contents().trim().is_empty() || p.select_first("img, iframe").is_ok()
+        }));
+    }
+
+    #[test]
+    fn test_br_runs_collapsed() {
+        let html = r#"<body><p>One<br><br> <br><br>Two<br>Three</p><div>Four<br><br><br>Five</div></body>"#;
+
+        let result = clean_dom(html);
+
+        assert!(result.contains("<p>One</p><p>Two<br>Three</p>"));
+        assert!(result.contains("<div>Four<br><br>Five</div>"));
+    }
+
+    #[test]
+    fn test_sanitize_content() {
+        let html = r#"<body><p onclick="steal()" class="lead">Hello <a href="java&#09;script:alert(1)" onmouseover="x()">there</a>, <a href="/ok">friend</a>.</p>
+            <div><script>alert(1)</script><style>p { color: red }</style><img src="data:text/html;base64,PHNjcmlwdD4=" alt="x" onerror="boom()"><img src="data:image/png;base64,iVBORw0KGgo="></div>
+            <iframe src=" JavaScript:void(0)"></iframe><svg onload="evil()"><a xlink:href="/fine"></a></svg></body>"#;
+
+        let result = sanitize_content(html);
+
+        for needle in [
+            "onclick",
+            "onmouseover",
+            "onerror",
+            "onload",
+            "<script",
+            "<style",
+            "alert",
+            "text/html",
+            "avaScript",
+        ] {
+            assert!(!result.contains(needle), "{needle} survived: {result}");
+        }
+        assert!(result.contains(r#"<a href="/ok">friend</a>"#));
+        assert!(result.contains(r#"class="lead""#));
+        assert!(result.contains("data:image/png"));
+    }
+
+    #[test]
+    fn test_sanitize_content_checks_srcset_candidates() {
+        let html = r#"<body><img src="a.jpg" srcset="a-640.jpg 640w, javascript:alert(1) 1280w"><img srcset="b.jpg 1x, b@2x.jpg 2x"></body>"#;
+
+        let result = sanitize_content(html);
+
+        assert!(!result.contains("alert"), "{result}");
+        assert!(result.contains(r#"<img src="a.jpg">"#));
+        assert!(result.contains(r#"srcset="b.jpg 1x, b@2x.jpg 2x""#));
+    }
+
+    #[test]
+    fn test_sanitize_content_checks_object_data() {
+        let html = r#"<body><object data="data:text/html;base64,PHNjcmlwdD4="></object><object data="chart.svg" type="image/svg+xml"></object><p data="javascript:kept()">Text</p></body>"#;
+
+        let result = sanitize_content(html);
+
+        assert!(!result.contains("text/html"), "{result}");
+        assert!(result.contains(r#"data="chart.svg""#));
+        // Only `<object>` loads its `data` attribute
+        assert!(result.contains(r#"data="javascript:kept()""#));
+    }
+
+    #[test]
+    fn test_sanitize_content_checks_svg_animation_values() {
+        let html = r#"<body><svg><a><animate attributeName="href" values="/ok; javascript:alert(1)"></animate><set attributeName="href" to="javascript:alert(2)"></set><animate attributeName="href" from="vbscript:msgbox" to="/fine"></animate><animate attributeName="x" values="0;10;0"></animate><text>Link</text></a></svg></body>"#;
+
+        let result = sanitize_content(html);
+
+        for needle in ["alert", "vbscript", "values=\"/ok"] {
+            assert!(!result.contains(needle), "{needle} survived: {result}");
+        }
+        assert!(result.contains(r#"to="/fine""#));
+        assert!(result.contains(r#"values="0;10;0""#));
+    }
+
+    #[test]
+    fn test_clean_attributes_strips_styles() {
+        let html = r##"<body><table bgcolor="#eee" border="1" width="600"><tr><td align="left" style="color: red">Rust</td><td>1.0</td></tr><tr><td>Go</td><td>1.22</td></tr></table><p style="float: left">Note</p><svg style="fill: red"></svg></body>"##;
+
+        let result = clean_attributes(html, &ReadabilityOptions::default());
+        assert_eq!(
+            result,
+            r##"<table><tbody><tr><td>Rust</td><td>1.0</td></tr><tr><td>Go</td><td>1.22</td></tr></tbody></table><p>Note</p><svg style="fill: red"></svg>"##
+        );
+
+        let options = ReadabilityOptions::builder().strip_styles(false).build();
+        let result = clean_attributes(html, &options);
+        assert!(result.contains(r##"bgcolor="#eee""##));
+        assert!(result.contains(r##"style="float: left""##));
+    }
+
+    #[test]
+    fn test_strip_oversized_raw_text() {
+        let bundle = "var x = 1;".repeat(20);
+        let json = format!(r#"{{"author": "Jane Doe", "pad": "{}"}}"#, "x".repeat(200));
+        let html = format!(
+            r#"<head><script type="text/javascript">{bundle}</script><SCRIPT type="application/ld+json">{json}</SCRIPT><style data-x="a>b">{bundle}</style></head><body><!-- <script> --><p>Kept</p><script>short</script></body>"#
+        );
+
+        let stripped = strip_oversized_raw_text(&html, 100);
+        assert_eq!(
+            stripped,
+            format!(
+                r#"<head><script type="text/javascript"></script><SCRIPT type="application/ld+json">{json}</SCRIPT><style data-x="a>b"></style></head><body><!-- <script> --><p>Kept</p><script>short</script></body>"#
+            )
+        );
+        assert!(matches!(
+            strip_oversized_raw_text(&html, 0),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            strip_oversized_raw_text(&html, 1000),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_strip_lede_dateline() {
+        let document = kuchikikiki::parse_html().one(
+            r#"<body><p><strong>CAIRO —</strong> Egyptian officials said on Monday that talks would resume.</p><p>WASHINGTON — A second dateline stays.</p></body>"#,
+        );
+        strip_lede_dateline(&document);
+        let paragraphs: Vec<String> = select_nodes(&document, "p")
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert_eq!(
+            paragraphs,
+            [
+                "<p>Egyptian officials said on Monday that talks would resume.</p>",
+                "<p>WASHINGTON — A second dateline stays.</p>",
+            ]
+        );
+
+        let document = kuchikikiki::parse_html()
+            .one("<body><p>Paris — the city of light — has long drawn painters.</p></body>");
+        strip_lede_dateline(&document);
+        assert!(document
+            .to_string()
+            .contains("<p>Paris — the city of light — has long drawn painters.</p>"));
+    }
+
+    #[test]
+    fn test_strip_image_credits() {
+        let html = r#"<body>
+            <figure><img src="a.jpg"><figcaption>Crowds gather at the harbour on Saturday. Photo: Jane Doe/Reuters</figcaption></figure>
+            <figure><img src="b.jpg"><figcaption>The new bridge at dusk <span class="credit">(Image: Getty Images)</span></figcaption></figure>
+            <figure><img src="c.jpg"><figcaption>Volunteers at work.<br>Photograph by Sam Lee for The Herald</figcaption></figure>
+            <figure><img src="d.jpg"><figcaption>Source: the 2020 census shows the valley grew by a third</figcaption></figure>
+            <figure><img src="e.jpg"><figcaption>Credit: AP</figcaption></figure>
+        </body>"#;
+
+        let document = kuchikikiki::parse_html().one(html);
+        strip_image_credits(&document);
+        let captions: Vec<String> = select_nodes(&document, "figcaption")
+            .iter()
+            .map(|caption| caption.to_string())
+            .collect();
+        assert_eq!(
+            captions,
+            vec![
+                "<figcaption>Crowds gather at the harbour on Saturday.</figcaption>",
+                "<figcaption>The new bridge at dusk</figcaption>",
+                "<figcaption>Volunteers at work.</figcaption>",
+                "<figcaption>Source: the 2020 census shows the valley grew by a third</figcaption>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_presentational_wrappers_unwrapped() {
+        let html = r#"<body><p><span><span style="color: red">Nested</span></span> <span color="blue" face="Arial">font</span> <span lang="fr">bonjour</span> <abbr title="World Health Organization">WHO</abbr> at <time datetime="2024-03-01">1 March</time>, H<sub>2</sub>O, 10<sup>5</sup>, <kbd>Ctrl</kbd> and <mark>this</mark>.</p></body>"#;
+
+        let result = clean_attributes(html, &ReadabilityOptions::default());
+        assert_eq!(
+            result,
+            r#"<p>Nested font <span lang="fr">bonjour</span> <abbr title="World Health Organization">WHO</abbr> at <time datetime="2024-03-01">1 March</time>, H<sub>2</sub>O, 10<sup>5</sup>, <kbd>Ctrl</kbd> and <mark>this</mark>.</p>"#
+        );
+
+        let document = kuchikikiki::parse_html().one("<p>x<sup>2</sup></p>");
+        let sup = document.select_first("sup").unwrap().as_node().clone();
+        unwrap_node(&sup);
+        assert!(document.to_string().contains("<sup>2</sup>"));
+    }
+
+    #[test]
+    fn test_clean_attributes_strips_data_attributes() {
+        let html = r#"<body><p data-module="Body" data-analytics-id="p1">See note<sup data-footnote-id="1">1</sup></p></body>"#;
+
+        let result = clean_attributes(html, &ReadabilityOptions::default());
+        assert_eq!(result, "<p>See note<sup>1</sup></p>");
+
+        let options = ReadabilityOptions::builder()
+            .data_attributes_to_preserve(vec!["data-footnote-id".to_string()])
+            .build();
+        let result = clean_attributes(html, &options);
+        assert_eq!(
+            result,
+            r#"<p>See note<sup data-footnote-id="1">1</sup></p>"#
+        );
+    }
+
+    #[test]
+    fn test_tracking_images_removed() {
+        let html = r#"<body><p><img src="https://example.com/open/pixel.gif?u=1" width="1" height="1"></p>
+            <p><img src="https://example.com/photo.jpg" style="width: 0px; height: 0px"></p>
+            <p><img src="data:image/gif;base64,R0lGODlhAQABAAAAACw="></p>
+            <p><img src="https://example.com/deco.png" aria-hidden="true" alt=""></p>
+            <p><img src="https://example.com/hero.jpg" width="800" height="400"></p>
+            <p>We loved it <img src="https://example.com/emoji/heart.png" width="2" height="2" alt="heart"> and so did you.</p></body>"#;
+
+        let document = kuchikikiki::parse_html().one(html);
+        remove_tracking_images(&document);
+
+        let sources: Vec<String> = select_nodes(&document, "img")
+            .iter()
+            .filter_map(|image| get_attribute(image, "src"))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                "https://example.com/hero.jpg",
+                "https://example.com/emoji/heart.png"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        let http = Url::parse("http://example.com/news/story").unwrap();
+        let https = Url::parse("https://example.com/news/story").unwrap();
+
+        assert_eq!(
+            resolve_url("//cdn.example.com/img.jpg", Some(&http), false, false).as_deref(),
+            Some("http://cdn.example.com/img.jpg")
+        );
+        assert_eq!(
+            resolve_url(" //cdn.example.com/img.jpg ", Some(&https), false, false).as_deref(),
+            Some("https://cdn.example.com/img.jpg")
+        );
+        assert_eq!(
+            resolve_url("//example.org/page?q=1#top", Some(&https), false, false).as_deref(),
+            Some("https://example.org/page?q=1#top")
+        );
+        assert_eq!(
+            resolve_url("img/a.jpg", Some(&https), false, false).as_deref(),
+            Some("https://example.com/news/img/a.jpg")
+        );
+
+        // Without a base, only protocol-relative URLs resolve, and only on request
+        assert_eq!(
+            resolve_url("//cdn.example.com/img.jpg", None, false, false),
+            None
+        );
+        assert_eq!(
+            resolve_url("//cdn.example.com/img.jpg", None, true, false).as_deref(),
+            Some("https://cdn.example.com/img.jpg")
+        );
+        assert_eq!(resolve_url("/img.jpg", None, true, false), None);
+        assert_eq!(resolve_url("///img.jpg", None, true, false), None);
+        assert_eq!(resolve_url("  ", Some(&https), true, false), None);
+    }
+
+    #[test]
+    fn test_fix_relative_uris() {
+        let html = r##"<body><p><a href="../about">About</a> <a href="#notes">Notes</a> <img src="img/a.png"></p></body>"##;
+        let result = remove_conditionally(
+            html,
+            Some("https://example.com/posts/1/"),
+            &ReadabilityOptions::default(),
+        );
+        assert!(result.contains(r#"href="https://example.com/posts/about""#));
+        assert!(result.contains(r##"href="#notes""##));
+        assert!(result.contains(r#"src="https://example.com/posts/1/img/a.png""#));
+    }
+
+    #[test]
+    fn test_fix_relative_uri_attributes() {
+        let html = r#"<body>
+            <video src="clip.mp4" poster="poster.jpg"><track src="subs.vtt"></video>
+            <blockquote cite="/source">Quoted</blockquote> <q cite="q.html">short</q>
+            <ins cite="changes#1">added</ins> <del cite="changes#2">removed</del>
+            <object data="movie.swf"></object>
+            <form><button formaction="/submit">Go</button><input type="image" src="go.png" formaction="go"></form>
+            <picture><source srcset="wide.jpg 1200w, narrow.jpg 600w"><img src="a.jpg" srcset="a.jpg, a@2x.jpg 2x"></picture>
+            <map><area href="region.html"></map>
+        </body>"#;
+        let base = Url::parse("https://example.com/posts/1/").unwrap();
+
+        let document = kuchikikiki::parse_html().one(html);
+        fix_relative_uris(&document, Some(&base), false);
+        let result = document.to_string();
+
+        static URL_ATTR_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#"\s(href|src|poster|cite|data|formaction|srcset)="([^"]*)""#).unwrap()
+        });
+        let mut checked = 0;
+        for caps in URL_ATTR_REGEX.captures_iter(&result) {
+            for url in caps[2].split(", ") {
+                let url = url.split_whitespace().next().unwrap();
+                assert!(
+                    url.starts_with("https://example.com/"),
+                    "{} = {url}",
+                    &caps[1]
+                );
+                checked += 1;
+            }
+        }
+        assert_eq!(checked, 17);
+        assert!(result.contains(r#"srcset="https://example.com/posts/1/wide.jpg 1200w, https://example.com/posts/1/narrow.jpg 600w""#));
+    }
+
+    #[test]
+    fn test_image_url_rewriter_gets_sizes() {
+        use std::sync::{Arc, Mutex};
+
+        let html = r#"<body><p>Text around the photos.</p>
+            <img src="a.jpg" srcset="a-640.jpg 640w, a-1280.jpg 1280w" width="800" height="600">
+            <picture><source srcset="b.webp 1x, b@2x.webp 2x"><img src="b.jpg" style="width: 300px"></picture>
+            <video poster="poster.jpg" src="clip.mp4"></video></body>"#;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&seen);
+        let options = ReadabilityOptions::builder()
+            .image_url_rewriter(move |url, size| {
+                recorded.lock().unwrap().push((url.to_string(), size));
+                format!("https://img.example/{}", url.rsplit('/').next().unwrap())
+            })
+            .build();
+        let result = remove_conditionally(html, Some("https://example.com/post/"), &options);
+
+        let seen = seen.lock().unwrap();
+        let size_of = |url: &str| {
+            seen.iter()
+                .find(|(seen, _)| seen == &format!("https://example.com/post/{url}"))
+                .map(|(_, size)| *size)
+        };
+        assert_eq!(size_of("a.jpg"), Some(Some((800, 600))));
+        assert_eq!(size_of("a-640.jpg"), Some(Some((640, 480))));
+        assert_eq!(size_of("a-1280.jpg"), Some(Some((1280, 960))));
+        assert_eq!(size_of("b@2x.webp"), Some(Some((600, 0))));
+        assert_eq!(size_of("poster.jpg"), Some(None));
+        assert_eq!(size_of("clip.mp4"), None);
+
+        assert!(result.contains(
+            r#"srcset="https://img.example/a-640.jpg 640w, https://img.example/a-1280.jpg 1280w""#
+        ));
+        assert!(result.contains(r#"poster="https://img.example/poster.jpg""#));
+    }
+
+    #[test]
+    fn test_fix_relative_uris_spells_hosts_consistently() {
+        let resolve_all = |urls: &[(&str, &str)]| {
+            let elements: String = urls
+                .iter()
+                .map(|(url, _)| format!(r#"<a href="{url}">link</a><img src="{url}">"#))
+                .collect();
+            let document = kuchikikiki::parse_html().one(format!("<body><p>{elements}</p></body>"));
+            let base = Url::parse("https://пример.рф/статья/1").unwrap();
+            fix_relative_uris(&document, Some(&base), false);
+
+            let expected: Vec<&str> = urls.iter().map(|(_, resolved)| *resolved).collect();
+            for (selector, attribute) in [("a", "href"), ("img", "src")] {
+                let resolved: Vec<String> = select_nodes(&document, selector)
+                    .iter()
+                    .filter_map(|node| get_attribute(node, attribute))
+                    .collect();
+                assert_eq!(resolved, expected, "{selector}");
+            }
+        };
+
+        // One link written in Unicode puts every host in Unicode
+        resolve_all(&[
+            ("https://мвд.рф/news/1", "https://мвд.рф/news/1"),
+            ("https://xn--b1aew.xn--p1ai/news/2", "https://мвд.рф/news/2"),
+            (
+                "https://MÜNCHEN.Example:443/stadt?q=ü",
+                "https://münchen.example/stadt?q=%C3%BC",
+            ),
+            ("//bücher.example/a.jpg", "https://bücher.example/a.jpg"),
+            (
+                "https://xn--mnchen-3ya.example/b",
+                "https://münchen.example/b",
+            ),
+            ("https://i❤️.ws/love", "https://i❤.ws/love"),
+            ("https://xn--i-7iq.ws/x", "https://i❤.ws/x"),
+            ("../bilder/ü.png", "https://пример.рф/bilder/%C3%BC.png"),
+        ]);
+
+        // Otherwise every host is ASCII, including the base's
+        resolve_all(&[
+            (
+                "https://xn--b1aew.xn--p1ai/news/2",
+                "https://xn--b1aew.xn--p1ai/news/2",
+            ),
+            ("https://EXAMPLE.com/a", "https://example.com/a"),
+            (
+                "../bilder/ü.png",
+                "https://xn--e1afmkfd.xn--p1ai/bilder/%C3%BC.png",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_fix_relative_uris_keeps_non_hierarchical_hrefs() {
+        let hrefs = [
+            "mailto:jane@example.com?subject=Hello there",
+            "MailTo:Jane%20Doe",
+            "tel:+1 555 123 4567",
+            "sms:+15551234?body=See you at 5",
+            "geo:37.786971,-122.399677;u=35",
+            "urn:isbn:0451450523",
+            "magnet:?xt=urn:btih:abc&dn=A File",
+            "#notes",
+            "#",
+        ];
+        let links: String = hrefs
+            .iter()
+            .map(|href| format!(r#"<a href="{href}">link</a>"#))
+            .collect();
+        let document = kuchikikiki::parse_html().one(format!("<body><p>{links}</p></body>"));
+        let base = Url::parse("https://example.com/posts/1/").unwrap();
+        fix_relative_uris(&document, Some(&base), true);
+
+        let resolved: Vec<String> = select_nodes(&document, "a")
+            .iter()
+            .filter_map(|link| get_attribute(link, "href"))
+            .collect();
+        assert_eq!(resolved, hrefs);
+
+        // Scripting links are still neutralized by the sanitizer
+        let sanitized = sanitize_content(r#"<p><a href="javascript:alert(1)">x</a></p>"#);
+        assert!(!sanitized.contains("javascript:"));
+    }
+
     #[test]
     fn test_replace_brs_simple() {
         let html = "Line 1<br><br>Line 2";
@@ -905,6 +4087,57 @@ mod tests {
         assert!(result.contains("<p>amet, consectetur</p>"));
     }
 
+    #[test]
+    fn test_replace_brs_only_splits_top_level() {
+        let html = "<div>Verse one<br>line two<br><br><blockquote><em>Refrain<br><br>again</em></blockquote><pre>a<br><br>b</pre></div>";
+        let result = replace_brs(html);
+        assert_eq!(
+            result,
+            "<div><p>Verse one<br>line two</p>\n<blockquote><em>Refrain<br><br>again</em></blockquote>\n<pre>a<br><br>b</pre></div>"
+        );
+
+        let html = "<p>Stanza <em>one</em>\n<br>line two\n<br>\n<br>Stanza two</p>";
+        assert_eq!(
+            replace_brs(html),
+            "<p>Stanza <em>one</em>\n<br>line two</p>\n<p>Stanza two</p>"
+        );
+    }
+
+    #[test]
+    fn test_strip_tables_of_contents() {
+        let html = r##"<div id="toc"><div class="toctitle"><h2>Contents</h2></div><ul><li><a href="#a">A</a></li><li><a href="#b">B</a></li><li><a href="#c">C</a></li></ul></div><h2>Sections</h2><ul><li><a href="#a">A</a></li><li><a href="#b">B</a></li><li><a href="#c">C</a></li></ul><h2>In this article</h2><ul><li><a href="/a">A</a></li><li><a href="/b">B</a></li><li><a href="/c">C</a></li></ul>"##;
+        let document = kuchikikiki::parse_html().one(html);
+        strip_tables_of_contents(&document);
+        let result = document.to_string();
+        assert!(!result.contains("toc"));
+        assert!(!result.contains("Contents"));
+        assert_eq!(result.matches("<ul>").count(), 2);
+    }
+
+    #[test]
+    fn test_prep_document_replaces_noscript_placeholder() {
+        let html = r#"<html><body><article>
+            <div class="progressiveMedia"><img class="thumbnail" src="https://cdn.example.com/thumb.jpg"></div>
+            <noscript><img class="full" src="https://cdn.example.com/full.jpg"></noscript>
+            <p>Body text</p>
+        </article></body></html>"#;
+        let result = prep_document(html);
+        assert_eq!(result.matches("<img").count(), 1);
+        assert!(result.contains(r#"src="https://cdn.example.com/full.jpg""#));
+        assert!(result.contains(r#"data-old-src="https://cdn.example.com/thumb.jpg""#));
+        assert!(!result.contains("<noscript"));
+        assert!(!result.contains("progressiveMedia"));
+    }
+
+    #[test]
+    fn test_prep_document_unwraps_standalone_noscript_image() {
+        let html = r#"<html><body><p>Intro text</p><img class="lazy"><noscript><img src="photo.png"></noscript></body></html>"#;
+        let result = prep_document(html);
+        assert_eq!(result.matches("<img").count(), 1);
+        assert!(result.contains(r#"src="photo.png""#));
+        assert!(!result.contains("<noscript"));
+    }
+
     #[test]
     fn test_replace_brs_preserves_attributes() {
         let html = r#"<div class="content" id="main">Text 1<br><br>Text 2</div>"#;
@@ -1,7 +1,7 @@
 //! Constants, regular expressions, and static data used throughout the library.
 
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 
 // Bitflags for parsing strategies
 bitflags::bitflags! {
@@ -15,20 +15,31 @@ bitflags::bitflags! {
 
 // Element tags to score by default
 // Note: DIV is included because many modern websites use DIVs for paragraphs
+// and DD so that definition list descriptions count towards their list
 pub static DEFAULT_TAGS_TO_SCORE: Lazy<Vec<&'static str>> = Lazy::new(|| {
     vec![
-        "SECTION", "H2", "H3", "H4", "H5", "H6", "P", "TD", "PRE", "DIV",
+        "SECTION", "H2", "H3", "H4", "H5", "H6", "P", "TD", "PRE", "DIV", "DD",
     ]
 });
 
 // Regular expressions (compiled once)
 pub static REGEXPS: Lazy<RegexPatterns> = Lazy::new(RegexPatterns::new);
 
+// Class/id heuristics, matched together through `RegexPatterns::class_id`
+const UNLIKELY_CANDIDATES: &str = r"(?i)-ad-|ai2html|banner|breadcrumbs|combx|comment|community|cover-wrap|disqus|extra|footer|gdpr|header|legends|menu|related|remark|replies|rss|shoutbox|sidebar|skyscraper|social|sponsor|supplemental|ad-break|agegate|pagination|pager|popup|yom-remote";
+const OK_MAYBE_ITS_A_CANDIDATE: &str = r"(?i)and|article|body|column|content|main|mathjax|shadow";
+const POSITIVE: &str =
+    r"(?i)article|body|content|entry|hentry|h-entry|main|page|pagination|post|text|blog|story";
+const NEGATIVE: &str = r"(?i)-ad-|hidden|^hid$| hid$| hid |^hid |banner|combx|comment|com-|contact|footer|gdpr|masthead|media|meta|outbrain|promo|related|scroll|share|shoutbox|sidebar|skyscraper|sponsor|shopping|tags|widget";
+
+// Longest prefix of a `class` or `id` value the class/id heuristics look at.
+// Utility-CSS frameworks put dozens of classes on every element, and the
+// telling ones (`sidebar`, `article-body`) come first in practice.
+pub const MAX_CLASS_ID_MATCH_BYTES: usize = 256;
+
 pub struct RegexPatterns {
-    pub unlikely_candidates: Regex,
-    pub ok_maybe_its_a_candidate: Regex,
-    pub positive: Regex,
-    pub negative: Regex,
+    /// The unlikely, ok-maybe, positive and negative class/id patterns, in that order
+    pub class_id: RegexSet,
     pub byline: Regex,
     pub normalize: Regex,
     pub videos: Regex,
@@ -42,18 +53,13 @@ pub struct RegexPatterns {
 impl RegexPatterns {
     fn new() -> Self {
         Self {
-            unlikely_candidates: Regex::new(
-                r"(?i)-ad-|ai2html|banner|breadcrumbs|combx|comment|community|cover-wrap|disqus|extra|footer|gdpr|header|legends|menu|related|remark|replies|rss|shoutbox|sidebar|skyscraper|social|sponsor|supplemental|ad-break|agegate|pagination|pager|popup|yom-remote"
-            ).unwrap(),
-            ok_maybe_its_a_candidate: Regex::new(
-                r"(?i)and|article|body|column|content|main|mathjax|shadow"
-            ).unwrap(),
-            positive: Regex::new(
-                r"(?i)article|body|content|entry|hentry|h-entry|main|page|pagination|post|text|blog|story"
-            ).unwrap(),
-            negative: Regex::new(
-                r"(?i)-ad-|hidden|^hid$| hid$| hid |^hid |banner|combx|comment|com-|contact|footer|gdpr|masthead|media|meta|outbrain|promo|related|scroll|share|shoutbox|sidebar|skyscraper|sponsor|shopping|tags|widget"
-            ).unwrap(),
+            class_id: RegexSet::new([
+                UNLIKELY_CANDIDATES,
+                OK_MAYBE_ITS_A_CANDIDATE,
+                POSITIVE,
+                NEGATIVE,
+            ])
+            .unwrap(),
             byline: Regex::new(
                 r"(?i)byline|author|dateline|writtenby|p-author"
             ).unwrap(),
@@ -80,13 +86,143 @@ impl RegexPatterns {
             ).unwrap(),
         }
     }
+
+    /// Match a `class` or `id` value against all class/id heuristics in one pass
+    ///
+    /// Callers cut the value first with [`truncate_class_id`] or
+    /// [`class_id_match_string`].
+    pub fn match_class_id(&self, value: &str) -> ClassIdMatches {
+        let matches = self.class_id.matches(value);
+        ClassIdMatches {
+            unlikely: matches.matched(0),
+            ok_maybe: matches.matched(1),
+            positive: matches.matched(2),
+            negative: matches.matched(3),
+        }
+    }
 }
 
+/// Which class/id heuristics a `class` or `id` value matched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassIdMatches {
+    /// Looks like page furniture (comments, footers, sidebars, ...)
+    pub unlikely: bool,
+    /// Looks like content despite an unlikely match
+    pub ok_maybe: bool,
+    /// Adds to the class weight
+    pub positive: bool,
+    /// Subtracts from the class weight
+    pub negative: bool,
+}
+
+/// Cut a `class` or `id` value to the whole tokens within its first
+/// [`MAX_CLASS_ID_MATCH_BYTES`], so a token straddling the cap can't match as
+/// a fragment (`hidden` cut to `hid`)
+pub fn truncate_class_id(value: &str) -> &str {
+    if value.len() <= MAX_CLASS_ID_MATCH_BYTES {
+        return value;
+    }
+    let mut end = MAX_CLASS_ID_MATCH_BYTES;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    if value[end..].starts_with(char::is_whitespace) {
+        return &value[..end];
+    }
+    value[..end]
+        .rfind(char::is_whitespace)
+        .map_or("", |space| &value[..space])
+}
+
+/// Join `class` and `id` the way the unlikely-candidate check matches them,
+/// each cut with [`truncate_class_id`]
+pub fn class_id_match_string(class: &str, id: &str) -> String {
+    let class = truncate_class_id(class);
+    let id = truncate_class_id(id);
+    let mut match_string = String::with_capacity(class.len() + id.len() + 1);
+    match_string.push_str(class);
+    match_string.push(' ');
+    match_string.push_str(id);
+    match_string
+}
+
+/// Check a `class` and `id` against the unlikely-candidate patterns.
+///
+/// Unlikely means matching `unlikely_override` (or the built-in pattern when
+/// that is `None`) or `extra_unlikely`, and neither the built-in "maybe a
+/// candidate" pattern nor `extra_ok_maybe`. Elements with neither attribute
+/// are never unlikely.
+pub fn is_unlikely_class_id(
+    class: &str,
+    id: &str,
+    unlikely_override: Option<&Regex>,
+    extra_unlikely: Option<&Regex>,
+    extra_ok_maybe: Option<&Regex>,
+) -> bool {
+    if class.is_empty() && id.is_empty() {
+        return false;
+    }
+    let match_string = class_id_match_string(class, id);
+    let builtin = REGEXPS.match_class_id(&match_string);
+    let matches = |regex: Option<&Regex>| regex.is_some_and(|regex| regex.is_match(&match_string));
+    let unlikely = match unlikely_override {
+        Some(regex) => regex.is_match(&match_string),
+        None => builtin.unlikely,
+    };
+    (unlikely || matches(extra_unlikely)) && !(builtin.ok_maybe || matches(extra_ok_maybe))
+}
+
+// Attributes removed from output elements by style cleaning
+pub const PRESENTATIONAL_ATTRIBUTES: &[&str] = &[
+    "align",
+    "background",
+    "bgcolor",
+    "border",
+    "cellpadding",
+    "cellspacing",
+    "frame",
+    "hspace",
+    "rules",
+    "style",
+    "valign",
+    "vspace",
+];
+
+// Elements whose deprecated width/height attributes are removed with styles
+pub const DEPRECATED_SIZE_ATTRIBUTE_ELEMS: &[&str] = &["table", "th", "td", "hr", "pre"];
+
+// Attributes holding a single URL, per element, resolved against the base URL
+pub const URL_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href"]),
+    ("area", &["href"]),
+    ("audio", &["src"]),
+    ("blockquote", &["cite"]),
+    ("button", &["formaction"]),
+    ("del", &["cite"]),
+    ("embed", &["src"]),
+    ("iframe", &["src"]),
+    ("img", &["src"]),
+    ("input", &["src", "formaction"]),
+    ("ins", &["cite"]),
+    ("object", &["data"]),
+    ("q", &["cite"]),
+    ("source", &["src"]),
+    ("track", &["src"]),
+    ("video", &["src", "poster"]),
+];
+
+// Elements whose `srcset` candidate URLs are resolved against the base URL
+pub const SRCSET_ELEMS: &[&str] = &["img", "source"];
+
 // Elements that can be converted from DIV to P
 pub const DIV_TO_P_ELEMS: &[&str] = &[
     "BLOCKQUOTE",
+    "DD",
+    "DETAILS",
     "DL",
+    "DT",
     "DIV",
+    "FIGURE",
     "IMG",
     "OL",
     "P",
@@ -95,6 +231,579 @@ pub const DIV_TO_P_ELEMS: &[&str] = &[
     "UL",
 ];
 
+// Words that make up share bars ("Share on Facebook", "Tweet", "Email", "Print", ...)
+pub const SHARE_WIDGET_WORDS: &[&str] = &[
+    "a",
+    "bluesky",
+    "by",
+    "click",
+    "comment",
+    "comments",
+    "copied",
+    "copy",
+    "e",
+    "email",
+    "facebook",
+    "flipboard",
+    "follow",
+    "friend",
+    "friends",
+    "google",
+    "in",
+    "it",
+    "like",
+    "link",
+    "linkedin",
+    "loading",
+    "mail",
+    "mastodon",
+    "messenger",
+    "more",
+    "new",
+    "on",
+    "opens",
+    "page",
+    "pin",
+    "pinterest",
+    "pocket",
+    "post",
+    "print",
+    "reddit",
+    "retweet",
+    "save",
+    "share",
+    "shared",
+    "shares",
+    "sharing",
+    "skype",
+    "story",
+    "telegram",
+    "this",
+    "threads",
+    "to",
+    "tumblr",
+    "tweet",
+    "twitter",
+    "us",
+    "via",
+    "whatsapp",
+    "window",
+    "with",
+    "x",
+    "your",
+];
+
+// Heading phrases of "related stories" style modules inside article bodies
+pub const RELATED_CONTENT_PHRASES: &[&str] = &[
+    "related",
+    "more from",
+    "read next",
+    "recommended",
+    "you may also like",
+    "you might also like",
+    "more stories",
+];
+
+// Words introducing an author name in a byline ("By", "Par", "Von", "Por", "Di", "Door", "Av",
+// Arabic "بقلم"/"كتب", Hebrew "מאת", Persian "نوشته"/"نویسنده")
+pub const BYLINE_PREFIXES: &[&str] = &[
+    "by",
+    "par",
+    "von",
+    "por",
+    "di",
+    "door",
+    "av",
+    "بقلم",
+    "كتب",
+    "מאת",
+    "نوشته",
+    "نویسنده",
+];
+
+// Conjunctions joining author names in a byline, per language
+pub const AUTHOR_CONJUNCTIONS: &[&str] = &[
+    " and ", " & ", " y ", " e ", " und ", " et ", " en ", "、", " و ",
+];
+
+// Words that rule out a lowercase or all-caps byline remainder as a name ("by the way", "BY CLICKING SUBMIT")
+pub const NON_NAME_WORDS: &[&str] = &[
+    "a", "all", "an", "any", "default", "design", "each", "email", "every", "far", "her", "his",
+    "its", "mail", "means", "my", "no", "now", "our", "phone", "submit", "that", "the", "their",
+    "then", "these", "this", "those", "up", "way", "your",
+];
+
+// Honorifics that may precede an author name ("Dr. Jane Rivera", "Rev. John Smith")
+pub const NAME_HONORIFICS: &[&str] = &[
+    "dr", "prof", "rev", "mr", "mrs", "ms", "mx", "sir", "dame", "fr", "rabbi",
+];
+
+// Lowercase particles inside names ("Ursula von der Leyen", "Ngũgĩ wa Thiong'o", "al-Sayed")
+pub const NAME_PARTICLES: &[&str] = &[
+    "al", "bin", "da", "das", "de", "del", "della", "der", "di", "dos", "du", "el", "ibn", "la",
+    "le", "ten", "ter", "van", "von", "wa", "y", "zu",
+];
+
+// Generational suffixes that are part of a name ("John Smith Jr.")
+pub const NAME_SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii", "iv"];
+
+// Academic and professional credentials that may follow an author name
+pub const NAME_CREDENTIALS: &[&str] = &[
+    "BA", "BSc", "CPA", "DDS", "DO", "DPhil", "DVM", "EdD", "Esq", "JD", "LCSW", "MA", "MBA", "MD",
+    "MPH", "MS", "MSc", "PharmD", "PhD", "RD", "RN",
+];
+
+// Words that rule out a byline as a personal name ("BuzzFeed News Reporter")
+pub const AUTHOR_DISQUALIFIERS: &[&str] = &[
+    "reporter",
+    "editor",
+    "writer",
+    "staff",
+    "senior",
+    "team",
+    "desk",
+    "anchor",
+    "producer",
+    "analyst",
+    "correspondent",
+    "contributor",
+    "technologist",
+    "developer",
+    "developers",
+    "news",
+    "press",
+    "service",
+    "bureau",
+    "foreign",
+    "android",
+    "buzzfeed",
+    "telegraph",
+    "view",
+    "columnist",
+    "critic",
+    "journalist",
+    "photographer",
+];
+
+// Words that mark a byline as an organization credit when two of them appear ("Our Foreign Staff")
+pub const ORG_CREDIT_KEYWORDS: &[&str] = &[
+    "staff",
+    "news",
+    "newsroom",
+    "desk",
+    "team",
+    "press",
+    "service",
+    "bureau",
+    "foreign",
+    "reporter",
+    "reporters",
+    "developers",
+    "android",
+    "buzzfeed",
+    "wire",
+    "agency",
+    "agencies",
+    "telegraph",
+    "our",
+    "editors",
+    "view",
+];
+
+// News agencies credited on their own in place of an author
+pub const NEWS_AGENCIES: &[&str] = &[
+    "afp",
+    "ap",
+    "associated press",
+    "reuters",
+    "bloomberg",
+    "press association",
+    "kyodo",
+    "ansa",
+    "dpa",
+    "upi",
+];
+
+// English month names and abbreviations, matched anywhere in lowercased date text
+pub const MONTH_NAMES: &[&str] = &[
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "may",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "sept",
+    "oct",
+    "nov",
+    "dec",
+    "january",
+    "february",
+    "march",
+    "april",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+// Month names in Spanish, French, German, Italian, Portuguese and Dutch, matched as whole words
+pub const LOCALIZED_MONTH_NAMES: &[&str] = &[
+    // es
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "setiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+    // fr
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+    // de
+    "januar",
+    "jänner",
+    "februar",
+    "märz",
+    "juni",
+    "juli",
+    "oktober",
+    "dezember",
+    // it
+    "gennaio",
+    "febbraio",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "settembre",
+    "ottobre",
+    "dicembre",
+    // pt
+    "janeiro",
+    "fevereiro",
+    "março",
+    "maio",
+    "junho",
+    "julho",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+    // nl
+    "januari",
+    "februari",
+    "maart",
+    "mei",
+    "augustus",
+];
+
+// English weekday names and abbreviations, matched as whole words in lowercased date text
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "mon",
+    "tue",
+    "tues",
+    "wed",
+    "thu",
+    "thur",
+    "thurs",
+    "fri",
+    "sat",
+    "sun",
+];
+
+// Words marking a relative or updated timestamp on their own, per language
+// ("ayer", "heute", "mis à jour", "昨日")
+pub const RELATIVE_TIME_WORDS: &[&str] = &[
+    "ayer",
+    "hoy",
+    "actualizado",
+    "hier",
+    "aujourd'hui",
+    "mis à jour",
+    "gestern",
+    "heute",
+    "aktualisiert",
+    "ieri",
+    "oggi",
+    "aggiornato",
+    "ontem",
+    "hoje",
+    "atualizado",
+    "gisteren",
+    "vandaag",
+    "bijgewerkt",
+    "昨日",
+    "今日",
+    "昨天",
+    "今天",
+    "更新",
+    "어제",
+    "오늘",
+];
+
+// Words marking a relative timestamp when a number goes with them, per language
+// ("hace 2 días", "vor 3 Stunden", "il y a 5 minutes", "2 時間前")
+pub const RELATIVE_TIME_MARKERS: &[&str] = &[
+    "hace",
+    "il y a",
+    "vor",
+    "fa",
+    "há",
+    "atrás",
+    "geleden",
+    "秒前",
+    "分前",
+    "時間前",
+    "日前",
+    "分钟前",
+    "小时前",
+    "天前",
+    "초 전",
+    "분 전",
+    "시간 전",
+    "일 전",
+];
+
+// AM/PM markers and timezone abbreviations that follow a clock time ("8:02 pm UTC", "10:30 IST")
+pub const TIMEZONE_TOKENS: &[&str] = &[
+    "am", "pm", "a.m", "p.m", "utc", "gmt", "z", "est", "edt", "cst", "cdt", "mst", "mdt", "pst",
+    "pdt", "akst", "akdt", "hst", "adt", "nst", "ndt", "bst", "ist", "cet", "cest", "eet", "eest",
+    "wet", "msk", "sast", "pkt", "npt", "ict", "wib", "hkt", "sgt", "pht", "jst", "kst", "awst",
+    "acst", "acdt", "aest", "aedt", "nzst", "nzdt", "brt", "clt",
+];
+
+// Profile URL prefixes of social platforms, dropped from bylines ("instagram.com/author")
+pub const SOCIAL_PROFILE_HOSTS: &[&str] = &[
+    "twitter.com/",
+    "x.com/",
+    "facebook.com/",
+    "instagram.com/",
+    "threads.net/",
+    "mastodon.social/",
+    "bsky.app/profile/",
+    "linkedin.com/in/",
+    "t.me/",
+    "tiktok.com/@",
+    "youtube.com/@",
+];
+
+// Characters browsers substitute for numeric references to C1 control codes,
+// following Windows-1252 ("&#146;" is a right single quote)
+pub const WINDOWS_1252_C1_CHARS: &[(u32, char)] = &[
+    (0x80, '\u{20ac}'),
+    (0x82, '\u{201a}'),
+    (0x83, '\u{0192}'),
+    (0x84, '\u{201e}'),
+    (0x85, '\u{2026}'),
+    (0x86, '\u{2020}'),
+    (0x87, '\u{2021}'),
+    (0x88, '\u{02c6}'),
+    (0x89, '\u{2030}'),
+    (0x8a, '\u{0160}'),
+    (0x8b, '\u{2039}'),
+    (0x8c, '\u{0152}'),
+    (0x8e, '\u{017d}'),
+    (0x91, '\u{2018}'),
+    (0x92, '\u{2019}'),
+    (0x93, '\u{201c}'),
+    (0x94, '\u{201d}'),
+    (0x95, '\u{2022}'),
+    (0x96, '\u{2013}'),
+    (0x97, '\u{2014}'),
+    (0x98, '\u{02dc}'),
+    (0x99, '\u{2122}'),
+    (0x9a, '\u{0161}'),
+    (0x9b, '\u{203a}'),
+    (0x9c, '\u{0153}'),
+    (0x9e, '\u{017e}'),
+    (0x9f, '\u{0178}'),
+];
+
+// Typographic spaces read as a plain space: no-break, en and em, figure, thin,
+// hair, narrow no-break, math and ideographic spaces
+pub const SPACE_LIKE_CHARS: &[char] = &[
+    '\u{00a0}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}', '\u{2005}', '\u{2006}',
+    '\u{2007}', '\u{2008}', '\u{2009}', '\u{200a}', '\u{202f}', '\u{205f}', '\u{3000}',
+];
+
+// Invisible characters dropped from text: zero-width space, word joiner, byte
+// order mark and the bidi marks and isolates around RTL text
+pub const INVISIBLE_CHARS: &[char] = &[
+    '\u{200b}', '\u{2060}', '\u{feff}', '\u{200e}', '\u{200f}', '\u{202a}', '\u{202b}', '\u{202c}',
+    '\u{202d}', '\u{202e}', '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+// Opening and closing characters around navigation menu items ("[Home] [About]")
+pub const MENU_ITEM_ENCLOSURES: &[(char, char)] =
+    &[('[', ']'), ('(', ')'), ('«', '»'), ('‹', '›'), ('<', '>')];
+
+// Characters separating navigation menu items ("Home · About · Contact")
+pub const MENU_SEPARATORS: &[char] = &['·', '•', '|', '/', '»', '›'];
+
+// Navigation menu entries, telling "Home · About · Contact" apart from a list of surnames
+pub const MENU_WORDS: &[&str] = &[
+    "about",
+    "account",
+    "archive",
+    "archives",
+    "blog",
+    "business",
+    "careers",
+    "categories",
+    "contact",
+    "culture",
+    "docs",
+    "download",
+    "downloads",
+    "entertainment",
+    "events",
+    "faq",
+    "forum",
+    "health",
+    "help",
+    "home",
+    "jobs",
+    "login",
+    "menu",
+    "more",
+    "news",
+    "newsletter",
+    "next",
+    "opinion",
+    "photos",
+    "podcasts",
+    "politics",
+    "prev",
+    "previous",
+    "pricing",
+    "privacy",
+    "products",
+    "register",
+    "science",
+    "search",
+    "services",
+    "shop",
+    "sitemap",
+    "sport",
+    "sports",
+    "store",
+    "subscribe",
+    "support",
+    "tags",
+    "tech",
+    "technology",
+    "terms",
+    "top",
+    "travel",
+    "video",
+    "videos",
+    "weather",
+    "world",
+];
+
+// Labels left behind where an ad used to be, removed when they make up a whole block
+pub const AD_LABELS: &[&str] = &[
+    "advertisement",
+    "advertising",
+    "sponsored",
+    "sponsored content",
+    "publicité",
+    "publicidad",
+    "pubblicità",
+    "anzeige",
+    "werbung",
+    "реклама",
+    "广告",
+    "広告",
+];
+
+// Query parameters removed from links by `strip_tracking_params`: any name
+// starting with one of the prefixes, or equal to one of the names
+pub const TRACKING_PARAM_PREFIXES: &[&str] = &["utm_"];
+pub const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "msclkid", "mc_cid", "mc_eid", "igshid", "ref", "_hsenc", "_hsmi",
+];
+
+// Inline SVGs with more elements than this are replaced by their label text
+pub const MAX_INLINE_SVG_ELEMENTS: usize = 2000;
+
+// Elements nested deeper than this are unwrapped before extraction. Cleanup
+// looks at each element's whole subtree, so the cost grows quadratically with
+// depth: in release builds a chain of 256 divs parses in about 0.5 s, 512 in
+// 2 s, 1,000 in 11 s and 2,000 in over a minute. The deepest fixture is 38.
+pub const MAX_PROCESSED_NESTING_DEPTH: usize = 256;
+
+// Readerable score multipliers for paragraphs whose nearest landmark is an
+// <article>, <main> or [role=main], or an <aside>, <nav> or <footer>
+pub const READERABLE_LANDMARK_BONUS: f64 = 1.5;
+pub const READERABLE_BOILERPLATE_PENALTY: f64 = 0.75;
+
+// Phrases from consent screens, login walls and bot checks. Paragraphs with two
+// or more of them count against the readerable score
+pub const INTERSTITIAL_PHRASES: &[&str] = &[
+    "we use cookies",
+    "accept cookies",
+    "accept all",
+    "reject all",
+    "cookie settings",
+    "manage preferences",
+    "manage cookies",
+    "our partners",
+    "legitimate interest",
+    "withdraw your consent",
+    "sign in to continue",
+    "log in to continue",
+    "subscribe to continue",
+    "create a free account",
+    "enable javascript",
+    "javascript is disabled",
+    "verify you are human",
+    "are you a robot",
+    "checking your browser",
+    "complete the security check",
+];
+
+// Share of a paragraph's readerable score subtracted for each interstitial
+// signal around it
+pub const INTERSTITIAL_SIGNAL_PENALTY: f64 = 0.5;
+
+// Share of the document's text above which a `<form>` is taken for a page
+// wrapper rather than an interstitial, and being inside it is no signal alone
+pub const INTERSTITIAL_FORM_SHARE: f64 = 0.5;
+
+// Readerable thresholds are scaled by this for inputs that look cut off, when
+// `assume_truncated` is set
+pub const TRUNCATED_THRESHOLD_SCALE: f64 = 0.5;
+
 
 // Phrasing (inline) elements
 pub const PHRASING_ELEMS: &[&str] = &[
@@ -103,3 +812,17 @@ pub const PHRASING_ELEMS: &[&str] = &[
     "OBJECT", "OUTPUT", "PROGRESS", "Q", "RUBY", "SAMP", "SCRIPT", "SELECT", "SMALL", "SPAN",
     "STRONG", "SUB", "SUP", "TEXTAREA", "TIME", "VAR", "WBR",
 ];
+
+// Inline elements that carry meaning and are never unwrapped. Their key
+// attributes (`title` on abbr and dfn, `datetime` on time, ins and del,
+// `value` on data, `cite` on q) pass through attribute cleanup untouched.
+pub const SEMANTIC_INLINE_ELEMS: &[&str] = &[
+    "abbr", "b", "bdi", "bdo", "cite", "code", "data", "del", "dfn", "em", "i", "ins", "kbd",
+    "mark", "q", "s", "samp", "small", "strong", "sub", "sup", "time", "u", "var",
+];
+
+// Purely presentational inline wrappers, unwrapped once no meaningful attribute is left
+pub const PRESENTATIONAL_WRAPPER_ELEMS: &[&str] = &["span", "font"];
+
+// Attributes of `<font>` (kept when it is renamed to `<span>`) that only affect styling
+pub const FONT_ATTRIBUTES: &[&str] = &["color", "face", "size"];
@@ -1,11 +1,18 @@
 //! Core content extraction algorithm (_grabArticle implementation).
 
-use crate::constants::{ParseFlags, DEFAULT_TAGS_TO_SCORE, REGEXPS};
+use crate::article::Timings;
+use crate::constants::{class_id_match_string, ParseFlags, DEFAULT_TAGS_TO_SCORE, REGEXPS};
+use crate::dom_utils::{self, TextStats};
 use crate::error::Result;
 use crate::options::ReadabilityOptions;
-use crate::{dom_utils, scoring};
+use crate::scoring;
+use crate::utils;
+use ego_tree::iter::Edge;
+use ego_tree::NodeId;
 use scraper::{ElementRef, Html, Selector};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt::Write;
 use v_htmlescape::escape;
 
 /// Represents an extraction attempt
@@ -20,21 +27,32 @@ struct Attempt {
 /// Implements Mozilla's Readability algorithm with adaptive flag removal.
 /// If extraction fails with strict settings, retries with progressively
 /// looser criteria until content is found or all options are exhausted.
-pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Option<String>> {
+pub fn grab_article(
+    document: &Html,
+    options: &ReadabilityOptions,
+    timings: &mut Option<Timings>,
+) -> Result<Option<String>> {
     let mut attempts = Vec::new();
     let mut flags =
         ParseFlags::STRIP_UNLIKELYS | ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
+    // Attempts only read the document, so they share one element index
+    let index = ElementIndex::new(document);
 
     // Try extraction with different flag combinations
     // Order: All flags -> Remove STRIP_UNLIKELYS -> Remove WEIGHT_CLASSES -> Remove CLEAN_CONDITIONALLY
     for attempt_num in 0..4 {
-        let attempt_result = try_extract_with_flags(document, options, flags)?;
+        let attempt_result = try_extract_with_flags(document, &index, options, flags, timings)?;
 
         if let Some(content) = attempt_result {
-            let text_length = extract_text_length(&content);
+            let (text_length, mostly_cjk) = extract_text_stats(&content);
 
             // Check if we have enough content
-            if text_length >= options.char_threshold {
+            let char_threshold = utils::cjk_length_threshold(
+                options.char_threshold,
+                options.cjk_length_factor,
+                mostly_cjk,
+            );
+            if text_length >= char_threshold {
                 return Ok(Some(content));
             }
 
@@ -56,7 +74,7 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
 
     // No successful extraction with threshold, return longest attempt
     if !attempts.is_empty() {
-        attempts.sort_by(|a, b| b.text_length.cmp(&a.text_length));
+        attempts.sort_by_key(|attempt| std::cmp::Reverse(attempt.text_length));
         if attempts[0].text_length > 0 {
             return Ok(Some(attempts[0].content.clone()));
         }
@@ -68,36 +86,57 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
 /// Try to extract article content with specific flags
 fn try_extract_with_flags(
     document: &Html,
+    index: &ElementIndex,
     options: &ReadabilityOptions,
     flags: ParseFlags,
+    timings: &mut Option<Timings>,
 ) -> Result<Option<String>> {
-    let candidates = find_candidates(document, options, flags)?;
+    let candidates = Timings::record(
+        timings,
+        |t| &mut t.prep,
+        || find_candidates(document, options, flags),
+    )?;
     if candidates.is_empty() {
         return Ok(None);
     }
 
-    let mut scored_candidates = score_candidates(document, candidates, options, flags);
-    apply_link_density_penalty(document, &mut scored_candidates);
-
-    if let Some(best) = find_best_candidate(document, &scored_candidates, options) {
-        let content = extract_article_content(document, best, &scored_candidates, options)?;
+    let scored_candidates = Timings::record(
+        timings,
+        |t| &mut t.scoring,
+        || {
+            let mut scored_candidates = score_candidates(index, candidates, options, flags);
+            apply_link_density_penalty(index, &mut scored_candidates);
+            scored_candidates
+        },
+    );
+
+    let best = Timings::record(
+        timings,
+        |t| &mut t.candidate_selection,
+        || find_best_candidate(index, &scored_candidates, options),
+    );
+    if let Some(best) = best {
+        let content = Timings::record(
+            timings,
+            |t| &mut t.serialization,
+            || extract_article_content(index, best, &scored_candidates, options, flags),
+        )?;
         return Ok(Some(content));
     }
 
     Ok(None)
 }
 
-/// Extract plain text length from HTML content
-fn extract_text_length(html: &str) -> usize {
+/// Length of the text in HTML content, and whether it is mostly CJK
+fn extract_text_stats(html: &str) -> (usize, bool) {
     let doc = Html::parse_fragment(html);
-    let text: String = doc.root_element().text().collect();
-    text.trim().len()
+    dom_utils::get_text_content_stats(doc.root_element())
 }
 
 /// Find all potential content candidates in the document
 fn find_candidates<'a>(
     document: &'a Html,
-    _options: &ReadabilityOptions,
+    options: &ReadabilityOptions,
     flags: ParseFlags,
 ) -> Result<Vec<ElementRef<'a>>> {
     let mut candidates = Vec::new();
@@ -111,17 +150,12 @@ fn find_candidates<'a>(
         if flags.contains(ParseFlags::STRIP_UNLIKELYS) {
             let class = p.value().attr("class").unwrap_or("");
             let id = p.value().attr("id").unwrap_or("");
-            let match_string = format!("{class} {id}");
-
-            if REGEXPS.unlikely_candidates.is_match(&match_string)
-                && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-            {
+            if options.is_unlikely_candidate(class, id) {
                 continue;
             }
         }
 
-        let text = dom_utils::get_inner_text(p, false);
-        if text.len() < 25 {
+        if dom_utils::get_inner_text_length(p) < 25 {
             continue;
         }
 
@@ -138,17 +172,12 @@ fn find_candidates<'a>(
             if flags.contains(ParseFlags::STRIP_UNLIKELYS) {
                 let class = elem.value().attr("class").unwrap_or("");
                 let id = elem.value().attr("id").unwrap_or("");
-                let match_string = format!("{class} {id}");
-
-                if REGEXPS.unlikely_candidates.is_match(&match_string)
-                    && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-                {
+                if options.is_unlikely_candidate(class, id) {
                     continue;
                 }
             }
 
-            let text = dom_utils::get_inner_text(elem, false);
-            if text.len() >= 25 {
+            if dom_utils::get_inner_text_length(elem) >= 25 {
                 candidates.push(elem);
             }
         }
@@ -159,7 +188,7 @@ fn find_candidates<'a>(
 
 /// Score all candidates and their ancestors
 fn score_candidates<'a>(
-    _document: &'a Html,
+    index: &ElementIndex<'a>,
     candidates: Vec<ElementRef<'a>>,
     options: &ReadabilityOptions,
     flags: ParseFlags,
@@ -167,8 +196,10 @@ fn score_candidates<'a>(
     let mut scores: HashMap<String, f64> = HashMap::new();
 
     for candidate in candidates {
-        let content_score =
-            scoring::calculate_content_score(candidate, options.link_density_modifier);
+        let content_score = scoring::calculate_content_score(
+            &index.text_stats(candidate),
+            options.link_density_modifier,
+        );
 
         if content_score == 0.0 {
             continue;
@@ -210,10 +241,10 @@ fn score_candidates<'a>(
 }
 
 /// Adjust candidate scores based on their actual link density.
-fn apply_link_density_penalty(document: &Html, scores: &mut HashMap<String, f64>) {
+fn apply_link_density_penalty(index: &ElementIndex, scores: &mut HashMap<String, f64>) {
     for (element_id, score) in scores.iter_mut() {
-        if let Some(element) = find_element_by_id(document, element_id) {
-            let penalty = (1.0 - dom_utils::get_link_density(element)).max(0.0);
+        if let Some(element) = index.get(element_id) {
+            let penalty = (1.0 - index.text_stats(element).link_density()).max(0.0);
             *score *= penalty;
         }
     }
@@ -221,7 +252,7 @@ fn apply_link_density_penalty(document: &Html, scores: &mut HashMap<String, f64>
 
 /// Find the best candidate based on scores, promoting parents when needed.
 fn find_best_candidate(
-    document: &Html,
+    index: &ElementIndex,
     scores: &HashMap<String, f64>,
     options: &ReadabilityOptions,
 ) -> Option<String> {
@@ -242,8 +273,8 @@ fn find_best_candidate(
     let mut best_score = top_candidates[0].1;
 
     for (candidate_id, candidate_score) in &top_candidates {
-        if let Some(elem) = find_element_by_id(document, candidate_id) {
-            if is_viable_best_candidate(elem, *candidate_score) {
+        if let Some(elem) = index.get(candidate_id) {
+            if is_viable_best_candidate(index, elem, *candidate_score) {
                 best_id = candidate_id.clone();
                 best_score = *candidate_score;
                 break;
@@ -252,30 +283,28 @@ fn find_best_candidate(
     }
 
     if let Some(promoted) =
-        promote_shared_top_candidate_parent(document, &best_id, best_score, &top_candidates)
+        promote_shared_top_candidate_parent(index, &best_id, best_score, &top_candidates)
     {
         best_id = promoted;
         best_score = scores.get(&best_id).copied().unwrap_or(best_score);
     }
 
-    if let Some(promoted) = promote_high_scoring_parents(document, &best_id, best_score, scores) {
+    if let Some(promoted) = promote_high_scoring_parents(index, &best_id, best_score, scores) {
         best_id = promoted;
         best_score = scores.get(&best_id).copied().unwrap_or(best_score);
     }
 
     // If the best candidate lives inside a single-child parent chain, walk up so we can pull siblings later.
-    if let Some(promoted) = promote_single_child_parents(document, &best_id) {
+    if let Some(promoted) = promote_single_child_parents(index, &best_id) {
         best_id = promoted;
     }
 
-    if let Some(promoted) = promote_dense_wrapper_child(document, &best_id, scores, &sorted_scores)
-    {
+    if let Some(promoted) = promote_dense_wrapper_child(index, &best_id, scores, &sorted_scores) {
         best_id = promoted;
         best_score = scores.get(&best_id).copied().unwrap_or(best_score);
     }
 
-    if let Some(promoted) =
-        promote_semantic_descendant(document, &best_id, best_score, &sorted_scores)
+    if let Some(promoted) = promote_semantic_descendant(index, &best_id, best_score, &sorted_scores)
     {
         best_id = promoted;
     }
@@ -284,9 +313,9 @@ fn find_best_candidate(
 }
 
 /// Promote parent nodes when the current candidate is the only child, mirroring Mozilla's logic.
-fn promote_single_child_parents(document: &Html, best_id: &str) -> Option<String> {
+fn promote_single_child_parents(index: &ElementIndex, best_id: &str) -> Option<String> {
     let mut promoted_id = None;
-    let mut current = find_element_by_id(document, best_id)?;
+    let mut current = index.get(best_id)?;
 
     while let Some(parent_node) = current.parent() {
         let Some(parent) = ElementRef::wrap(parent_node) else {
@@ -312,7 +341,7 @@ fn promote_single_child_parents(document: &Html, best_id: &str) -> Option<String
 
 /// Promote a higher scoring parent when it looks more article-like than the current candidate.
 fn promote_shared_top_candidate_parent(
-    document: &Html,
+    index: &ElementIndex,
     best_id: &str,
     best_score: f64,
     top_candidates: &[(String, f64)],
@@ -329,7 +358,7 @@ fn promote_shared_top_candidate_parent(
             continue;
         }
 
-        let Some(candidate_elem) = find_element_by_id(document, candidate_id) else {
+        let Some(candidate_elem) = index.get(candidate_id) else {
             continue;
         };
         let ancestors = dom_utils::get_node_ancestors(candidate_elem, None);
@@ -348,7 +377,8 @@ fn promote_shared_top_candidate_parent(
         return None;
     }
 
-    let mut parent_opt = find_element_by_id(document, best_id)
+    let mut parent_opt = index
+        .get(best_id)
         .and_then(|node| node.parent())
         .and_then(ElementRef::wrap)?;
 
@@ -373,12 +403,12 @@ fn promote_shared_top_candidate_parent(
 }
 
 fn promote_high_scoring_parents(
-    document: &Html,
+    index: &ElementIndex,
     best_id: &str,
     best_score: f64,
     scores: &HashMap<String, f64>,
 ) -> Option<String> {
-    let mut current = find_element_by_id(document, best_id)?;
+    let mut current = index.get(best_id)?;
     let mut last_score = best_score;
     let score_threshold = best_score / 3.0;
 
@@ -415,7 +445,7 @@ fn promote_high_scoring_parents(
             break;
         }
 
-        let parent_link_density = dom_utils::get_link_density(parent);
+        let parent_link_density = index.text_stats(parent).link_density();
         if parent_link_density > 0.33 {
             current = parent;
             continue;
@@ -434,12 +464,12 @@ fn promote_high_scoring_parents(
 
 /// If our best candidate is a wrapper with high link density, look for a better child candidate.
 fn promote_dense_wrapper_child(
-    document: &Html,
+    index: &ElementIndex,
     best_id: &str,
     scores: &HashMap<String, f64>,
     sorted_scores: &[(&String, &f64)],
 ) -> Option<String> {
-    let best_elem = find_element_by_id(document, best_id)?;
+    let best_elem = index.get(best_id)?;
 
     let tag = best_elem.value().name().to_uppercase();
     if matches!(tag.as_str(), "ARTICLE" | "SECTION" | "MAIN") {
@@ -447,7 +477,7 @@ fn promote_dense_wrapper_child(
     }
 
     let parent_score = scores.get(best_id).copied().unwrap_or(0.0);
-    let best_link_density = dom_utils::get_link_density(best_elem);
+    let best_link_density = index.text_stats(best_elem).link_density();
 
     let mut fallback = None;
 
@@ -455,7 +485,7 @@ fn promote_dense_wrapper_child(
         if *candidate_id == best_id {
             continue;
         }
-        let Some(candidate_elem) = find_element_by_id(document, candidate_id) else {
+        let Some(candidate_elem) = index.get(candidate_id) else {
             continue;
         };
 
@@ -463,12 +493,13 @@ fn promote_dense_wrapper_child(
             continue;
         }
 
-        let text_len = dom_utils::get_inner_text(candidate_elem, false).len();
+        let stats = index.text_stats(candidate_elem);
+        let text_len = stats.text_length;
         if text_len < 160 {
             continue;
         }
 
-        let link_density = dom_utils::get_link_density(candidate_elem);
+        let link_density = stats.link_density();
         if link_density >= 0.35 {
             continue;
         }
@@ -480,12 +511,11 @@ fn promote_dense_wrapper_child(
         let candidate_weight =
             scoring::get_class_weight(candidate_elem, ParseFlags::WEIGHT_CLASSES);
         if candidate_weight < 0 {
-            let match_string = format!(
-                "{} {}",
+            let match_string = class_id_match_string(
                 candidate_elem.value().attr("class").unwrap_or(""),
-                candidate_elem.value().attr("id").unwrap_or("")
+                candidate_elem.value().attr("id").unwrap_or(""),
             );
-            if !REGEXPS.positive.is_match(&match_string) {
+            if !REGEXPS.match_class_id(&match_string).positive {
                 continue;
             }
         }
@@ -496,7 +526,7 @@ fn promote_dense_wrapper_child(
             continue;
         }
 
-        if dom_utils::get_link_density(candidate_elem) >= best_link_density {
+        if link_density >= best_link_density {
             continue;
         }
 
@@ -520,7 +550,7 @@ fn promote_dense_wrapper_child(
 }
 
 fn promote_semantic_descendant(
-    document: &Html,
+    index: &ElementIndex,
     best_id: &str,
     best_score: f64,
     sorted_scores: &[(&String, &f64)],
@@ -529,7 +559,7 @@ fn promote_semantic_descendant(
         return None;
     }
 
-    let best_elem = find_element_by_id(document, best_id)?;
+    let best_elem = index.get(best_id)?;
 
     let class_id = format!(
         "{} {}",
@@ -565,7 +595,7 @@ fn promote_semantic_descendant(
             continue;
         }
 
-        let Some(candidate_elem) = find_element_by_id(document, candidate_id) else {
+        let Some(candidate_elem) = index.get(candidate_id) else {
             continue;
         };
 
@@ -573,13 +603,13 @@ fn promote_semantic_descendant(
             continue;
         }
 
-        let text = dom_utils::get_inner_text(candidate_elem, false);
-        let text_len = text.len();
+        let stats = index.text_stats(candidate_elem);
+        let text_len = stats.text_length;
         if text_len < 200 {
             continue;
         }
 
-        let link_density = dom_utils::get_link_density(candidate_elem);
+        let link_density = stats.link_density();
         if link_density > 0.45 {
             continue;
         }
@@ -628,12 +658,13 @@ fn promote_semantic_descendant(
 ///    - Are good paragraphs (low link density, decent text length)
 /// 4. Aggregate all content together
 fn extract_article_content(
-    document: &Html,
+    index: &ElementIndex,
     best_candidate_id: String,
     all_scores: &HashMap<String, f64>,
-    _options: &ReadabilityOptions,
+    options: &ReadabilityOptions,
+    flags: ParseFlags,
 ) -> Result<String> {
-    let Some(best_candidate) = find_element_by_id(document, &best_candidate_id) else {
+    let Some(best_candidate) = index.get(&best_candidate_id) else {
         return Ok(String::new());
     };
 
@@ -648,11 +679,14 @@ fn extract_article_content(
     let mut article_content = Vec::new();
     let Some(parent) = best_candidate.parent() else {
         // No parent, just return the best candidate
-        let html = element_to_html(best_candidate);
+        let html = element_to_html(best_candidate, options, flags);
         let html = crate::cleaner::replace_brs(&html);
         return Ok(html);
     };
 
+    // Direction the siblings inherit from the container, kept on the output roots
+    let inherited_dir = ElementRef::wrap(parent).and_then(dom_utils::get_element_direction);
+
     for child_node in parent.children() {
         let Some(sibling) = ElementRef::wrap(child_node) else {
             continue;
@@ -663,6 +697,10 @@ fn extract_article_content(
 
         let should_include = if is_best_candidate {
             true
+        } else if flags.contains(ParseFlags::STRIP_UNLIKELYS)
+            && is_unlikely_element(sibling, options)
+        {
+            false
         } else {
             let sibling_score = all_scores.get(&sibling_id).copied().unwrap_or(0.0);
             let class_bonus = if !best_candidate_class.is_empty() {
@@ -680,16 +718,21 @@ fn extract_article_content(
 
             let weighted_sibling_score = sibling_score + class_bonus;
             if weighted_sibling_score >= sibling_score_threshold
-                || is_good_sibling_paragraph(sibling)
+                || is_good_sibling_paragraph(index, sibling, options)
             {
                 true
             } else {
-                should_keep_block_element(sibling, best_score)
+                should_keep_block_element(index, sibling, best_score)
             }
         };
 
         if should_include {
-            let mut sibling_html = element_to_html(sibling);
+            let mut sibling_html = element_to_html(sibling, options, flags);
+            if let Some(dir) = &inherited_dir {
+                if sibling.value().attr("dir").is_none() {
+                    sibling_html = add_dir_attribute(&sibling_html, dir);
+                }
+            }
             sibling_html = crate::cleaner::replace_brs(&sibling_html);
 
             if !sibling_html.trim().is_empty() {
@@ -701,6 +744,22 @@ fn extract_article_content(
     Ok(article_content.join("\n"))
 }
 
+/// Add a `dir` attribute to the root tag of serialized element HTML
+fn add_dir_attribute(html: &str, dir: &str) -> String {
+    let Some(rest) = html.strip_prefix('<') else {
+        return html.to_string();
+    };
+    let name_end = rest
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(rest.len());
+    format!(
+        "<{} dir=\"{}\"{}",
+        &rest[..name_end],
+        dir,
+        &rest[name_end..]
+    )
+}
+
 /// Check if a sibling element is a "good paragraph" worth including
 ///
 /// A good paragraph is one that:
@@ -708,34 +767,37 @@ fn extract_article_content(
 /// - Has reasonable text length (> 80 chars)
 /// - Has low link density (< 33%)
 /// - Looks like actual content, not navigation
-fn is_good_sibling_paragraph(element: ElementRef) -> bool {
+fn is_good_sibling_paragraph(
+    index: &ElementIndex,
+    element: ElementRef,
+    options: &ReadabilityOptions,
+) -> bool {
     let tag_name = element.value().name();
     if tag_name != "p" {
         return false;
     }
 
-    let text = dom_utils::get_inner_text(element, false);
-    let text_length = text.len();
+    let stats = index.text_stats(element);
+    let text_length = stats.text_length;
     if text_length == 0 {
         return false;
     }
 
     let class = element.value().attr("class").unwrap_or("");
     let id = element.value().attr("id").unwrap_or("");
-    let match_string = format!("{class} {id}");
-
-    if REGEXPS.unlikely_candidates.is_match(&match_string)
-        && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-    {
+    if options.is_unlikely_candidate(class, id) {
         return false;
     }
 
-    let link_density = dom_utils::get_link_density(element);
+    let link_density = stats.link_density();
     if text_length > 80 && link_density < 0.25 {
         return true;
     }
 
-    if text_length <= 80 && link_density == 0.0 && has_sentence_boundary(&text) {
+    if text_length <= 80
+        && link_density == 0.0
+        && has_sentence_boundary(&dom_utils::get_inner_text(element, false))
+    {
         return true;
     }
 
@@ -743,7 +805,7 @@ fn is_good_sibling_paragraph(element: ElementRef) -> bool {
 }
 
 /// Determine whether a non-paragraph block should be kept during sibling aggregation.
-fn should_keep_block_element(element: ElementRef, best_score: f64) -> bool {
+fn should_keep_block_element(index: &ElementIndex, element: ElementRef, best_score: f64) -> bool {
     use scraper::Selector;
     let tag = element.value().name().to_lowercase();
 
@@ -759,9 +821,9 @@ fn should_keep_block_element(element: ElementRef, best_score: f64) -> bool {
         return false;
     }
 
-    let text = dom_utils::get_inner_text(element, false);
-    let text_length = text.len();
-    let link_density = dom_utils::get_link_density(element);
+    let stats = index.text_stats(element);
+    let text_length = stats.text_length;
+    let link_density = stats.link_density();
 
     if text_length == 0 || link_density > 0.6 {
         return false;
@@ -810,7 +872,7 @@ const VOID_ELEMENTS: &[&str] = &[
 ];
 
 /// Check if a tag is a void element (self-closing)
-fn is_void_element(tag_name: &str) -> bool {
+pub(crate) fn is_void_element(tag_name: &str) -> bool {
     VOID_ELEMENTS.contains(&tag_name.to_lowercase().as_str())
 }
 
@@ -842,10 +904,7 @@ fn should_convert_div_to_p(element: ElementRef) -> bool {
 
 /// Count element children (ignoring text/comment nodes).
 fn count_element_children(element: ElementRef) -> usize {
-    element
-        .children()
-        .filter_map(ElementRef::wrap)
-        .count()
+    element.children().filter_map(ElementRef::wrap).count()
 }
 
 fn is_descendant_of(element: ElementRef, ancestor_id: &str) -> bool {
@@ -863,14 +922,14 @@ fn is_descendant_of(element: ElementRef, ancestor_id: &str) -> bool {
     false
 }
 
-fn is_viable_best_candidate(element: ElementRef, score: f64) -> bool {
-    let text = dom_utils::get_inner_text(element, false);
-    let text_length = text.len();
+fn is_viable_best_candidate(index: &ElementIndex, element: ElementRef, score: f64) -> bool {
+    let stats = index.text_stats(element);
+    let text_length = stats.text_length;
     if text_length < 150 && score < 50.0 {
         return false;
     }
 
-    let link_density = dom_utils::get_link_density(element);
+    let link_density = stats.link_density();
     if link_density > 0.6 {
         return false;
     }
@@ -898,70 +957,165 @@ fn is_viable_best_candidate(element: ElementRef, score: f64) -> bool {
 ///
 /// Additionally, this function implements DIV→P transformation: DIVs without
 /// block-level children are converted to P tags to match Mozilla's behavior.
-fn element_to_html(element: ElementRef) -> String {
+fn element_to_html(element: ElementRef, options: &ReadabilityOptions, flags: ParseFlags) -> String {
     use scraper::node::Node;
-    if !dom_utils::is_probably_visible(element) {
-        return String::new();
-    }
-
-    let elem_data = element.value();
-    let original_tag_name = elem_data.name();
-
-    let tag_name = if should_convert_div_to_p(element) {
-        "p"
-    } else {
-        original_tag_name
-    };
 
     let mut html = String::new();
-    html.push_str(&format!("<{tag_name}"));
-
-    for (name, value) in elem_data.attrs.iter() {
-        html.push_str(&format!(" {}=\"{}\"", name.local, value));
-    }
-
-    if is_void_element(tag_name) {
-        html.push_str(" />");
-        return html;
-    }
-
-    html.push('>');
-
-    for child in element.children() {
-        match child.value() {
-            Node::Element(_) => {
-                if let Some(child_elem) = ElementRef::wrap(child) {
-                    let child_html = element_to_html(child_elem);
-                    if !child_html.is_empty() {
-                        html.push_str(&child_html);
+    // Closing tags of the open elements; walked iteratively so deeply nested
+    // markup can't overflow the stack
+    let mut open_tags: Vec<&str> = Vec::new();
+    // Depth inside a skipped (hidden, unlikely or void) element
+    let mut skip_depth = 0usize;
+
+    for edge in element.traverse() {
+        match edge {
+            Edge::Open(node) => {
+                if skip_depth > 0 {
+                    if node.value().is_element() {
+                        skip_depth += 1;
                     }
+                    continue;
+                }
+                match node.value() {
+                    Node::Element(elem_data) => {
+                        let Some(current) = ElementRef::wrap(node) else {
+                            continue;
+                        };
+                        let is_unlikely = node.id() != element.id()
+                            && flags.contains(ParseFlags::STRIP_UNLIKELYS)
+                            && is_unlikely_element(current, options);
+                        if is_unlikely || !dom_utils::is_probably_visible(current) {
+                            skip_depth = 1;
+                            continue;
+                        }
+
+                        let tag_name = if should_convert_div_to_p(current) {
+                            "p"
+                        } else {
+                            elem_data.name()
+                        };
+
+                        html.push('<');
+                        html.push_str(tag_name);
+                        for (name, value) in elem_data.attrs.iter() {
+                            html.push(' ');
+                            // Foreign attributes such as `xlink:href` in inline SVG
+                            if let Some(prefix) = &name.prefix {
+                                html.push_str(prefix);
+                                html.push(':');
+                            }
+                            html.push_str(&name.local);
+                            html.push_str("=\"");
+                            html.push_str(value);
+                            html.push('"');
+                        }
+
+                        if is_void_element(tag_name) {
+                            html.push_str(" />");
+                            skip_depth = 1;
+                            continue;
+                        }
+
+                        html.push('>');
+                        open_tags.push(tag_name);
+                    }
+                    Node::Text(text) => {
+                        let _ = write!(html, "{}", escape(&text.text));
+                    }
+                    Node::Comment(comment) => {
+                        html.push_str("<!--");
+                        html.push_str(&comment.comment);
+                        html.push_str("-->");
+                    }
+                    _ => {}
                 }
             }
-            Node::Text(text) => {
-                html.push_str(&escape(&text.text).to_string());
-            }
-            Node::Comment(comment) => {
-                html.push_str(&format!("<!--{}-->", comment.comment));
+            Edge::Close(node) => {
+                if !node.value().is_element() {
+                    continue;
+                }
+                if skip_depth > 0 {
+                    skip_depth -= 1;
+                } else if let Some(tag_name) = open_tags.pop() {
+                    html.push_str("</");
+                    html.push_str(tag_name);
+                    html.push('>');
+                }
             }
-            _ => {}
         }
     }
 
-    html.push_str(&format!("</{tag_name}>"));
     html
 }
 
+/// Check if an element inside the article is unlikely to be content
+///
+/// Mirrors the node removal in Mozilla's _grabArticle: links, the body and
+/// anything inside a table or code block are never treated as unlikely.
+/// Only runs when `extra_unlikely_candidates` is set, so the output for the
+/// built-in patterns alone stays as it was.
+fn is_unlikely_element(element: ElementRef, options: &ReadabilityOptions) -> bool {
+    if options.extra_unlikely_candidates.is_none() {
+        return false;
+    }
+    let tag_name = element.value().name();
+    if tag_name == "a" || tag_name == "body" {
+        return false;
+    }
+
+    let class = element.value().attr("class").unwrap_or("");
+    let id = element.value().attr("id").unwrap_or("");
+    if !options.is_unlikely_candidate(class, id) {
+        return false;
+    }
+
+    !element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| matches!(ancestor.value().name(), "table" | "code"))
+}
+
 fn get_element_id(element: &ElementRef) -> String {
     format!("{:?}", element.id())
 }
 
-/// Find an element by our generated ID
-fn find_element_by_id<'a>(document: &'a Html, id: &str) -> Option<ElementRef<'a>> {
-    // This is a simplified approach - in production we'd need better element tracking
-    // For now, search for elements and match by generated ID
+/// Elements of a document keyed by our generated ID, with their text
+/// measurements memoized as scoring asks for them
+///
+/// The document isn't modified during extraction, so measurements stay valid
+/// for every attempt.
+struct ElementIndex<'a> {
+    elements: HashMap<String, ElementRef<'a>>,
+    stats: RefCell<HashMap<NodeId, TextStats>>,
+}
 
-    let all_selector = Selector::parse("*").unwrap();
-    document.select(&all_selector).find(|&elem| get_element_id(&elem) == id)
+impl<'a> ElementIndex<'a> {
+    fn new(document: &'a Html) -> Self {
+        let all_selector = Selector::parse("*").unwrap();
+        let elements = document
+            .select(&all_selector)
+            .map(|elem| (get_element_id(&elem), elem))
+            .collect();
+        Self {
+            elements,
+            stats: RefCell::default(),
+        }
+    }
+
+    /// Find an element by our generated ID
+    fn get(&self, id: &str) -> Option<ElementRef<'a>> {
+        self.elements.get(id).copied()
+    }
+
+    /// Text length, link text length and comma count of an element
+    fn text_stats(&self, element: ElementRef) -> TextStats {
+        if let Some(stats) = self.stats.borrow().get(&element.id()) {
+            return *stats;
+        }
+        let stats = TextStats::measure(element);
+        self.stats.borrow_mut().insert(element.id(), stats);
+        stats
+    }
 }
 
 #[cfg(test)]
@@ -986,7 +1140,7 @@ mod tests {
         let document = Html::parse_document(html);
         let options = ReadabilityOptions::builder().char_threshold(100).build();
 
-        let result = grab_article(&document, &options);
+        let result = grab_article(&document, &options, &mut None);
         assert!(result.is_ok());
 
         let content = result.unwrap();
@@ -996,6 +1150,42 @@ mod tests {
         assert!(content_html.contains("first paragraph"));
     }
 
+    #[test]
+    fn test_char_threshold_scaled_for_cjk() {
+        // About 300 characters: short of 500, but past the scaled threshold of 200
+        let paragraph = "新路線は全長およそ八キロで、十四の停留所を約二十五分で結ぶ。車両は低床式で、車いすやベビーカーでも段差なく乗り降りできる。運行は早朝から深夜まで続き、日中は七分間隔で走る。";
+        let comment = "とても便利になりそうです、週末に家族で乗りに行きたいと思います。";
+        let html = format!(
+            r#"<html><body><div id="story">
+                <p>{paragraph}</p><p>{paragraph}</p><p>{paragraph}</p>
+                <div class="reader-voices"><p>{comment}</p><p>{comment}</p></div>
+            </div></body></html>"#
+        );
+        let document = Html::parse_document(&html);
+        // Drops the comments on the first attempt only, which strips unlikelys
+        let voices = regex::Regex::new("voices").unwrap();
+
+        let options = ReadabilityOptions::builder()
+            .extra_unlikely_candidates(voices.clone())
+            .build();
+        let content = grab_article(&document, &options, &mut None)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("八キロ"));
+        assert!(!content.contains("週末"));
+
+        // Without scaling the first attempt falls short, and the longest retry
+        // keeps the reader comments
+        let options = ReadabilityOptions::builder()
+            .extra_unlikely_candidates(voices)
+            .cjk_length_factor(1.0)
+            .build();
+        let content = grab_article(&document, &options, &mut None)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("週末"));
+    }
+
     #[test]
     fn test_grab_article_short_content() {
         let html = r#"
@@ -1009,7 +1199,7 @@ mod tests {
         let document = Html::parse_document(html);
         let options = ReadabilityOptions::default();
 
-        let result = grab_article(&document, &options);
+        let result = grab_article(&document, &options, &mut None);
         assert!(result.is_ok());
 
         assert!(result.unwrap().is_none());
@@ -1038,10 +1228,11 @@ mod tests {
         let flags = ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
 
         let candidates = find_candidates(&document, &options, flags).unwrap();
-        assert!(candidates.len() > 0);
+        assert!(!candidates.is_empty());
 
-        let scores = score_candidates(&document, candidates, &options, flags);
-        assert!(scores.len() > 0);
+        let index = ElementIndex::new(&document);
+        let scores = score_candidates(&index, candidates, &options, flags);
+        assert!(!scores.is_empty());
     }
 
     #[test]
@@ -1066,7 +1257,7 @@ mod tests {
         let document = Html::parse_document(html);
         let options = ReadabilityOptions::builder().char_threshold(100).build();
 
-        let result = grab_article(&document, &options);
+        let result = grab_article(&document, &options, &mut None);
         assert!(result.is_ok());
 
         let content = result.unwrap();
@@ -1,23 +1,118 @@
 //! DOM manipulation and traversal utilities.
 
 use crate::constants::{PHRASING_ELEMS, REGEXPS};
+use crate::utils;
+use ego_tree::iter::Edge;
 use scraper::{ElementRef, Html, Selector};
+use std::borrow::Cow;
+use std::cell::Cell;
 
 /// Get inner text of an element - cross browser compatibly.
 /// This also strips out any excess whitespace to be found.
 ///
+/// Text inside hidden descendants (`hidden`, `aria-hidden="true"`,
+/// `display: none`) is left out, so it does not count towards scoring.
+///
 /// # Arguments
 /// * `element` - The element to extract text from
 /// * `normalize_spaces` - Whether to normalize whitespace (default: true)
 pub fn get_inner_text(element: ElementRef, normalize_spaces: bool) -> String {
-    let text = element.text().collect::<String>();
-    let trimmed = text.trim();
+    let mut text = String::new();
+    for_each_visible_text(element, |chunk| text.push_str(chunk));
 
     if normalize_spaces {
-        crate::utils::normalize_whitespace(trimmed)
-    } else {
-        trimmed.to_string()
+        if let Cow::Owned(normalized) = crate::utils::normalize_whitespace(text.trim()) {
+            return normalized;
+        }
     }
+    // Trim in place rather than copying the text
+    text.truncate(text.trim_end().len());
+    let start = text.len() - text.trim_start().len();
+    text.drain(..start);
+    text
+}
+
+fn for_each_visible_text(element: ElementRef, mut visit: impl FnMut(&str)) {
+    // Walked with an explicit traversal so deeply nested markup can't overflow the stack
+    let mut hidden_depth = 0usize;
+    for edge in element.traverse().skip(1) {
+        match edge {
+            Edge::Open(node) => match ElementRef::wrap(node) {
+                Some(_) if hidden_depth > 0 => hidden_depth += 1,
+                Some(child_element) if is_hidden(child_element) => hidden_depth = 1,
+                Some(_) => {}
+                None if hidden_depth == 0 => {
+                    if let Some(child_text) = node.value().as_text() {
+                        visit(child_text);
+                    }
+                }
+                None => {}
+            },
+            Edge::Close(node) if node.value().is_element() && hidden_depth > 0 => {
+                hidden_depth -= 1;
+            }
+            Edge::Close(_) => {}
+        }
+    }
+}
+
+/// Length of `get_inner_text(element, false)`
+pub fn get_inner_text_length(element: ElementRef) -> usize {
+    with_inner_text(element, str::len)
+}
+
+/// Length of the element's trimmed `textContent`, hidden descendants included
+pub fn get_text_content_length(element: ElementRef) -> usize {
+    with_text_content(element, str::len)
+}
+
+/// Length of the element's trimmed `textContent`, hidden descendants included,
+/// and whether that text is mostly CJK.
+///
+/// The length is in bytes, except for mostly CJK text, which is counted in
+/// characters so that `utils::cjk_length_threshold` can scale it.
+pub fn get_text_content_stats(element: ElementRef) -> (usize, bool) {
+    with_text_content(element, |text| {
+        let mostly_cjk = utils::is_mostly_cjk(text);
+        let length = if mostly_cjk {
+            text.chars().count()
+        } else {
+            text.len()
+        };
+        (length, mostly_cjk)
+    })
+}
+
+fn with_text_content<R>(element: ElementRef, measure: impl FnOnce(&str) -> R) -> R {
+    with_scratch_text(
+        |buffer| element.text().for_each(|chunk| buffer.push_str(chunk)),
+        measure,
+    )
+}
+
+/// Run `measure` on the trimmed, unnormalized inner text of an element.
+///
+/// The text is gathered into a reused per-thread buffer, so measuring large
+/// subtrees doesn't allocate a fresh copy of their text every time.
+pub fn with_inner_text<R>(element: ElementRef, measure: impl FnOnce(&str) -> R) -> R {
+    with_scratch_text(
+        |buffer| for_each_visible_text(element, |chunk| buffer.push_str(chunk)),
+        measure,
+    )
+}
+
+thread_local! {
+    static SCRATCH_TEXT: Cell<String> = const { Cell::new(String::new()) };
+}
+
+fn with_scratch_text<R>(fill: impl FnOnce(&mut String), measure: impl FnOnce(&str) -> R) -> R {
+    // Taken out of the cell, so a nested call just starts from an empty buffer
+    let mut buffer = SCRATCH_TEXT.take();
+    buffer.clear();
+    fill(&mut buffer);
+    let result = measure(buffer.trim());
+    SCRATCH_TEXT.set(buffer);
+    result
 }
 
 /// Get the density of links as a percentage of the content.
@@ -29,11 +124,16 @@ pub fn get_inner_text(element: ElementRef, normalize_spaces: bool) -> String {
 /// # Returns
 /// The link density as a float between 0.0 and 1.0
 pub fn get_link_density(element: ElementRef) -> f64 {
-    let text_length = get_inner_text(element, false).len();
+    let text_length = get_inner_text_length(element);
     if text_length == 0 {
         return 0.0;
     }
 
+    get_link_text_length(element) / text_length as f64
+}
+
+/// Length of the text inside links, with in-page anchors counting for 0.3.
+fn get_link_text_length(element: ElementRef) -> f64 {
     let mut link_length = 0.0;
 
     let link_selector = Selector::parse("a").unwrap();
@@ -44,11 +144,52 @@ pub fn get_link_density(element: ElementRef) -> f64 {
             } else {
                 1.0
             };
-            link_length += get_inner_text(link, false).len() as f64 * coefficient;
+            link_length += get_inner_text_length(link) as f64 * coefficient;
         }
     }
 
-    link_length / text_length as f64
+    link_length
+}
+
+/// Text measurements of an element used while scoring candidates.
+///
+/// Measuring walks the whole subtree, so callers scoring the same elements
+/// repeatedly keep these per node rather than calling `get_inner_text` and
+/// `get_link_density` again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStats {
+    /// Length of `get_inner_text(element, false)`
+    pub text_length: usize,
+    /// Text length inside links, as summed by `get_link_density`
+    pub link_text_length: f64,
+    /// Commas in the inner text, as matched by `REGEXPS.commas`
+    pub comma_count: usize,
+}
+
+impl TextStats {
+    pub fn measure(element: ElementRef) -> Self {
+        let (text_length, comma_count) = with_inner_text(element, |text| {
+            (text.len(), REGEXPS.commas.find_iter(text).count())
+        });
+        let link_text_length = if text_length == 0 {
+            0.0
+        } else {
+            get_link_text_length(element)
+        };
+        Self {
+            text_length,
+            link_text_length,
+            comma_count,
+        }
+    }
+
+    /// Same value as `get_link_density` for the measured element
+    pub fn link_density(&self) -> f64 {
+        if self.text_length == 0 {
+            return 0.0;
+        }
+        self.link_text_length / self.text_length as f64
+    }
 }
 
 /// Check if a node is phrasing content (inline element).
@@ -70,13 +211,19 @@ pub fn is_phrasing_content(element: ElementRef) -> bool {
 
     // Special handling for A, DEL, INS - they're phrasing only if all children are phrasing
     if tag_name == "A" || tag_name == "DEL" || tag_name == "INS" {
-        return element.children().all(|child| {
-            if let Some(child_elem) = ElementRef::wrap(child) {
-                is_phrasing_content(child_elem)
-            } else {
-                true
+        let mut pending: Vec<ElementRef> =
+            element.children().filter_map(ElementRef::wrap).collect();
+        while let Some(child) = pending.pop() {
+            let child_tag = child.value().name().to_uppercase();
+            if PHRASING_ELEMS.contains(&child_tag.as_str()) {
+                continue;
             }
-        });
+            if !matches!(child_tag.as_str(), "A" | "DEL" | "INS") {
+                return false;
+            }
+            pending.extend(child.children().filter_map(ElementRef::wrap));
+        }
+        return true;
     }
 
     false
@@ -95,40 +242,48 @@ pub fn is_probably_visible(element: ElementRef) -> bool {
     let mut current = Some(element);
 
     while let Some(node) = current {
-        if let Some(style) = node.value().attr("style") {
-            let style_lower = style.to_lowercase();
-            if style_lower.contains("display:none") || style_lower.contains("display: none") {
-                return false;
-            }
-            if style_lower.contains("visibility:hidden")
-                || style_lower.contains("visibility: hidden")
-            {
-                return false;
-            }
-        }
-
-        if node.value().attr("hidden").is_some() {
+        if is_hidden(node) {
             return false;
         }
 
-        if let Some(aria_hidden) = node.value().attr("aria-hidden") {
-            if aria_hidden == "true" {
-                let is_fallback_image = node
-                    .value()
-                    .attr("class")
-                    .map(|class| class.contains("fallback-image"))
-                    .unwrap_or(false);
+        current = node.parent().and_then(ElementRef::wrap);
+    }
 
-                if !is_fallback_image {
-                    return false;
-                }
-            }
+    true
+}
+
+/// Check whether the element itself is hidden, ignoring its ancestors.
+///
+/// Like Readability.js, `aria-hidden` elements holding a `fallback-image`
+/// stay visible since they carry the only rendition of their content.
+fn is_hidden(element: ElementRef) -> bool {
+    let node = element.value();
+    if let Some(style) = node.attr("style") {
+        let style_lower = style.to_lowercase();
+        if style_lower.contains("display:none") || style_lower.contains("display: none") {
+            return true;
         }
+        if style_lower.contains("visibility:hidden") || style_lower.contains("visibility: hidden") {
+            return true;
+        }
+    }
 
-        current = node.parent().and_then(ElementRef::wrap);
+    if node.attr("hidden").is_some() {
+        return true;
     }
 
-    true
+    if node.attr("aria-hidden") == Some("true") {
+        let is_fallback_image = node
+            .attr("class")
+            .map(|class| class.contains("fallback-image"))
+            .unwrap_or(false);
+
+        if !is_fallback_image {
+            return true;
+        }
+    }
+
+    false
 }
 
 /// Get the ancestors of a node up to a maximum depth.
@@ -197,11 +352,8 @@ pub fn get_article_direction(document: &Html) -> Option<String> {
     if let Some(html_elem) = document.root_element().first_child() {
         if let Some(node_ref) = ElementRef::wrap(html_elem) {
             if node_ref.value().name() == "html" {
-                if let Some(dir) = node_ref.value().attr("dir") {
-                    let dir = dir.trim().to_lowercase();
-                    if dir == "ltr" || dir == "rtl" || dir == "auto" {
-                        return Some(dir);
-                    }
+                if let Some(dir) = node_ref.value().attr("dir").and_then(parse_direction) {
+                    return Some(dir);
                 }
             }
         }
@@ -210,6 +362,111 @@ pub fn get_article_direction(document: &Html) -> Option<String> {
     None
 }
 
+/// Get the `href` of the document's first `<base>` element that has one
+pub fn get_base_href(document: &Html) -> Option<String> {
+    let selector = Selector::parse("base[href]").unwrap();
+    document
+        .select(&selector)
+        .next()
+        .and_then(|base| base.value().attr("href"))
+        .map(|href| href.trim().to_string())
+}
+
+/// Get the deepest element nesting level in the document
+///
+/// The `<html>` element is at depth 1.
+pub fn get_nesting_depth(document: &Html) -> usize {
+    let mut depth = 0usize;
+    let mut max_depth = 0usize;
+    for edge in document.root_element().traverse() {
+        match edge {
+            Edge::Open(node) if node.value().is_element() => {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            Edge::Close(node) if node.value().is_element() => depth -= 1,
+            _ => {}
+        }
+    }
+    max_depth
+}
+
+/// Unwrap elements nested deeper than `max_depth`, keeping what they hold
+///
+/// Below each element at `max_depth`, every element holding other elements is
+/// replaced by its children, so the document ends up at most one level deeper
+/// than `max_depth`. Leaf elements (text-only paragraphs, images, links) stay.
+pub fn flatten_deep_nesting(document: &mut Html, max_depth: usize) {
+    let mut at_limit = Vec::new();
+    let mut depth = 0usize;
+    for edge in document.root_element().traverse() {
+        match edge {
+            Edge::Open(node) if node.value().is_element() => {
+                depth += 1;
+                if depth == max_depth {
+                    at_limit.push(node.id());
+                }
+            }
+            Edge::Close(node) if node.value().is_element() => depth -= 1,
+            _ => {}
+        }
+    }
+
+    for id in at_limit {
+        let Some(limit) = document.tree.get(id) else {
+            continue;
+        };
+        // In document order, so each wrapper is unwrapped before the ones inside it
+        let wrappers: Vec<_> = limit
+            .descendants()
+            .skip(1)
+            .filter(|node| {
+                node.value().is_element() && node.children().any(|c| c.value().is_element())
+            })
+            .map(|node| node.id())
+            .collect();
+        for wrapper in wrappers {
+            let children: Vec<_> = document
+                .tree
+                .get(wrapper)
+                .unwrap()
+                .children()
+                .map(|c| c.id())
+                .collect();
+            let mut wrapper = document.tree.get_mut(wrapper).unwrap();
+            for child in children {
+                wrapper.insert_id_before(child);
+            }
+            wrapper.detach();
+        }
+    }
+}
+
+/// Get the text direction that applies to an element
+///
+/// Returns the first valid `dir` attribute ("ltr", "rtl" or "auto") found on
+/// the element itself or its nearest ancestor.
+pub fn get_element_direction(element: ElementRef) -> Option<String> {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .find_map(|el| el.value().attr("dir").and_then(parse_direction))
+}
+
+/// Get the text direction of extracted content from the `dir` of its first root element
+pub fn get_content_direction(html: &str) -> Option<String> {
+    let fragment = Html::parse_fragment(html);
+    let first = fragment
+        .root_element()
+        .children()
+        .find_map(ElementRef::wrap)?;
+    first.value().attr("dir").and_then(parse_direction)
+}
+
+fn parse_direction(dir: &str) -> Option<String> {
+    let dir = dir.trim().to_lowercase();
+    matches!(dir.as_str(), "ltr" | "rtl" | "auto").then_some(dir)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,4 +508,67 @@ mod tests {
         let visible = html.select(&visible_sel).next().unwrap();
         assert!(is_probably_visible(visible));
     }
+
+    #[test]
+    fn test_get_inner_text_skips_hidden() {
+        let html = Html::parse_fragment(
+            r#"<div><span aria-hidden="true">Headline</span><span hidden>Print footer</span>Body text<span style="display: none">x</span></div>"#,
+        );
+        let div = html
+            .select(&Selector::parse("div").unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(get_inner_text(div, false), "Body text");
+    }
+
+    #[test]
+    fn test_text_lengths_match_collected_text() {
+        let html = Html::parse_fragment(
+            "<div>  Jane\u{a0}Doe,  <span hidden>hidden, text</span> reports \n</div>",
+        );
+        let div = html
+            .select(&Selector::parse("div").unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(get_inner_text_length(div), get_inner_text(div, false).len());
+        assert_eq!(
+            get_text_content_length(div),
+            div.text().collect::<String>().trim().len()
+        );
+        assert_eq!(
+            get_text_content_stats(div),
+            (get_text_content_length(div), false)
+        );
+        let cjk = Html::parse_fragment("<p> 北浜市で十五日、<b>路面電車</b>が開業した。 </p>");
+        assert_eq!(get_text_content_stats(cjk.root_element()), (18, true));
+
+        // Measuring inside a measurement gets its own buffer
+        let nested = with_inner_text(div, |outer| (outer.to_string(), get_inner_text_length(div)));
+        assert_eq!(
+            nested,
+            (get_inner_text(div, false), get_inner_text_length(div))
+        );
+    }
+
+    #[test]
+    fn test_text_stats_match_direct_measurements() {
+        let html = Html::parse_fragment(
+            r##"<div>Read this, then that, <a href="/more">more here</a> and <a href="#top">top</a>.</div>"##,
+        );
+        let div = html
+            .select(&Selector::parse("div").unwrap())
+            .next()
+            .unwrap();
+        let stats = TextStats::measure(div);
+        assert_eq!(stats.text_length, get_inner_text(div, false).len());
+        assert_eq!(stats.comma_count, 2);
+        assert_eq!(stats.link_density(), get_link_density(div));
+
+        let empty = Html::parse_fragment("<div><a href='/x'></a></div>");
+        let div = empty
+            .select(&Selector::parse("div").unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(TextStats::measure(div).link_density(), 0.0);
+    }
 }
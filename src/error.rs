@@ -86,7 +86,8 @@ pub enum ReadabilityError {
 
     /// Invalid URL provided.
     ///
-    /// This error occurs when a URL string cannot be parsed as a valid URL.
+    /// This error occurs when a URL string cannot be parsed as a valid URL, or is
+    /// one relative links cannot resolve against (such as `mailto:` or `data:`).
     /// URLs are validated when passed to [`Readability::new`](crate::Readability::new).
     ///
     /// ## Example
@@ -137,6 +138,49 @@ pub enum ReadabilityError {
     #[error("Maximum element limit exceeded: {0}")]
     MaxElementsExceeded(usize),
 
+    /// Input HTML is larger than the configured `max_input_bytes` limit.
+    ///
+    /// Returned by [`Readability::new`](crate::Readability::new) before any parsing
+    /// happens. Holds the size of the rejected input in bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{Readability, ReadabilityOptions, ReadabilityError};
+    ///
+    /// let html = "<html><body>".to_string() + &"<p>text</p>".repeat(1000) + "</body></html>";
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .max_input_bytes(1024)
+    ///     .build();
+    ///
+    /// let result = Readability::new(&html, None, Some(options));
+    /// assert!(matches!(result, Err(ReadabilityError::InputTooLarge(_))));
+    /// ```
+    #[error("Input too large: {0} bytes")]
+    InputTooLarge(usize),
+
+    /// The document's elements are nested too deeply.
+    ///
+    /// This error occurs when the parsed document is nested deeper than
+    /// `max_nesting_depth` elements. The contained value is the document's depth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{Readability, ReadabilityError, ReadabilityOptions};
+    ///
+    /// let html = format!("{}<p>Text</p>{}", "<div>".repeat(100), "</div>".repeat(100));
+    /// let options = ReadabilityOptions::builder()
+    ///     .max_nesting_depth(50)
+    ///     .build();
+    ///
+    /// let result = Readability::new(&html, None, Some(options));
+    /// assert!(matches!(result, Err(ReadabilityError::DocumentTooDeep(_))));
+    /// ```
+    #[error("Document too deep: {0} nested elements")]
+    DocumentTooDeep(usize),
+
     /// No article content could be extracted.
     ///
     /// This error occurs when the parser cannot identify any suitable article content
@@ -0,0 +1,209 @@
+//! C bindings, enabled with the `ffi` feature.
+//!
+//! The functions here use the C ABI so the extractor can be linked into C and
+//! C++ programs as a shared or static library, and are declared in the checked-in
+//! `include/readabilityrs.h`. Build the library and refresh the header with:
+//!
+//! ```text
+//! cargo rustc --lib --release --features ffi --crate-type cdylib
+//! cbindgen --config cbindgen.toml --output include/readabilityrs.h
+//! ```
+//!
+//! ```c
+//! #include "readabilityrs.h"
+//!
+//! ReadabilityArticle *article = NULL;
+//! ReadabilityStatus status =
+//!     readability_parse(html, html_len, "https://example.com/post", &article);
+//! if (status == READABILITY_STATUS_OK) {
+//!     size_t len = 0;
+//!     const uint8_t *title = readability_article_title(article, &len);
+//!     fwrite(title, 1, len, stdout);
+//!     readability_article_free(article);
+//! }
+//! ```
+//!
+//! Strings handed out by the accessors are UTF-8, not NUL-terminated, and stay
+//! valid until the article is freed. No panic unwinds into the caller: one is
+//! reported as [`ReadabilityStatus::Panic`].
+
+use crate::{Article, Readability, ReadabilityError};
+use std::ffi::{c_char, CStr};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Outcome of [`readability_parse`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadabilityStatus {
+    /// An article was extracted
+    Ok = 0,
+    /// The document was read but holds no article
+    NoContent = 1,
+    /// A required pointer was null
+    NullArgument = 2,
+    /// The HTML or the URL is not valid UTF-8
+    InvalidUtf8 = 3,
+    /// The URL does not parse, or links cannot resolve against it
+    InvalidUrl = 4,
+    /// The document was rejected, for example for being too large or too deep
+    InvalidDocument = 5,
+    /// The extractor panicked
+    Panic = 6,
+}
+
+impl From<ReadabilityError> for ReadabilityStatus {
+    fn from(error: ReadabilityError) -> Self {
+        match error {
+            ReadabilityError::InvalidUrl(_) => ReadabilityStatus::InvalidUrl,
+            ReadabilityError::NoContentFound => ReadabilityStatus::NoContent,
+            _ => ReadabilityStatus::InvalidDocument,
+        }
+    }
+}
+
+/// An extracted article, owned by the caller until [`readability_article_free`]
+pub struct ReadabilityArticle(Article);
+
+/// Extract the article from `html_len` bytes of UTF-8 HTML at `html_ptr`.
+///
+/// `url_ptr` is a NUL-terminated document URL used to resolve relative links,
+/// or null. On [`ReadabilityStatus::Ok`] the article is stored in
+/// `*out_article`; on any other status `*out_article` is set to null.
+///
+/// # Safety
+///
+/// `html_ptr` must point to `html_len` readable bytes, `url_ptr` must be null
+/// or a valid C string, and `out_article` must be a valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn readability_parse(
+    html_ptr: *const u8,
+    html_len: usize,
+    url_ptr: *const c_char,
+    out_article: *mut *mut ReadabilityArticle,
+) -> ReadabilityStatus {
+    if out_article.is_null() {
+        return ReadabilityStatus::NullArgument;
+    }
+    *out_article = ptr::null_mut();
+    if html_ptr.is_null() && html_len > 0 {
+        return ReadabilityStatus::NullArgument;
+    }
+
+    let html = if html_len == 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(html_ptr, html_len)
+    };
+    let Ok(html) = std::str::from_utf8(html) else {
+        return ReadabilityStatus::InvalidUtf8;
+    };
+    let url = if url_ptr.is_null() {
+        None
+    } else {
+        match CStr::from_ptr(url_ptr).to_str() {
+            Ok(url) => Some(url),
+            Err(_) => return ReadabilityStatus::InvalidUtf8,
+        }
+    };
+
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+        Readability::new(html, url, None).map(|readability| readability.parse())
+    }));
+    match parsed {
+        Ok(Ok(Some(article))) => {
+            *out_article = Box::into_raw(Box::new(ReadabilityArticle(article)));
+            ReadabilityStatus::Ok
+        }
+        Ok(Ok(None)) => ReadabilityStatus::NoContent,
+        Ok(Err(error)) => error.into(),
+        Err(_) => ReadabilityStatus::Panic,
+    }
+}
+
+/// Hand out `value` as a pointer and a length, or null and 0 when absent
+unsafe fn field(value: Option<&str>, out_len: *mut usize) -> *const u8 {
+    let (ptr, len) = match value {
+        Some(value) => (value.as_ptr(), value.len()),
+        None => (ptr::null(), 0),
+    };
+    if !out_len.is_null() {
+        *out_len = len;
+    }
+    ptr
+}
+
+/// The article title, or null when there is none.
+///
+/// # Safety
+///
+/// `article` must come from [`readability_parse`] and not yet be freed, and
+/// `out_len` must be null or a valid pointer to write the length to.
+#[no_mangle]
+pub unsafe extern "C" fn readability_article_title(
+    article: *const ReadabilityArticle,
+    out_len: *mut usize,
+) -> *const u8 {
+    field(article.as_ref().and_then(|a| a.0.title.as_deref()), out_len)
+}
+
+/// The article byline, or null when there is none.
+///
+/// # Safety
+///
+/// Same as [`readability_article_title`].
+#[no_mangle]
+pub unsafe extern "C" fn readability_article_byline(
+    article: *const ReadabilityArticle,
+    out_len: *mut usize,
+) -> *const u8 {
+    field(
+        article.as_ref().and_then(|a| a.0.byline.as_deref()),
+        out_len,
+    )
+}
+
+/// The cleaned article HTML, or null when there is none.
+///
+/// # Safety
+///
+/// Same as [`readability_article_title`].
+#[no_mangle]
+pub unsafe extern "C" fn readability_article_content(
+    article: *const ReadabilityArticle,
+    out_len: *mut usize,
+) -> *const u8 {
+    field(
+        article.as_ref().and_then(|a| a.0.content.as_deref()),
+        out_len,
+    )
+}
+
+/// The article excerpt, or null when there is none.
+///
+/// # Safety
+///
+/// Same as [`readability_article_title`].
+#[no_mangle]
+pub unsafe extern "C" fn readability_article_excerpt(
+    article: *const ReadabilityArticle,
+    out_len: *mut usize,
+) -> *const u8 {
+    field(
+        article.as_ref().and_then(|a| a.0.excerpt.as_deref()),
+        out_len,
+    )
+}
+
+/// Free an article returned by [`readability_parse`]. Null is ignored.
+///
+/// # Safety
+///
+/// `article` must be null or come from [`readability_parse`], and must not be
+/// used again afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn readability_article_free(article: *mut ReadabilityArticle) {
+    if !article.is_null() {
+        drop(Box::from_raw(article));
+    }
+}
@@ -0,0 +1,261 @@
+//! Decoding of HTTP response bodies, enabled with the `http` feature.
+//!
+//! Picks the character encoding in the order the WHATWG encoding sniffing
+//! algorithm uses: a byte order mark, then the `charset` parameter of the
+//! `Content-Type` header, then a `<meta>` declaration in the first 1024 bytes.
+//! Bodies that declare nothing are read as UTF-8.
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252, X_USER_DEFINED};
+
+/// How many bytes the `<meta>` prescan looks at
+const PRESCAN_BYTES: usize = 1024;
+
+/// Decode a response body to a string, replacing malformed sequences
+pub(crate) fn decode_body(bytes: &[u8], content_type: Option<&str>) -> String {
+    let encoding = content_type
+        .and_then(|header| charset_from_content(header.as_bytes()))
+        .or_else(|| prescan(&bytes[..bytes.len().min(PRESCAN_BYTES)]))
+        .unwrap_or(UTF_8);
+    // A BOM, when present, overrides `encoding`
+    let (text, _, _) = encoding.decode(bytes);
+    text.into_owned()
+}
+
+/// The encoding named by a `charset=` parameter, as in `text/html; charset=utf-8`.
+///
+/// Follows the "extract a character encoding from a meta element" algorithm,
+/// which also covers `Content-Type` header values.
+fn charset_from_content(content: &[u8]) -> Option<&'static Encoding> {
+    let mut rest = content;
+    loop {
+        let start = find_ignore_case(rest, b"charset")?;
+        rest = skip_whitespace(&rest[start + b"charset".len()..]);
+        if let Some(after) = rest.strip_prefix(b"=") {
+            rest = skip_whitespace(after);
+            break;
+        }
+    }
+
+    let label = match rest.first() {
+        Some(&quote @ (b'"' | b'\'')) => {
+            let value = &rest[1..];
+            &value[..value.iter().position(|&b| b == quote)?]
+        }
+        _ => {
+            let end = rest
+                .iter()
+                .position(|&b| b == b';' || b.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+            &rest[..end]
+        }
+    };
+    Encoding::for_label(label)
+}
+
+/// The encoding a `<meta>` tag declares, per the WHATWG prescan
+fn prescan(bytes: &[u8]) -> Option<&'static Encoding> {
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        if rest.starts_with(b"<!--") {
+            i += find(&rest[4..], b"-->").map_or(bytes.len(), |end| 4 + end + 3);
+        } else if starts_with_ignore_case(rest, b"<meta")
+            && rest
+                .get(5)
+                .is_some_and(|&b| b.is_ascii_whitespace() || b == b'/')
+        {
+            let (encoding, end) = meta_encoding(&rest[5..]);
+            if let Some(encoding) = encoding {
+                return Some(encoding);
+            }
+            i += 5 + end;
+        } else if rest.starts_with(b"<")
+            && rest
+                .get(1)
+                .is_some_and(|&b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!' | b'?'))
+        {
+            // Skip other tags, declarations and processing instructions whole
+            i += rest.iter().position(|&b| b == b'>').unwrap_or(rest.len());
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// The encoding declared by the attributes of one `<meta>` tag, and how many
+/// bytes the attributes took up
+fn meta_encoding(bytes: &[u8]) -> (Option<&'static Encoding>, usize) {
+    let mut charset = None;
+    let mut content = None;
+    let mut http_equiv_content_type = false;
+
+    let mut i = 0;
+    while let Some((name, value, next)) = next_attribute(bytes, i) {
+        i = next;
+        match name.as_slice() {
+            b"charset" if charset.is_none() => charset = Some(Encoding::for_label(&value)),
+            b"content" if content.is_none() => content = Some(value),
+            b"http-equiv" => http_equiv_content_type |= value.eq_ignore_ascii_case(b"content-type"),
+            _ => {}
+        }
+    }
+
+    let encoding = match charset {
+        Some(encoding) => encoding,
+        None if http_equiv_content_type => content.as_deref().and_then(charset_from_content),
+        None => None,
+    };
+    // A page can't be UTF-16 if its ASCII bytes were just read
+    let encoding = encoding.map(|encoding| {
+        if encoding == UTF_16LE || encoding == UTF_16BE {
+            UTF_8
+        } else if encoding == X_USER_DEFINED {
+            WINDOWS_1252
+        } else {
+            encoding
+        }
+    });
+    (encoding, i)
+}
+
+/// Read the attribute starting at `i`, lowercasing its name, as the prescan's
+/// "get an attribute" step does. Returns `None` at the end of the tag.
+fn next_attribute(bytes: &[u8], mut i: usize) -> Option<(Vec<u8>, Vec<u8>, usize)> {
+    while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+        i += 1;
+    }
+    if i >= bytes.len() || bytes[i] == b'>' {
+        return None;
+    }
+
+    let mut name = Vec::new();
+    while i < bytes.len() {
+        let b = bytes[i];
+        if (b == b'=' && !name.is_empty()) || b.is_ascii_whitespace() || b == b'/' || b == b'>' {
+            break;
+        }
+        name.push(b.to_ascii_lowercase());
+        i += 1;
+    }
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    if bytes.get(i) != Some(&b'=') {
+        return Some((name, Vec::new(), i));
+    }
+    i += 1;
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+
+    let mut value = Vec::new();
+    match bytes.get(i) {
+        Some(&quote @ (b'"' | b'\'')) => {
+            i += 1;
+            while i < bytes.len() && bytes[i] != quote {
+                value.push(bytes[i].to_ascii_lowercase());
+                i += 1;
+            }
+            i += 1;
+        }
+        _ => {
+            while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                value.push(bytes[i].to_ascii_lowercase());
+                i += 1;
+            }
+        }
+    }
+    Some((name, value, i))
+}
+
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn find_ignore_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle))
+}
+
+fn starts_with_ignore_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::SHIFT_JIS;
+
+    #[test]
+    fn test_charset_from_content_type() {
+        assert_eq!(
+            charset_from_content(b"text/html; charset=Shift_JIS"),
+            Some(SHIFT_JIS)
+        );
+        assert_eq!(
+            charset_from_content(b"text/html;charset=\"windows-1252\""),
+            Some(WINDOWS_1252)
+        );
+        assert_eq!(
+            charset_from_content(b"text/html; charset = utf-8 ; x=y"),
+            Some(UTF_8)
+        );
+        assert_eq!(charset_from_content(b"text/html"), None);
+        assert_eq!(charset_from_content(b"text/html; charset=bogus"), None);
+    }
+
+    #[test]
+    fn test_prescan_reads_meta_declarations() {
+        assert_eq!(
+            prescan(b"<!doctype html><html><head><meta charset=\"shift_jis\">"),
+            Some(SHIFT_JIS)
+        );
+        assert_eq!(
+            prescan(
+                b"<head><meta http-equiv=\"Content-Type\" content=\"text/html; charset=windows-1252\">"
+            ),
+            Some(WINDOWS_1252)
+        );
+        // Declarations inside comments don't count, and UTF-16 means UTF-8
+        assert_eq!(prescan(b"<!-- <meta charset=shift_jis> --><p>"), None);
+        assert_eq!(prescan(b"<meta charset=utf-16le>"), Some(UTF_8));
+        // `content` alone, without `http-equiv`, is not a declaration
+        assert_eq!(
+            prescan(b"<meta content=\"text/html; charset=shift_jis\">"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_decode_body_precedence() {
+        let (body, _, _) = SHIFT_JIS.encode("<meta charset=\"windows-1252\"><p>日本語</p>");
+
+        // The header beats the meta tag
+        assert_eq!(
+            decode_body(&body, Some("text/html; charset=Shift_JIS")),
+            "<meta charset=\"windows-1252\"><p>日本語</p>"
+        );
+        // Without a header, the meta tag is used
+        assert!(!decode_body(&body, None).contains("日本語"));
+
+        // A BOM beats the header
+        let mut utf16 = vec![0xFF, 0xFE];
+        for unit in "<p>héllo</p>".encode_utf16() {
+            utf16.extend_from_slice(&unit.to_le_bytes());
+        }
+        assert_eq!(
+            decode_body(&utf16, Some("text/html; charset=Shift_JIS")),
+            "<p>héllo</p>"
+        );
+    }
+}
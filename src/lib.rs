@@ -110,6 +110,23 @@
 //!
 //! This implementation strives to match the behavior of Mozilla's Readability.js as closely
 //! as possible while leveraging Rust's type system and safety guarantees.
+//!
+//! ## WebAssembly
+//!
+//! With the `wasm` feature, the crate builds for `wasm32-unknown-unknown` and exports
+//! `parse(html, url, options)` and `isProbablyReaderable(html, options)` to JavaScript
+//! through `wasm-bindgen`, returning Readability.js-shaped objects. The crate is an
+//! `rlib` by default, so ask for the `cdylib` when building the module:
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/readabilityrs.wasm
+//! ```
+//!
+//! ## C API
+//!
+//! With the `ffi` feature, the [`ffi`] module exports `extern "C"` functions for
+//! embedding the extractor in C and C++ programs, declared in `include/readabilityrs.h`.
 
 mod article;
 mod cleaner;
@@ -117,6 +134,10 @@ mod constants;
 mod content_extractor;
 mod dom_utils;
 mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "http")]
+mod http;
 mod metadata;
 mod options;
 mod post_processor;
@@ -124,10 +145,16 @@ mod readability;
 mod readerable;
 mod scoring;
 mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Public exports
-pub use article::Article;
+pub use article::{Article, Contributor, ContributorRole, Timings};
 pub use error::{ReadabilityError, Result};
-pub use options::ReadabilityOptions;
+pub use options::{BylinePriority, ImageUrlRewriter, ReadabilityOptions, UrlContext, UrlRewriter};
 pub use readability::Readability;
-pub use readerable::{is_probably_readerable, ReaderableOptions};
+pub use readerable::{is_probably_readerable, NodeInfo, ReaderableOptions, VisibilityChecker};
+pub use utils::{
+    clean_byline_text_detailed, normalize_whitespace, unescape_html_entities, BylineDetails,
+    BylineSignals, BylineWords, CleanBylineOutcome,
+};
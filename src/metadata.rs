@@ -1,8 +1,11 @@
 //! Metadata extraction from HTML documents (JSON-LD, meta tags, etc.).
 
+use crate::article::Contributor;
 use crate::constants::REGEXPS;
-use crate::utils;
+use crate::options::{BylinePriority, ReadabilityOptions};
+use crate::utils::{self, BylineWords, UrlKind};
 use once_cell::sync::Lazy;
+use regex::Regex;
 use scraper::node::Node;
 use scraper::{ElementRef, Html, Selector};
 use serde_json::Value;
@@ -19,6 +22,7 @@ pub struct Metadata {
     pub published_time: Option<String>,
     pub lang: Option<String>,
     pub image: Option<String>,
+    pub contributors: Vec<Contributor>,
 }
 
 /// Extract JSON-LD structured data from document
@@ -28,8 +32,10 @@ pub struct Metadata {
 pub fn get_json_ld(document: &Html) -> Metadata {
     let mut metadata = Metadata::default();
 
+    static SCHEMA_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^https?://schema\.org/?$").unwrap());
+
     let script_selector = Selector::parse("script[type='application/ld+json']").unwrap();
-    let schema_regex = regex::Regex::new(r"^https?://schema\.org/?$").unwrap();
 
     for script in document.select(&script_selector) {
         let content = script.text().collect::<String>();
@@ -60,10 +66,10 @@ pub fn get_json_ld(document: &Html) -> Metadata {
             // Check for schema.org context
             let has_schema_context = if let Some(context) = parsed.get("@context") {
                 if let Some(ctx_str) = context.as_str() {
-                    schema_regex.is_match(ctx_str)
+                    SCHEMA_REGEX.is_match(ctx_str)
                 } else if let Some(ctx_obj) = context.as_object() {
                     if let Some(vocab) = ctx_obj.get("@vocab").and_then(|v| v.as_str()) {
-                        schema_regex.is_match(vocab)
+                        SCHEMA_REGEX.is_match(vocab)
                     } else {
                         false
                     }
@@ -247,15 +253,23 @@ fn extract_json_ld_image(parsed: &Value) -> Option<String> {
 /// Extract article metadata from meta tags
 ///
 /// Supports OpenGraph, Twitter Cards, Dublin Core, and standard meta tags.
-pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
-    let mut values: HashMap<String, String> = HashMap::new();
-    let property_pattern = regex::Regex::new(
-        r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name|image:url|image:secure_url|image$)\s*"
-    ).unwrap();
+pub fn get_article_metadata(
+    document: &Html,
+    json_ld: Metadata,
+    options: &ReadabilityOptions,
+) -> Metadata {
+    static PROPERTY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name|image:url|image:secure_url|image$)\s*"
+        ).unwrap()
+    });
+    static NAME_PATTERN: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?i)^\s*(?:(?:article|dc|dcterm|og|twitter|parsely|weibo:(?:article|webpage))\s*[-\.:]\s*)?(author|author_name|creator|pub-date|description|title|site_name|image|thumbnail)\s*$"
+        ).unwrap()
+    });
 
-    let name_pattern = regex::Regex::new(
-        r"(?i)^\s*(?:(?:article|dc|dcterm|og|twitter|parsely|weibo:(?:article|webpage))\s*[-\.:]\s*)?(author|author_name|creator|pub-date|description|title|site_name|image|thumbnail)\s*$"
-    ).unwrap();
+    let mut values: HashMap<String, String> = HashMap::new();
 
     let meta_selector = Selector::parse("meta").unwrap();
     for meta in document.select(&meta_selector) {
@@ -274,7 +288,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             // Handle space-separated properties (e.g., "dc:creator twitter:site_name")
             // Split on whitespace and process each property separately
             for prop in property.split_whitespace() {
-                if let Some(mat) = property_pattern.find(prop) {
+                if let Some(mat) = PROPERTY_PATTERN.find(prop) {
                     let key = prop[mat.start()..mat.end()]
                         .to_lowercase()
                         .replace(char::is_whitespace, "");
@@ -286,7 +300,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         // Check name attribute if property didn't match
         if matched_name.is_none() {
             if let Some(name) = element_name {
-                if name_pattern.is_match(name) {
+                if NAME_PATTERN.is_match(name) {
                     let normalized = name
                         .to_lowercase()
                         .replace(char::is_whitespace, "")
@@ -324,10 +338,16 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
     let article_author = values
         .get("article:author")
         .or_else(|| values.get("article:author_name"))
-        .filter(|v| !utils::is_url(v))
+        .filter(|v| {
+            !matches!(
+                utils::classify_url(v),
+                UrlKind::Absolute | UrlKind::ProtocolRelative | UrlKind::Special(_)
+            )
+        })
         .cloned();
 
-    let dom_byline = extract_byline_from_document(document);
+    let words = options.byline_words();
+    let dom_byline = extract_byline_from_document(document, &words);
     let mut meta_byline = json_ld.byline.or_else(|| {
         values
             .get("dc:creator")
@@ -342,7 +362,13 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         let dom_text = dom_value.text.clone();
         match &meta_byline {
             Some(existing) => {
-                if should_prefer_dom_byline(existing, &dom_text, dom_value.confidence) {
+                if prefer_dom_byline(
+                    existing,
+                    &dom_text,
+                    dom_value.confidence,
+                    options.byline_priority,
+                    &words,
+                ) {
                     meta_byline = Some(dom_text);
                 }
             }
@@ -393,33 +419,47 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
     }
 
     metadata.lang = extract_language_from_document(document);
+    metadata.contributors = extract_contributors(document);
 
-    metadata.title = metadata.title.map(|t| utils::unescape_html_entities(&t));
+    metadata.title = metadata.title.map(utils::unescape_html_entities_owned);
     metadata.byline = metadata
         .byline
-        .map(|b| utils::unescape_html_entities(&b))
-        .and_then(|b| utils::clean_byline_text(&b));
+        .map(utils::unescape_html_entities_owned)
+        .and_then(|b| {
+            utils::clean_byline_text(&b, &words, options.title_case_bylines).or_else(|| {
+                options
+                    .accept_agency_bylines
+                    .then(|| utils::agency_byline(&b, &words))
+                    .flatten()
+                    .map(str::to_string)
+            })
+        });
     metadata.excerpt = metadata
         .excerpt
-        .map(|e| utils::unescape_html_entities(&e))
+        .map(utils::unescape_html_entities_owned)
         .and_then(|e| {
             let trimmed = e.trim();
             if trimmed.is_empty() {
                 return None;
             }
-            if utils::looks_like_bracket_menu(trimmed) {
+            if utils::looks_like_menu(trimmed) {
                 return None;
             }
             Some(e)
         });
-    metadata.site_name = metadata
-        .site_name
-        .map(|s| utils::unescape_html_entities(&s));
+    metadata.site_name = metadata.site_name.map(utils::unescape_html_entities_owned);
 
     if let (Some(existing), Some(dom_value)) = (metadata.byline.clone(), dom_byline.clone()) {
-        if should_prefer_dom_byline(&existing, &dom_value.text, dom_value.confidence) {
+        if prefer_dom_byline(
+            &existing,
+            &dom_value.text,
+            dom_value.confidence,
+            options.byline_priority,
+            &words,
+        ) {
             metadata.byline =
-                utils::clean_byline_text(&dom_value.text).or_else(|| Some(dom_value.text.clone()));
+                utils::clean_byline_text(&dom_value.text, &words, options.title_case_bylines)
+                    .or_else(|| Some(dom_value.text.clone()));
         }
     }
 
@@ -432,14 +472,17 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         }
     }
 
-    if let Some(caps_candidate) = extract_standfirst_caps_byline(document) {
-        match &metadata.byline {
-            Some(existing) => {
-                if should_prefer_caps_standfirst(existing, &caps_candidate) {
-                    metadata.byline = Some(caps_candidate);
-                }
-            }
-            None => metadata.byline = Some(caps_candidate),
+    if let Some(caps_candidate) = extract_standfirst_caps_byline(document, &words) {
+        let prefer_candidate = match &metadata.byline {
+            Some(existing) => should_prefer_caps_standfirst(existing, &caps_candidate),
+            None => true,
+        };
+        if prefer_candidate {
+            metadata.byline = Some(if options.title_case_bylines {
+                utils::title_case_caps_byline(&caps_candidate)
+            } else {
+                caps_candidate
+            });
         }
     }
 
@@ -451,7 +494,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
 
     metadata.published_time = metadata
         .published_time
-        .map(|p| utils::unescape_html_entities(&p));
+        .map(utils::unescape_html_entities_owned);
 
     // Clean up image URL
     metadata.image = metadata.image.and_then(|img| {
@@ -459,7 +502,15 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         if trimmed.is_empty() {
             return None;
         }
-        Some(utils::unescape_html_entities(trimmed))
+        let image = utils::unescape_html_entities(trimmed).into_owned();
+        if options.strip_tracking_params_from_metadata {
+            if let Some(stripped) =
+                utils::strip_tracking_params(&image, &options.extra_tracking_params)
+            {
+                return Some(stripped);
+            }
+        }
+        Some(image)
     });
 
     metadata
@@ -540,11 +591,14 @@ enum DomBylineConfidence {
 /// 2. itemprop="author" elements
 /// 3. Common byline CSS classes (.byline, .author, .by, etc.)
 /// 4. <address> tags with author context
-fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
+fn extract_byline_from_document(
+    document: &Html,
+    words: &BylineWords,
+) -> Option<DomBylineCandidate> {
     use crate::scoring;
 
     let mut fallback_candidate: Option<DomBylineCandidate> = None;
-    if let Some(candidate) = extract_standfirst_caps_byline(document) {
+    if let Some(candidate) = extract_standfirst_caps_byline(document, words) {
         return Some(DomBylineCandidate::new(
             candidate,
             DomBylineConfidence::High,
@@ -559,14 +613,16 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             if is_noise_byline_context(&link) {
                 continue;
             }
-            if let Some(parent_text) = parent_byline_text(&link) {
+            if let Some(parent_text) = parent_byline_text(&link, words) {
                 return Some(DomBylineCandidate::new(
                     parent_text,
                     DomBylineConfidence::High,
                 ));
             }
 
-            let text = collect_byline_candidate_text(link).trim().to_string();
+            let text = collect_byline_candidate_text(link, words)
+                .trim()
+                .to_string();
             if !text.is_empty() {
                 let class = link.value().attr("class").unwrap_or("");
                 let id = link.value().attr("id").unwrap_or("");
@@ -577,7 +633,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     .any(|rel| rel.eq_ignore_ascii_case("author"));
 
                 if has_author_rel || scoring::is_valid_byline(link, &match_string) {
-                    match utils::clean_byline_text_with_reason(&text) {
+                    match utils::clean_byline_text_with_reason(&text, words) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
                             return Some(DomBylineCandidate::new(
                                 cleaned,
@@ -600,14 +656,16 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             if is_noise_byline_context(&elem) {
                 continue;
             }
-            if let Some(parent_text) = parent_byline_text(&elem) {
+            if let Some(parent_text) = parent_byline_text(&elem, words) {
                 return Some(DomBylineCandidate::new(
                     parent_text,
                     DomBylineConfidence::High,
                 ));
             }
 
-            let text = collect_byline_candidate_text(elem).trim().to_string();
+            let text = collect_byline_candidate_text(elem, words)
+                .trim()
+                .to_string();
             if !text.is_empty() {
                 let class = elem.value().attr("class").unwrap_or("");
                 let id = elem.value().attr("id").unwrap_or("");
@@ -618,7 +676,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     .any(|prop| prop.eq_ignore_ascii_case("author"));
 
                 if has_author_itemprop || scoring::is_valid_byline(elem, &match_string) {
-                    match utils::clean_byline_text_with_reason(&text) {
+                    match utils::clean_byline_text_with_reason(&text, words) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
                             return Some(DomBylineCandidate::new(
                                 cleaned,
@@ -657,10 +715,12 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 if !element_has_byline_keyword(&elem) && is_noise_byline_context(&elem) {
                     continue;
                 }
-                let text = collect_byline_candidate_text(elem).trim().to_string();
+                let Some(text) = collect_short_byline_candidate_text(elem, words, 100) else {
+                    continue;
+                };
                 let text_is_caps = looks_like_caps_author(&text);
 
-                if text.is_empty() || text.len() > 100 {
+                if text.is_empty() {
                     continue;
                 }
 
@@ -669,7 +729,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 let match_string = format!("{class} {id}");
 
                 if scoring::is_valid_byline(elem, &match_string)
-                    || utils::looks_like_byline(&text)
+                    || utils::looks_like_byline(&text, words)
                     || text_is_caps
                 {
                     let confidence = if element_has_explicit_byline_marker(&elem) {
@@ -677,10 +737,10 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     } else {
                         DomBylineConfidence::Medium
                     };
-                    match utils::clean_byline_text_with_reason(&text) {
+                    match utils::clean_byline_text_with_reason(&text, words) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
                             let candidate = DomBylineCandidate::new(cleaned, confidence);
-                            if is_priority_dom_candidate(&candidate, text_is_caps) {
+                            if is_priority_dom_candidate(&candidate, text_is_caps, words) {
                                 return Some(candidate);
                             } else if fallback_candidate.is_none() {
                                 fallback_candidate = Some(candidate);
@@ -716,22 +776,24 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 continue;
             }
 
-            let text = collect_byline_candidate_text(elem).trim().to_string();
-            if text.is_empty() || text.len() > 120 {
+            let Some(text) = collect_short_byline_candidate_text(elem, words, 120) else {
+                continue;
+            };
+            if text.is_empty() {
                 continue;
             }
 
             let text_is_caps = looks_like_caps_author(&text);
             let match_string = format!("{class} {id}");
             if scoring::is_valid_byline(elem, &match_string)
-                || utils::looks_like_byline(&text)
+                || utils::looks_like_byline(&text, words)
                 || text_is_caps
             {
-                match utils::clean_byline_text_with_reason(&text) {
+                match utils::clean_byline_text_with_reason(&text, words) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
                         let candidate =
                             DomBylineCandidate::new(cleaned, DomBylineConfidence::Medium);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        if is_priority_dom_candidate(&candidate, text_is_caps, words) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -752,21 +814,23 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             if is_noise_byline_context(&elem) {
                 continue;
             }
-            let text = collect_byline_candidate_text(elem).trim().to_string();
+            let Some(text) = collect_short_byline_candidate_text(elem, words, 100) else {
+                continue;
+            };
 
-            if text.is_empty() || text.len() > 100 {
+            if text.is_empty() {
                 continue;
             }
 
             let text_is_caps = looks_like_caps_author(&text);
-            if utils::looks_like_byline(&text)
+            if utils::looks_like_byline(&text, words)
                 || scoring::is_valid_byline(elem, &text)
                 || text_is_caps
             {
-                match utils::clean_byline_text_with_reason(&text) {
+                match utils::clean_byline_text_with_reason(&text, words) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
                         let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        if is_priority_dom_candidate(&candidate, text_is_caps, words) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -787,8 +851,10 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             if is_noise_byline_context(&elem) {
                 continue;
             }
-            let text = collect_byline_candidate_text(elem).trim().to_string();
-            if text.is_empty() || text.len() > 120 {
+            let Some(text) = collect_short_byline_candidate_text(elem, words, 120) else {
+                continue;
+            };
+            if text.is_empty() {
                 continue;
             }
 
@@ -797,11 +863,11 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             }
 
             let text_is_caps = looks_like_caps_author(&text);
-            if utils::looks_like_byline(&text) || text_is_caps {
-                match utils::clean_byline_text_with_reason(&text) {
+            if utils::looks_like_byline(&text, words) || text_is_caps {
+                match utils::clean_byline_text_with_reason(&text, words) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
                         let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        if is_priority_dom_candidate(&candidate, text_is_caps, words) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -821,7 +887,38 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
     None
 }
 
-fn extract_standfirst_caps_byline(document: &Html) -> Option<String> {
+/// Collect "Photography by <name>" style credits from short blocks of the page.
+///
+/// Credits inside figures caption a single image and are skipped.
+fn extract_contributors(document: &Html) -> Vec<Contributor> {
+    static CREDIT_BLOCK_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("p, div, span, li, address").unwrap());
+
+    let mut contributors: Vec<Contributor> = Vec::new();
+    for element in document.select(&CREDIT_BLOCK_SELECTOR) {
+        let in_figure = element
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|ancestor| matches!(ancestor.value().name(), "figure" | "figcaption"));
+        if in_figure || is_ignorable_byline_context(&element) {
+            continue;
+        }
+        let text = build_byline_text(&element);
+        if text.trim().is_empty() || text.len() > 120 {
+            continue;
+        }
+        for clause in text.split(['\n', '|', '·']) {
+            if let Some(contributor) = utils::parse_contributor_credit(clause) {
+                if !contributors.contains(&contributor) {
+                    contributors.push(contributor);
+                }
+            }
+        }
+    }
+    contributors
+}
+
+fn extract_standfirst_caps_byline(document: &Html, words: &BylineWords) -> Option<String> {
     const SELECTORS: [&str; 2] = ["em.byline", "[class*='byline']"];
     const STANDFIRST_KEYWORDS: [&str; 1] = ["standfirst"];
 
@@ -834,14 +931,16 @@ fn extract_standfirst_caps_byline(document: &Html) -> Option<String> {
                 if is_ignorable_byline_context(&elem) || is_noise_byline_context(&elem) {
                     continue;
                 }
-                let text = collect_byline_candidate_text(elem).trim().to_string();
-                if text.is_empty() || text.len() > 80 {
+                let Some(text) = collect_short_byline_candidate_text(elem, words, 80) else {
+                    continue;
+                };
+                if text.is_empty() {
                     continue;
                 }
                 if !looks_like_caps_author(&text) {
                     continue;
                 }
-                match utils::clean_byline_text_with_reason(&text) {
+                match utils::clean_byline_text_with_reason(&text, words) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => return Some(cleaned),
                     utils::CleanBylineOutcome::DroppedOrgCredit
                     | utils::CleanBylineOutcome::Dropped => continue,
@@ -854,37 +953,26 @@ fn extract_standfirst_caps_byline(document: &Html) -> Option<String> {
 }
 
 fn build_byline_text(element: &ElementRef) -> String {
-    fn append_children_text(element: &ElementRef, out: &mut String) {
-        for child in element.children() {
-            match child.value() {
-                Node::Text(text) => {
-                    let mut text_slice: &str = text.as_ref();
-                    if out.ends_with('\n') && text_slice.starts_with('\n') {
-                        text_slice = &text_slice[1..];
-                    }
-                    if out.ends_with('\n') {
-                        let adjusted = strip_intermediate_newline(text_slice);
-                        out.push_str(&adjusted);
-                    } else {
-                        out.push_str(text_slice);
-                    }
+    let mut out = String::new();
+    for node in element.descendants().skip(1) {
+        match node.value() {
+            Node::Text(text) => {
+                let mut text_slice: &str = text.as_ref();
+                if out.ends_with('\n') && text_slice.starts_with('\n') {
+                    text_slice = &text_slice[1..];
                 }
-                Node::Element(data) => {
-                    if data.name().eq_ignore_ascii_case("br") {
-                        out.push('\n');
-                    }
-                    if let Some(child_el) = ElementRef::wrap(child) {
-                        append_children_text(&child_el, out);
-                    }
+                if out.ends_with('\n') {
+                    let adjusted = strip_intermediate_newline(text_slice);
+                    out.push_str(&adjusted);
+                } else {
+                    out.push_str(text_slice);
                 }
-                _ => {}
             }
+            Node::Element(data) if data.name().eq_ignore_ascii_case("br") => out.push('\n'),
+            _ => {}
         }
     }
-
-    let mut buffer = String::new();
-    append_children_text(element, &mut buffer);
-    buffer
+    out
 }
 
 fn strip_intermediate_newline(text: &str) -> Cow<'_, str> {
@@ -904,20 +992,36 @@ fn strip_intermediate_newline(text: &str) -> Cow<'_, str> {
     }
 }
 
-fn collect_byline_candidate_text(element: ElementRef) -> String {
+fn collect_byline_candidate_text(element: ElementRef, words: &BylineWords) -> String {
+    collect_short_byline_candidate_text(element, words, usize::MAX).unwrap_or_default()
+}
+
+/// The trimmed candidate text, or None when it is longer than `max_len`
+fn collect_short_byline_candidate_text(
+    element: ElementRef,
+    words: &BylineWords,
+    max_len: usize,
+) -> Option<String> {
     let raw_text = build_byline_text(&element);
-    if let Some(names) = collect_child_author_names(&element) {
+    if let Some(names) = collect_child_author_names(&element, words) {
+        let joined_len = names.iter().map(|name| name.len() + 2).sum::<usize>() - 2;
+        // Comparing a long container's text with its many linked names is
+        // costly, and both are too long to keep anyway
+        if raw_text.trim().len() > max_len && joined_len > max_len {
+            return None;
+        }
         if should_prefer_child_names(&element, &raw_text, &names) {
-            return names.join(", ");
+            return (joined_len <= max_len).then(|| names.join(", "));
         }
     }
-    raw_text
+    let text = raw_text.trim();
+    (text.len() <= max_len).then(|| text.to_string())
 }
 
 static ITEMPROP_NAME_SELECTOR: Lazy<Selector> =
     Lazy::new(|| Selector::parse("[itemprop='name'], [itemprop~='name']").unwrap());
 
-fn collect_child_author_names(element: &ElementRef) -> Option<Vec<String>> {
+fn collect_child_author_names(element: &ElementRef, words: &BylineWords) -> Option<Vec<String>> {
     static ANCHOR_SELECTOR: Lazy<Selector> =
         Lazy::new(|| Selector::parse("a").expect("valid anchor selector"));
 
@@ -941,13 +1045,13 @@ fn collect_child_author_names(element: &ElementRef) -> Option<Vec<String>> {
 
     for anchor in element.select(&ANCHOR_SELECTOR) {
         let text = anchor.text().collect::<String>().trim().to_string();
-        if text.is_empty() || text.contains('@') || !utils::looks_like_author_name(&text) {
+        if text.is_empty() || text.contains('@') || !utils::looks_like_author_name(&text, words) {
             continue;
         }
 
         if let Some(href) = anchor.value().attr("href") {
             let href_lower = href.to_lowercase();
-            if href_lower.starts_with("mailto:")
+            if matches!(utils::classify_url(href), UrlKind::Special(_))
                 || href_lower.contains("twitter.com")
                 || href_lower.contains("facebook.com")
                 || href_lower.contains("linkedin.com")
@@ -1087,7 +1191,47 @@ const MONTH_KEYWORDS: [&str; 24] = [
     "december",
 ];
 
-fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConfidence) -> bool {
+/// Applies the configured [`BylinePriority`] to a conflicting metadata/DOM byline pair.
+fn prefer_dom_byline(
+    existing: &str,
+    dom: &str,
+    confidence: DomBylineConfidence,
+    priority: BylinePriority,
+    words: &BylineWords,
+) -> bool {
+    match priority {
+        BylinePriority::MetaFirst => false,
+        BylinePriority::DomFirst => true,
+        BylinePriority::PreferPersonName => {
+            match (
+                looks_like_person_byline(existing, words),
+                looks_like_person_byline(dom, words),
+            ) {
+                (false, true) => true,
+                (true, false) => false,
+                _ => should_prefer_dom_byline(existing, dom, confidence, words),
+            }
+        }
+        BylinePriority::Auto => should_prefer_dom_byline(existing, dom, confidence, words),
+    }
+}
+
+/// Whether every author named in a byline ("By Jane Doe and John Roe") looks like a person.
+fn looks_like_person_byline(byline: &str, words: &BylineWords) -> bool {
+    let authors = utils::split_byline_authors(byline, words);
+    !authors.is_empty()
+        && !utils::looks_like_org_credit(byline.trim(), words)
+        && authors
+            .iter()
+            .all(|author| utils::looks_like_author_name(author, words))
+}
+
+fn should_prefer_dom_byline(
+    existing: &str,
+    dom: &str,
+    confidence: DomBylineConfidence,
+    words: &BylineWords,
+) -> bool {
     let existing_clean = existing.trim();
     let dom_clean = dom.trim();
 
@@ -1095,7 +1239,9 @@ fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConf
         return false;
     }
 
-    if utils::looks_like_org_credit(existing_clean) && !utils::looks_like_org_credit(dom_clean) {
+    if utils::looks_like_org_credit(existing_clean, words)
+        && !utils::looks_like_org_credit(dom_clean, words)
+    {
         return true;
     }
 
@@ -1214,7 +1360,7 @@ fn contains_caps_noise_token(text: &str) -> bool {
     })
 }
 
-fn parent_byline_text(element: &ElementRef) -> Option<String> {
+fn parent_byline_text(element: &ElementRef, words: &BylineWords) -> Option<String> {
     let parent_node = element.parent()?;
     let parent = ElementRef::wrap(parent_node)?;
     if is_ignorable_byline_context(&parent) {
@@ -1226,8 +1372,10 @@ fn parent_byline_text(element: &ElementRef) -> Option<String> {
     if !element_has_byline_keyword(&parent) {
         return None;
     }
-    let text = collect_byline_candidate_text(parent).trim().to_string();
-    match utils::clean_byline_text_with_reason(&text) {
+    let text = collect_byline_candidate_text(parent, words)
+        .trim()
+        .to_string();
+    match utils::clean_byline_text_with_reason(&text, words) {
         utils::CleanBylineOutcome::Accepted(cleaned) => Some(cleaned),
         utils::CleanBylineOutcome::DroppedOrgCredit | utils::CleanBylineOutcome::Dropped => None,
     }
@@ -1253,8 +1401,12 @@ fn element_has_explicit_byline_marker(element: &ElementRef) -> bool {
     class.contains("byline") || id.contains("byline")
 }
 
-fn is_priority_dom_candidate(candidate: &DomBylineCandidate, raw_caps: bool) -> bool {
-    raw_caps || utils::looks_like_byline(&candidate.text)
+fn is_priority_dom_candidate(
+    candidate: &DomBylineCandidate,
+    raw_caps: bool,
+    words: &BylineWords,
+) -> bool {
+    raw_caps || utils::looks_like_byline(&candidate.text, words)
 }
 
 fn ancestor_has_keyword(element: &ElementRef, keywords: &[&str], max_depth: usize) -> bool {
@@ -1422,20 +1574,19 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
 
     // Title separators: | - – — \ / > »
     // Using alternation instead of character class since pipe needs special handling
-    let sep_regex = regex::Regex::new(r"\s(\||\-|–|—|\\|/|>|»)\s").unwrap();
+    static SEP_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s(\||\-|–|—|\\|/|>|»)\s").unwrap());
+    static HIERARCHICAL_SEP_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s[\\//>»]\s").unwrap());
+    static FIRST_SEP_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)^[^\|\-–—\\//>»]*[\|\-–—\\//>»]").unwrap());
 
-    if sep_regex.is_match(&cur_title) {
-        title_had_hierarchical_separators = regex::Regex::new(r"\s[\\//>»]\s")
-            .unwrap()
-            .is_match(&cur_title);
+    if SEP_REGEX.is_match(&cur_title) {
+        title_had_hierarchical_separators = HIERARCHICAL_SEP_REGEX.is_match(&cur_title);
 
-        let sep_matches: Vec<_> = sep_regex.find_iter(&orig_title).collect();
+        let sep_matches: Vec<_> = SEP_REGEX.find_iter(&orig_title).collect();
         if let Some(last_sep) = sep_matches.last() {
             cur_title = orig_title[..last_sep.start()].to_string();
             if word_count(&cur_title) < 3 {
-                let first_sep_regex =
-                    regex::Regex::new(r"(?i)^[^\|\-–—\\//>»]*[\|\-–—\\//>»]").unwrap();
-                cur_title = first_sep_regex.replace(&orig_title, "").to_string();
+                cur_title = FIRST_SEP_REGEX.replace(&orig_title, "").to_string();
             }
         }
     } else if cur_title.contains(": ") {
@@ -1473,14 +1624,11 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
         }
     }
 
-    cur_title = REGEXPS
-        .normalize
-        .replace_all(cur_title.trim(), " ")
-        .to_string();
+    cur_title = utils::normalize_whitespace_ascii(cur_title.trim());
 
     let cur_word_count = word_count(&cur_title);
     if cur_word_count <= 4 {
-        let orig_without_sep = sep_regex.replace_all(&orig_title, " ").to_string();
+        let orig_without_sep = SEP_REGEX.replace_all(&orig_title, " ").to_string();
         let orig_word_count = word_count(&orig_without_sep);
 
         if !title_had_hierarchical_separators || cur_word_count != orig_word_count - 1 {
@@ -1622,7 +1770,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = Metadata::default();
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert_eq!(metadata.title, Some("OG Title".to_string()));
         assert_eq!(metadata.byline, Some("Jane Smith".to_string()));
@@ -1641,7 +1789,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1661,7 +1813,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1681,7 +1837,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1709,7 +1869,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = get_json_ld(&document);
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert_eq!(
             metadata.image,
@@ -1728,7 +1888,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1747,7 +1911,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1766,7 +1934,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(
             metadata.image,
@@ -1785,7 +1957,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(metadata.byline, Some("Hazel Sheffield".to_string()));
     }
@@ -1825,7 +2001,7 @@ mod tests {
         // TODO: Colon separator extraction needs refinement
         // For now, just verify we got a title
         assert!(title.is_some());
-        assert!(title.as_ref().unwrap().len() > 0);
+        assert!(!title.as_ref().unwrap().is_empty());
     }
 
     #[test]
@@ -1843,7 +2019,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = Metadata::default();
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert_eq!(metadata.byline, Some("John Doe".to_string()));
     }
@@ -1863,7 +2039,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = Metadata::default();
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert!(metadata.byline.is_some());
         assert!(metadata.byline.as_ref().unwrap().contains("Jane Smith"));
@@ -1886,7 +2062,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = Metadata::default();
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert_eq!(metadata.byline, Some("Meta Author".to_string()));
     }
@@ -1954,7 +2130,7 @@ mod tests {
 
         let document = Html::parse_document(html);
         let json_ld = Metadata::default();
-        let metadata = get_article_metadata(&document, json_ld);
+        let metadata = get_article_metadata(&document, json_ld, &ReadabilityOptions::default());
 
         assert!(metadata.byline.is_none());
     }
@@ -1978,7 +2154,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert_eq!(metadata.byline, Some("Nicolas Perriault".to_string()));
     }
@@ -2003,7 +2183,11 @@ mod tests {
         "#;
 
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
 
         assert!(metadata.byline.is_none());
     }
@@ -2018,7 +2202,9 @@ mod tests {
             if is_ignorable_byline_context(&elem) || is_noise_byline_context(&elem) {
                 continue;
             }
-            let text = collect_byline_candidate_text(elem).trim().to_string();
+            let text = collect_byline_candidate_text(elem, &BylineWords::default())
+                .trim()
+                .to_string();
             if text.contains("Lucas Nolan") {
                 saw_lucas = true;
                 break;
@@ -2026,7 +2212,7 @@ mod tests {
         }
         assert!(saw_lucas, "expected to find Lucas Nolan byline candidate");
 
-        let dom_byline = extract_byline_from_document(&document);
+        let dom_byline = extract_byline_from_document(&document, &BylineWords::default());
         assert!(
             dom_byline.is_some(),
             "expected Breitbart byline to be detected"
@@ -2037,7 +2223,8 @@ mod tests {
     fn test_cnet_authorinfo_is_extracted() {
         let html = fs::read_to_string("tests/test-pages/cnet/source.html").unwrap();
         let document = Html::parse_document(&html);
-        let dom_byline = extract_byline_from_document(&document).map(|c| c.text);
+        let dom_byline =
+            extract_byline_from_document(&document, &BylineWords::default()).map(|c| c.text);
         assert_eq!(dom_byline, Some("Steven Musil".to_string()));
     }
 
@@ -2045,14 +2232,24 @@ mod tests {
     fn test_herald_sun_caps_byline_overrides_meta() {
         let html = fs::read_to_string("tests/test-pages/herald-sun-1/source.html").unwrap();
         let document = Html::parse_document(&html);
-        let dom_byline = extract_byline_from_document(&document).expect("dom byline");
+        let dom_byline =
+            extract_byline_from_document(&document, &BylineWords::default()).expect("dom byline");
         assert_eq!(dom_byline.text, "JOE HILDEBRAND");
         assert_eq!(dom_byline.confidence, DomBylineConfidence::High);
         assert!(
-            should_prefer_dom_byline("by: Laurie Oakes", &dom_byline.text, dom_byline.confidence),
+            should_prefer_dom_byline(
+                "by: Laurie Oakes",
+                &dom_byline.text,
+                dom_byline.confidence,
+                &BylineWords::default()
+            ),
             "dom byline should override Laurie Oakes"
         );
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
         assert_eq!(metadata.byline, Some("JOE HILDEBRAND".to_string()));
     }
 
@@ -2080,7 +2277,11 @@ mod tests {
             </html>
         "#;
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
         assert_eq!(metadata.byline, Some("Par Sébastien Farcis".to_string()));
     }
 
@@ -2101,10 +2302,78 @@ mod tests {
             </html>
         "#;
         let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
+        let metadata = get_article_metadata(
+            &document,
+            Metadata::default(),
+            &ReadabilityOptions::default(),
+        );
         assert_eq!(metadata.byline, Some("By Erin Cunningham".to_string()));
     }
 
+    #[test]
+    fn test_byline_priority_resolves_conflicting_sources() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta name="author" content="Metro Desk" />
+                </head>
+                <body>
+                    <article>
+                        <p class="byline">By Jane Doe</p>
+                        <p>Story text.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+        let document = Html::parse_document(html);
+        let byline_for = |priority| {
+            let options = ReadabilityOptions::builder()
+                .byline_priority(priority)
+                .build();
+            get_article_metadata(&document, Metadata::default(), &options).byline
+        };
+
+        assert_eq!(
+            byline_for(BylinePriority::Auto),
+            Some("Metro Desk".to_string())
+        );
+        assert_eq!(
+            byline_for(BylinePriority::MetaFirst),
+            Some("Metro Desk".to_string())
+        );
+        assert_eq!(
+            byline_for(BylinePriority::DomFirst),
+            Some("By Jane Doe".to_string())
+        );
+        assert_eq!(
+            byline_for(BylinePriority::PreferPersonName),
+            Some("By Jane Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn test_accept_agency_bylines() {
+        let metadata_for = |author: &str, accept: bool| {
+            let html = format!(
+                r#"<html><head><meta name="author" content="{author}" /></head>
+                <body><article><p>Story text.</p></article></body></html>"#
+            );
+            let options = ReadabilityOptions::builder()
+                .accept_agency_bylines(accept)
+                .build();
+            get_article_metadata(&Html::parse_document(&html), Metadata::default(), &options).byline
+        };
+
+        assert_eq!(metadata_for("Reuters", false), None);
+        assert_eq!(metadata_for("Reuters", true), Some("Reuters".to_string()));
+        assert_eq!(
+            metadata_for("Associated Press", true),
+            Some("Associated Press".to_string())
+        );
+        assert_eq!(metadata_for("Our Foreign Staff", true), None);
+        assert_eq!(metadata_for("Jane Doe", true), Some("Jane Doe".to_string()));
+    }
+
     #[test]
     fn test_wapo_byline_is_detected() {
         let html = fs::read_to_string("tests/test-pages/wapo-1/source.html").unwrap();
@@ -2115,13 +2384,46 @@ mod tests {
             "pb-byline element not found"
         );
         let elem = document.select(&selector).next().unwrap();
-        let text = collect_byline_candidate_text(elem.clone());
+        let text = collect_byline_candidate_text(elem, &BylineWords::default());
         assert!(
             text.contains("Erin Cunningham"),
             "pb-byline text was {:?}",
             text
         );
-        let dom_byline = extract_byline_from_document(&document).expect("should detect DOM byline");
+        let dom_byline = extract_byline_from_document(&document, &BylineWords::default())
+            .expect("should detect DOM byline");
         assert_eq!(dom_byline.text, "By Erin Cunningham");
     }
+
+    #[test]
+    fn test_short_byline_candidate_text_skips_long_containers() {
+        let names: String = (0..50)
+            .map(|i| {
+                format!(r#"<a href="/people/{i}">Jane Doe{i}</a> wrote about the city council. "#)
+            })
+            .collect();
+        let html = format!(
+            r#"<div class="authors">{names}</div><div class="byline">By <a href="/jane">Jane Doe</a></div>"#
+        );
+        let document = Html::parse_document(&html);
+        let words = BylineWords::default();
+        let container = document
+            .select(&Selector::parse(".authors").unwrap())
+            .next()
+            .unwrap();
+        let byline = document
+            .select(&Selector::parse(".byline").unwrap())
+            .next()
+            .unwrap();
+
+        assert_eq!(
+            collect_short_byline_candidate_text(container, &words, 120),
+            None
+        );
+        assert!(collect_byline_candidate_text(container, &words).len() > 120);
+        assert_eq!(
+            collect_short_byline_candidate_text(byline, &words, 120).as_deref(),
+            Some(collect_byline_candidate_text(byline, &words).as_str())
+        );
+    }
 }
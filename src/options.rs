@@ -23,7 +23,52 @@
 //! let readability = Readability::new(html, None, Some(options)).unwrap();
 //! ```
 
+use crate::constants::{is_unlikely_class_id, REGEXPS};
+use crate::utils::BylineWords;
 use regex::Regex;
+use std::fmt;
+use std::sync::Arc;
+
+/// How the byline found in page metadata is weighed against one found in the DOM.
+///
+/// Only matters when both sources produce a byline and they disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BylinePriority {
+    /// Keep the metadata byline unless it looks like an organization credit, a
+    /// dateline or a weaker form of the DOM byline.
+    #[default]
+    Auto,
+    /// Always keep the JSON-LD or `<meta>` author.
+    MetaFirst,
+    /// Always take the byline found in the article markup.
+    DomFirst,
+    /// Take whichever value looks like a person's name when only one of them does,
+    /// falling back to [`BylinePriority::Auto`] otherwise.
+    PreferPersonName,
+}
+
+/// What kind of element a URL passed to a [`UrlRewriter`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlContext {
+    /// `href` of an `<a>` or `<area>`
+    Link,
+    /// `src` or `srcset` of an `<img>`, a `<picture>` source or an image input
+    Image,
+    /// `src` of an `<audio>` or `<video>`, or of a `<source>` or `<track>` inside one
+    Media,
+    /// `src` of an `<iframe>`
+    Iframe,
+}
+
+/// Callback rewriting link and media URLs in the article content.
+///
+/// See [`ReadabilityOptions::url_rewriter`].
+pub type UrlRewriter = Arc<dyn Fn(UrlContext, &str) -> Option<String> + Send + Sync>;
+
+/// Callback rewriting image URLs given the rendition size, if known.
+///
+/// See [`ReadabilityOptions::image_url_rewriter`].
+pub type ImageUrlRewriter = Arc<dyn Fn(&str, Option<(u32, u32)>) -> String + Send + Sync>;
 
 /// Configuration options for the Readability parser.
 ///
@@ -55,7 +100,7 @@ use regex::Regex;
 /// ## Field Descriptions
 ///
 /// See individual field documentation for details on what each option controls.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ReadabilityOptions {
     /// Enable debug logging to stderr.
     ///
@@ -144,6 +189,354 @@ pub struct ReadabilityOptions {
     ///
     /// Default: `0.0`
     pub link_density_modifier: f64,
+
+    /// Extra heading phrases that mark "related content" modules.
+    ///
+    /// Containers inside the article whose heading contains one of these phrases
+    /// (case-insensitive) are removed when they are mostly links to the same site.
+    /// The phrases are added to a built-in list covering "related", "more from",
+    /// "read next", "recommended" and "you may also like".
+    ///
+    /// Default: `vec![]`
+    pub related_content_phrases: Vec<String>,
+
+    /// Normalize the heading hierarchy of the extracted content.
+    ///
+    /// When `true`, a leading heading that repeats the article title is removed and
+    /// heading levels are shifted so the top level used is `<h2>`, keeping their
+    /// relative depth. Headings inside blockquotes and tables are left alone.
+    ///
+    /// Default: `false`
+    pub normalize_headings: bool,
+
+    /// Sanitize the extracted article content.
+    ///
+    /// When `true`, inline event handler attributes (`onclick`, `onload`, ...) are
+    /// removed, `javascript:` and `data:text/html` URLs are dropped from links and
+    /// sources, and any `<script>` or `<style>` left inside kept containers is
+    /// removed. Disable this only if the output is sanitized elsewhere.
+    ///
+    /// Default: `true`
+    pub sanitize: bool,
+
+    /// Remove inline styles and presentational attributes from the output.
+    ///
+    /// When `true`, `style`, `bgcolor`, `align`, `border`, `hspace`, `vspace` and
+    /// the other presentational attributes Readability.js removes are stripped from
+    /// all elements, as are `width` and `height` on tables and preformatted blocks.
+    /// Hidden elements are still detected from their styles before stripping.
+    /// `<span>` and `<font>` wrappers left without other attributes are then
+    /// unwrapped, while semantic inline elements such as `<sup>`, `<abbr>` or
+    /// `<time>` are kept. When `false`, these attributes pass through untouched.
+    ///
+    /// Default: `true`
+    pub strip_styles: bool,
+
+    /// Remove `data-*` attributes from the output.
+    ///
+    /// When `true`, custom data attributes such as `data-module` or `data-analytics-id`
+    /// are stripped from all elements in the final cleanup, after lazy-loaded
+    /// `data-src` images have been promoted. Names listed in
+    /// `data_attributes_to_preserve` are kept.
+    ///
+    /// Default: `true`
+    pub strip_data_attributes: bool,
+
+    /// `data-*` attributes to keep when `strip_data_attributes` is enabled.
+    ///
+    /// Names are matched case-insensitively against the full attribute name,
+    /// for example `"data-footnote-id"`.
+    ///
+    /// Default: `vec![]`
+    pub data_attributes_to_preserve: Vec<String>,
+
+    /// Remove trailing photo credits from figure captions.
+    ///
+    /// When `true`, a clause such as "Photo: Getty Images" or "Credit: Jane Doe/Reuters"
+    /// at the end of a `<figcaption>` is removed, keeping the descriptive text before
+    /// it. Only credits naming an agency, organization or person are removed.
+    ///
+    /// Default: `false`
+    pub strip_image_credits: bool,
+
+    /// Extra class/id pattern for elements that are unlikely to be content.
+    ///
+    /// Matched against an element's `class` and `id` in addition to the built-in
+    /// list ("sidebar", "comment", "sponsor", ...). Matching elements are skipped as
+    /// candidates, unless they also match the built-in or extra "maybe a candidate"
+    /// patterns. When set, elements matching either list are also left out of the
+    /// extracted content, not only out of candidate selection.
+    ///
+    /// Default: `None`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::ReadabilityOptions;
+    /// use regex::Regex;
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .extra_unlikely_candidates(Regex::new(r"(?i)werbung|publicidad|reklama").unwrap())
+    ///     .build();
+    /// ```
+    pub extra_unlikely_candidates: Option<Regex>,
+
+    /// Extra class/id pattern for elements that may be content after all.
+    ///
+    /// An element matching an unlikely-candidate pattern is still considered when
+    /// its `class` or `id` matches this pattern or the built-in one ("article",
+    /// "body", "content", "main", ...).
+    ///
+    /// Default: `None`
+    pub extra_ok_maybe_candidates: Option<Regex>,
+
+    /// Force `<details>` disclosure widgets open in the output.
+    ///
+    /// `<details>` and `<summary>` are always kept, and their collapsed content counts
+    /// towards scoring. When `true`, an `open` attribute is added to every
+    /// `<details>` element so readers without interactivity show the content.
+    ///
+    /// Default: `false`
+    pub open_details: bool,
+
+    /// Remove in-article tables of contents.
+    ///
+    /// When `true`, a list whose items nearly all link to fragments of the page
+    /// (`#installation`) is removed together with the "Table of contents",
+    /// "Contents" or "In this article" heading introducing it. When `false`, the
+    /// list is kept and the `id` and `name` anchors it points at are preserved.
+    ///
+    /// Default: `false`
+    pub strip_toc: bool,
+
+    /// Extra labels that mark a leftover ad slot.
+    ///
+    /// Paragraphs and other blocks whose entire text is one of these labels
+    /// (case-insensitive) are removed. The labels are added to a built-in list
+    /// covering "Advertisement", "Advertising", "Sponsored", "Publicité", "Anzeige",
+    /// "Реклама" and a few other languages. Text that merely contains a label is kept.
+    ///
+    /// Default: `vec![]`
+    pub ad_labels: Vec<String>,
+
+    /// Extra words that introduce an author name in a byline.
+    ///
+    /// A line such as "By Jane Doe" is recognized as a byline when it starts with one
+    /// of these words (case-insensitive), followed by a capitalized name. The words
+    /// are added to a built-in list covering "By", "Par", "Von", "Por", "Di", "Door",
+    /// "Av", and Arabic, Hebrew and Persian prefixes, whose names need no capital.
+    ///
+    /// Default: `vec![]`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::ReadabilityOptions;
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .byline_prefixes(vec!["Af".to_string(), "Autor:".to_string()])
+    ///     .build();
+    /// ```
+    pub byline_prefixes: Vec<String>,
+
+    /// Whether to title-case bylines written entirely in capitals.
+    ///
+    /// Some sites render bylines in ALL CAPS ("BY JOHN SMITH"). When enabled,
+    /// such bylines are returned as "By John Smith". Bylines with any lowercase
+    /// letter are left as they are.
+    ///
+    /// Default: `false`
+    pub title_case_bylines: bool,
+
+    /// Extra words marking a byline as an organization credit rather than a person.
+    ///
+    /// The built-in list ("Staff", "Newsroom", "Bureau", ...) needs two words to
+    /// match; a byline containing any one of these words ("Redaktion",
+    /// "rédaction") is dropped on its own.
+    ///
+    /// Default: `vec![]`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::ReadabilityOptions;
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .extra_org_keywords(vec!["Redaktion".to_string()])
+    ///     .build();
+    /// ```
+    pub extra_org_keywords: Vec<String>,
+
+    /// Extra words ruling out a byline as a personal name.
+    ///
+    /// Added to the built-in list ("Reporter", "Editor", "Staff", ...); a candidate
+    /// name containing one of these words is not treated as an author.
+    ///
+    /// Default: `vec![]`
+    pub extra_author_disqualifiers: Vec<String>,
+
+    /// Remove the dateline opening the first paragraph.
+    ///
+    /// When `true`, a short place clause ending in a dash at the start of the lede,
+    /// such as "CAIRO —", "Cairo, Egypt —" or "San Francisco (Reuters) –", is
+    /// removed while the rest of the paragraph is kept. A sentence that merely
+    /// opens with a city name and a dash is left alone.
+    ///
+    /// Default: `false`
+    pub strip_datelines: bool,
+
+    /// Which byline wins when the page metadata and the article markup disagree.
+    ///
+    /// See [`BylinePriority`] for the available strategies.
+    ///
+    /// Default: `BylinePriority::Auto`
+    pub byline_priority: BylinePriority,
+
+    /// Whether to keep a wire-agency credit ("Reuters", "Associated Press", "... (AP)")
+    /// as the byline.
+    ///
+    /// Agency credits are normally dropped along with other organization credits,
+    /// leaving the byline empty. When enabled, a metadata byline naming only a
+    /// known agency is kept; generic credits ("Our Foreign Staff") are still dropped.
+    ///
+    /// Default: `false`
+    pub accept_agency_bylines: bool,
+
+    /// Maximum size of the input HTML in bytes.
+    ///
+    /// Larger inputs are rejected by `Readability::new` with
+    /// `ReadabilityError::InputTooLarge` before any parsing. Set to 0 to disable
+    /// the limit.
+    ///
+    /// Default: `0` (no limit)
+    pub max_input_bytes: usize,
+
+    /// Size in bytes above which `<script>` and `<style>` bodies are dropped before parsing.
+    ///
+    /// Inline bundles and stylesheets can run to megabytes and are removed before
+    /// extraction anyway; dropping them up front saves parsing them. JSON-LD and TeX
+    /// scripts are always kept. Set to 0 to keep every body.
+    ///
+    /// Default: `65536` (64 KiB)
+    pub max_script_style_bytes: usize,
+
+    /// Maximum element nesting depth of the parsed document.
+    ///
+    /// Deeper documents are rejected by `Readability::new` with
+    /// `ReadabilityError::DocumentTooDeep`. With no limit, documents nested more
+    /// than 256 levels deep still parse: elements past that depth are unwrapped,
+    /// keeping their text, since cleanup slows down quadratically with depth.
+    ///
+    /// Default: `0` (no limit)
+    pub max_nesting_depth: usize,
+
+    /// Record wall-clock time per parse phase.
+    ///
+    /// When enabled, the parsed `Article` carries a `Timings` breakdown (HTML parsing,
+    /// preprocessing, scoring, candidate selection, cleanup, serialization and
+    /// metadata) in its `timings` field. When disabled no clock is read, which
+    /// keeps `wasm32-unknown-unknown` builds, where `std::time::Instant` panics,
+    /// safe.
+    ///
+    /// Default: `false`
+    pub collect_timings: bool,
+
+    /// Factor applied to `char_threshold` when the article text is mostly Chinese,
+    /// Japanese or Korean.
+    ///
+    /// These languages convey far more per character, so a CJK article only needs
+    /// `char_threshold * cjk_length_factor` characters. Use `1.0` to treat all
+    /// text alike.
+    ///
+    /// Default: `0.4`
+    pub cjk_length_factor: f64,
+
+    /// Resolve protocol-relative URLs (`//cdn.example.com/a.jpg`) to `https:` when
+    /// no document URL is given.
+    ///
+    /// With a document URL, or a `<base href>` that resolves, such URLs take its
+    /// scheme. Without one they are normally left as they are; when `true`, links,
+    /// media sources and a protocol-relative `<base href>` are resolved as if the
+    /// page had been served over https.
+    ///
+    /// Default: `false`
+    pub assume_https_for_protocol_relative: bool,
+
+    /// Rewrite link, image, media and iframe URLs in the article content.
+    ///
+    /// Called for every `href`, `src` and `srcset` candidate URL of the elements
+    /// listed in [`UrlContext`] once relative URLs have been resolved and lazy
+    /// media sources promoted, just before the content is serialized. Returning
+    /// `None` keeps the URL as it is, `Some` replaces it. Sanitization still runs
+    /// afterwards, so a rewritten `javascript:` URL is dropped.
+    ///
+    /// ```rust
+    /// use readabilityrs::{ReadabilityOptions, UrlContext};
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .url_rewriter(|context, url| {
+    ///         (context == UrlContext::Image)
+    ///             .then(|| format!("https://images.example.com/?url={url}"))
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// Default: `None`
+    pub url_rewriter: Option<UrlRewriter>,
+
+    /// Rewrite image URLs with the size of the rendition they point at.
+    ///
+    /// Called with the resolved URL of every `<img>` `src`, `<img>` and
+    /// `<picture>` `srcset` candidate and `<video>` `poster`, and a
+    /// `(width, height)` hint in pixels. The hint comes from the `width` and
+    /// `height` attributes or inline style, and for `srcset` candidates from the
+    /// `w` or `x` descriptor, with the height scaled to match when the declared
+    /// aspect ratio is known. A side that cannot be worked out is `0`; `None`
+    /// means neither is known. Descriptors are kept. Runs before
+    /// [`url_rewriter`](Self::url_rewriter), which sees the rewritten URL.
+    ///
+    /// ```rust
+    /// use readabilityrs::ReadabilityOptions;
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .image_url_rewriter(|url, size| match size {
+    ///         Some((width, _)) if width > 0 => {
+    ///             format!("https://images.example.com/{width}/{url}")
+    ///         }
+    ///         _ => format!("https://images.example.com/{url}"),
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// Default: `None`
+    pub image_url_rewriter: Option<ImageUrlRewriter>,
+
+    /// Remove tracking query parameters from article links.
+    ///
+    /// When `true`, parameters such as `utm_source`, `fbclid`, `gclid`, `mc_cid`,
+    /// `ref` and `igshid` are dropped from every `href` in the content, along with
+    /// any listed in [`extra_tracking_params`](Self::extra_tracking_params). Other
+    /// parameters keep their exact spelling and order, and the fragment is kept.
+    /// Metadata URLs are only touched with
+    /// [`strip_tracking_params_from_metadata`](Self::strip_tracking_params_from_metadata).
+    ///
+    /// Default: `false`
+    pub strip_tracking_params: bool,
+
+    /// Additional query parameter names removed by the tracking parameter cleanup.
+    ///
+    /// Names are compared without case, after percent-decoding.
+    ///
+    /// Default: `vec![]`
+    pub extra_tracking_params: Vec<String>,
+
+    /// Remove tracking query parameters from the lead image URL in the metadata.
+    ///
+    /// Uses the same parameter list as [`strip_tracking_params`](Self::strip_tracking_params),
+    /// independently of it.
+    ///
+    /// Default: `false`
+    pub strip_tracking_params_from_metadata: bool,
 }
 
 impl Default for ReadabilityOptions {
@@ -158,15 +551,134 @@ impl Default for ReadabilityOptions {
             disable_json_ld: false,
             allowed_video_regex: None,
             link_density_modifier: 0.0,
+            related_content_phrases: Vec::new(),
+            normalize_headings: false,
+            sanitize: true,
+            strip_styles: true,
+            strip_data_attributes: true,
+            data_attributes_to_preserve: Vec::new(),
+            strip_image_credits: false,
+            extra_unlikely_candidates: None,
+            extra_ok_maybe_candidates: None,
+            open_details: false,
+            strip_toc: false,
+            ad_labels: Vec::new(),
+            byline_prefixes: Vec::new(),
+            title_case_bylines: false,
+            extra_org_keywords: Vec::new(),
+            extra_author_disqualifiers: Vec::new(),
+            strip_datelines: false,
+            byline_priority: BylinePriority::Auto,
+            accept_agency_bylines: false,
+            max_input_bytes: 0,
+            max_script_style_bytes: 64 * 1024,
+            max_nesting_depth: 0,
+            collect_timings: false,
+            cjk_length_factor: 0.4,
+            assume_https_for_protocol_relative: false,
+            url_rewriter: None,
+            image_url_rewriter: None,
+            strip_tracking_params: false,
+            extra_tracking_params: Vec::new(),
+            strip_tracking_params_from_metadata: false,
         }
     }
 }
 
+impl fmt::Debug for ReadabilityOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadabilityOptions")
+            .field("debug", &self.debug)
+            .field("max_elems_to_parse", &self.max_elems_to_parse)
+            .field("nb_top_candidates", &self.nb_top_candidates)
+            .field("char_threshold", &self.char_threshold)
+            .field("classes_to_preserve", &self.classes_to_preserve)
+            .field("keep_classes", &self.keep_classes)
+            .field("disable_json_ld", &self.disable_json_ld)
+            .field("allowed_video_regex", &self.allowed_video_regex)
+            .field("link_density_modifier", &self.link_density_modifier)
+            .field("related_content_phrases", &self.related_content_phrases)
+            .field("normalize_headings", &self.normalize_headings)
+            .field("sanitize", &self.sanitize)
+            .field("strip_styles", &self.strip_styles)
+            .field("strip_data_attributes", &self.strip_data_attributes)
+            .field(
+                "data_attributes_to_preserve",
+                &self.data_attributes_to_preserve,
+            )
+            .field("strip_image_credits", &self.strip_image_credits)
+            .field("extra_unlikely_candidates", &self.extra_unlikely_candidates)
+            .field("extra_ok_maybe_candidates", &self.extra_ok_maybe_candidates)
+            .field("open_details", &self.open_details)
+            .field("strip_toc", &self.strip_toc)
+            .field("ad_labels", &self.ad_labels)
+            .field("byline_prefixes", &self.byline_prefixes)
+            .field("title_case_bylines", &self.title_case_bylines)
+            .field("extra_org_keywords", &self.extra_org_keywords)
+            .field(
+                "extra_author_disqualifiers",
+                &self.extra_author_disqualifiers,
+            )
+            .field("strip_datelines", &self.strip_datelines)
+            .field("byline_priority", &self.byline_priority)
+            .field("accept_agency_bylines", &self.accept_agency_bylines)
+            .field("max_input_bytes", &self.max_input_bytes)
+            .field("max_script_style_bytes", &self.max_script_style_bytes)
+            .field("max_nesting_depth", &self.max_nesting_depth)
+            .field("collect_timings", &self.collect_timings)
+            .field("cjk_length_factor", &self.cjk_length_factor)
+            .field(
+                "assume_https_for_protocol_relative",
+                &self.assume_https_for_protocol_relative,
+            )
+            .field("url_rewriter", &self.url_rewriter.as_ref().map(|_| "<fn>"))
+            .field(
+                "image_url_rewriter",
+                &self.image_url_rewriter.as_ref().map(|_| "<fn>"),
+            )
+            .field("strip_tracking_params", &self.strip_tracking_params)
+            .field("extra_tracking_params", &self.extra_tracking_params)
+            .field(
+                "strip_tracking_params_from_metadata",
+                &self.strip_tracking_params_from_metadata,
+            )
+            .finish()
+    }
+}
+
 impl ReadabilityOptions {
     /// Creates a new builder for ReadabilityOptions
     pub fn builder() -> ReadabilityOptionsBuilder {
         ReadabilityOptionsBuilder::default()
     }
+
+    /// Regex for video embeds to keep, falling back to the built-in provider list
+    pub(crate) fn video_regex(&self) -> &Regex {
+        self.allowed_video_regex.as_ref().unwrap_or(&REGEXPS.videos)
+    }
+
+    /// Extra byline words from `byline_prefixes`, `extra_org_keywords` and
+    /// `extra_author_disqualifiers`
+    pub(crate) fn byline_words(&self) -> BylineWords<'_> {
+        BylineWords {
+            prefixes: &self.byline_prefixes,
+            org_keywords: &self.extra_org_keywords,
+            author_disqualifiers: &self.extra_author_disqualifiers,
+        }
+    }
+
+    /// Check an element's `class` and `id` against the built-in and extra unlikely patterns
+    ///
+    /// Elements with neither attribute are never unlikely.
+    pub(crate) fn is_unlikely_candidate(&self, class: &str, id: &str) -> bool {
+        is_unlikely_class_id(
+            class,
+            id,
+            None,
+            self.extra_unlikely_candidates.as_ref(),
+            self.extra_ok_maybe_candidates.as_ref(),
+        )
+    }
 }
 
 /// Builder for [`ReadabilityOptions`].
@@ -196,6 +708,36 @@ pub struct ReadabilityOptionsBuilder {
     disable_json_ld: Option<bool>,
     allowed_video_regex: Option<Regex>,
     link_density_modifier: Option<f64>,
+    related_content_phrases: Option<Vec<String>>,
+    normalize_headings: Option<bool>,
+    sanitize: Option<bool>,
+    strip_styles: Option<bool>,
+    strip_data_attributes: Option<bool>,
+    data_attributes_to_preserve: Option<Vec<String>>,
+    strip_image_credits: Option<bool>,
+    extra_unlikely_candidates: Option<Regex>,
+    extra_ok_maybe_candidates: Option<Regex>,
+    open_details: Option<bool>,
+    strip_toc: Option<bool>,
+    ad_labels: Option<Vec<String>>,
+    byline_prefixes: Option<Vec<String>>,
+    title_case_bylines: Option<bool>,
+    extra_org_keywords: Option<Vec<String>>,
+    extra_author_disqualifiers: Option<Vec<String>>,
+    strip_datelines: Option<bool>,
+    byline_priority: Option<BylinePriority>,
+    accept_agency_bylines: Option<bool>,
+    max_input_bytes: Option<usize>,
+    max_script_style_bytes: Option<usize>,
+    max_nesting_depth: Option<usize>,
+    collect_timings: Option<bool>,
+    cjk_length_factor: Option<f64>,
+    assume_https_for_protocol_relative: Option<bool>,
+    url_rewriter: Option<UrlRewriter>,
+    image_url_rewriter: Option<ImageUrlRewriter>,
+    strip_tracking_params: Option<bool>,
+    extra_tracking_params: Option<Vec<String>>,
+    strip_tracking_params_from_metadata: Option<bool>,
 }
 
 impl ReadabilityOptionsBuilder {
@@ -253,6 +795,192 @@ impl ReadabilityOptionsBuilder {
         self
     }
 
+    /// Set extra heading phrases that mark related-content modules
+    pub fn related_content_phrases(mut self, phrases: Vec<String>) -> Self {
+        self.related_content_phrases = Some(phrases);
+        self
+    }
+
+    /// Enable or disable heading normalization
+    pub fn normalize_headings(mut self, normalize: bool) -> Self {
+        self.normalize_headings = Some(normalize);
+        self
+    }
+
+    /// Enable or disable sanitization of the article content
+    pub fn sanitize(mut self, sanitize: bool) -> Self {
+        self.sanitize = Some(sanitize);
+        self
+    }
+
+    /// Enable or disable removal of presentational attributes
+    pub fn strip_styles(mut self, strip: bool) -> Self {
+        self.strip_styles = Some(strip);
+        self
+    }
+
+    /// Enable or disable removal of `data-*` attributes
+    pub fn strip_data_attributes(mut self, strip: bool) -> Self {
+        self.strip_data_attributes = Some(strip);
+        self
+    }
+
+    /// Set `data-*` attributes to keep when stripping data attributes
+    pub fn data_attributes_to_preserve(mut self, attributes: Vec<String>) -> Self {
+        self.data_attributes_to_preserve = Some(attributes);
+        self
+    }
+
+    /// Enable or disable removal of photo credits from captions
+    pub fn strip_image_credits(mut self, strip: bool) -> Self {
+        self.strip_image_credits = Some(strip);
+        self
+    }
+
+    /// Set extra class/id patterns for unlikely content
+    pub fn extra_unlikely_candidates(mut self, regex: Regex) -> Self {
+        self.extra_unlikely_candidates = Some(regex);
+        self
+    }
+
+    /// Set extra class/id patterns that rescue unlikely content
+    pub fn extra_ok_maybe_candidates(mut self, regex: Regex) -> Self {
+        self.extra_ok_maybe_candidates = Some(regex);
+        self
+    }
+
+    /// Enable or disable forcing disclosure widgets open
+    pub fn open_details(mut self, open: bool) -> Self {
+        self.open_details = Some(open);
+        self
+    }
+
+    /// Enable or disable removal of in-article tables of contents
+    pub fn strip_toc(mut self, strip: bool) -> Self {
+        self.strip_toc = Some(strip);
+        self
+    }
+
+    /// Set extra labels that mark leftover ad slots
+    pub fn ad_labels(mut self, labels: Vec<String>) -> Self {
+        self.ad_labels = Some(labels);
+        self
+    }
+
+    /// Set extra words that introduce an author name in a byline
+    pub fn byline_prefixes(mut self, prefixes: Vec<String>) -> Self {
+        self.byline_prefixes = Some(prefixes);
+        self
+    }
+
+    /// Set whether all-caps bylines are title-cased
+    pub fn title_case_bylines(mut self, enable: bool) -> Self {
+        self.title_case_bylines = Some(enable);
+        self
+    }
+
+    /// Set extra words marking a byline as an organization credit
+    pub fn extra_org_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.extra_org_keywords = Some(keywords);
+        self
+    }
+
+    /// Set extra words ruling out a byline as a personal name
+    pub fn extra_author_disqualifiers(mut self, words: Vec<String>) -> Self {
+        self.extra_author_disqualifiers = Some(words);
+        self
+    }
+
+    /// Enable or disable removal of the dateline opening the article
+    pub fn strip_datelines(mut self, strip: bool) -> Self {
+        self.strip_datelines = Some(strip);
+        self
+    }
+
+    /// Set how metadata and DOM bylines are weighed against each other
+    pub fn byline_priority(mut self, priority: BylinePriority) -> Self {
+        self.byline_priority = Some(priority);
+        self
+    }
+
+    /// Enable or disable keeping wire-agency credits as the byline
+    pub fn accept_agency_bylines(mut self, enable: bool) -> Self {
+        self.accept_agency_bylines = Some(enable);
+        self
+    }
+
+    /// Set the maximum input size in bytes (0 = no limit)
+    pub fn max_input_bytes(mut self, max: usize) -> Self {
+        self.max_input_bytes = Some(max);
+        self
+    }
+
+    /// Set the size above which script and style bodies are dropped before parsing (0 = keep all)
+    pub fn max_script_style_bytes(mut self, max: usize) -> Self {
+        self.max_script_style_bytes = Some(max);
+        self
+    }
+
+    /// Set the maximum element nesting depth (0 = no limit)
+    pub fn max_nesting_depth(mut self, max: usize) -> Self {
+        self.max_nesting_depth = Some(max);
+        self
+    }
+
+    /// Record how long each parse phase takes in `Article::timings`
+    pub fn collect_timings(mut self, collect_timings: bool) -> Self {
+        self.collect_timings = Some(collect_timings);
+        self
+    }
+
+    /// Set the factor applied to length thresholds for mostly CJK text
+    pub fn cjk_length_factor(mut self, factor: f64) -> Self {
+        self.cjk_length_factor = Some(factor);
+        self
+    }
+
+    /// Set whether protocol-relative URLs get https when there is no document URL
+    pub fn assume_https_for_protocol_relative(mut self, assume: bool) -> Self {
+        self.assume_https_for_protocol_relative = Some(assume);
+        self
+    }
+
+    /// Set a callback rewriting link and media URLs
+    pub fn url_rewriter(
+        mut self,
+        rewriter: impl Fn(UrlContext, &str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.url_rewriter = Some(Arc::new(rewriter));
+        self
+    }
+
+    /// Set a callback rewriting image URLs with their rendition size
+    pub fn image_url_rewriter(
+        mut self,
+        rewriter: impl Fn(&str, Option<(u32, u32)>) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.image_url_rewriter = Some(Arc::new(rewriter));
+        self
+    }
+
+    /// Set whether tracking query parameters are removed from links
+    pub fn strip_tracking_params(mut self, strip: bool) -> Self {
+        self.strip_tracking_params = Some(strip);
+        self
+    }
+
+    /// Set additional tracking query parameters to remove
+    pub fn extra_tracking_params(mut self, params: Vec<String>) -> Self {
+        self.extra_tracking_params = Some(params);
+        self
+    }
+
+    /// Set whether tracking query parameters are removed from the lead image URL
+    pub fn strip_tracking_params_from_metadata(mut self, strip: bool) -> Self {
+        self.strip_tracking_params_from_metadata = Some(strip);
+        self
+    }
+
     /// Build the ReadabilityOptions
     pub fn build(self) -> ReadabilityOptions {
         let defaults = ReadabilityOptions::default();
@@ -272,6 +1000,68 @@ impl ReadabilityOptionsBuilder {
             link_density_modifier: self
                 .link_density_modifier
                 .unwrap_or(defaults.link_density_modifier),
+            related_content_phrases: self
+                .related_content_phrases
+                .unwrap_or(defaults.related_content_phrases),
+            normalize_headings: self
+                .normalize_headings
+                .unwrap_or(defaults.normalize_headings),
+            sanitize: self.sanitize.unwrap_or(defaults.sanitize),
+            strip_styles: self.strip_styles.unwrap_or(defaults.strip_styles),
+            strip_data_attributes: self
+                .strip_data_attributes
+                .unwrap_or(defaults.strip_data_attributes),
+            data_attributes_to_preserve: self
+                .data_attributes_to_preserve
+                .unwrap_or(defaults.data_attributes_to_preserve),
+            strip_image_credits: self
+                .strip_image_credits
+                .unwrap_or(defaults.strip_image_credits),
+            extra_unlikely_candidates: self
+                .extra_unlikely_candidates
+                .or(defaults.extra_unlikely_candidates),
+            extra_ok_maybe_candidates: self
+                .extra_ok_maybe_candidates
+                .or(defaults.extra_ok_maybe_candidates),
+            open_details: self.open_details.unwrap_or(defaults.open_details),
+            strip_toc: self.strip_toc.unwrap_or(defaults.strip_toc),
+            ad_labels: self.ad_labels.unwrap_or(defaults.ad_labels),
+            byline_prefixes: self.byline_prefixes.unwrap_or(defaults.byline_prefixes),
+            title_case_bylines: self
+                .title_case_bylines
+                .unwrap_or(defaults.title_case_bylines),
+            extra_org_keywords: self
+                .extra_org_keywords
+                .unwrap_or(defaults.extra_org_keywords),
+            extra_author_disqualifiers: self
+                .extra_author_disqualifiers
+                .unwrap_or(defaults.extra_author_disqualifiers),
+            strip_datelines: self.strip_datelines.unwrap_or(defaults.strip_datelines),
+            byline_priority: self.byline_priority.unwrap_or(defaults.byline_priority),
+            accept_agency_bylines: self
+                .accept_agency_bylines
+                .unwrap_or(defaults.accept_agency_bylines),
+            max_input_bytes: self.max_input_bytes.unwrap_or(defaults.max_input_bytes),
+            max_script_style_bytes: self
+                .max_script_style_bytes
+                .unwrap_or(defaults.max_script_style_bytes),
+            max_nesting_depth: self.max_nesting_depth.unwrap_or(defaults.max_nesting_depth),
+            collect_timings: self.collect_timings.unwrap_or(defaults.collect_timings),
+            cjk_length_factor: self.cjk_length_factor.unwrap_or(defaults.cjk_length_factor),
+            assume_https_for_protocol_relative: self
+                .assume_https_for_protocol_relative
+                .unwrap_or(defaults.assume_https_for_protocol_relative),
+            url_rewriter: self.url_rewriter.or(defaults.url_rewriter),
+            image_url_rewriter: self.image_url_rewriter.or(defaults.image_url_rewriter),
+            strip_tracking_params: self
+                .strip_tracking_params
+                .unwrap_or(defaults.strip_tracking_params),
+            extra_tracking_params: self
+                .extra_tracking_params
+                .unwrap_or(defaults.extra_tracking_params),
+            strip_tracking_params_from_metadata: self
+                .strip_tracking_params_from_metadata
+                .unwrap_or(defaults.strip_tracking_params_from_metadata),
         }
     }
 }
@@ -3,8 +3,11 @@
 //! This module implements Mozilla's _prepArticle pipeline, which cleans
 //! the extracted article content by removing unwanted elements.
 
+use crate::cleaner::keep_in_page_toc;
+use crate::constants::SHARE_WIDGET_WORDS;
+use crate::options::ReadabilityOptions;
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Captures, Regex};
 
 /// Remove nav-heavy wrappers by descending into content-like children.
 fn unwrap_nav_wrappers(html: &str) -> String {
@@ -21,14 +24,14 @@ fn unwrap_nav_wrappers(html: &str) -> String {
 /// Prepare extracted article content for final output
 ///
 /// This implements Mozilla's _prepArticle() pipeline using regex-based cleaning
-pub fn prep_article(html: &str) -> String {
+pub fn prep_article(html: &str, options: &ReadabilityOptions) -> String {
     let mut html = html.to_string();
 
     // Unwrap nav wrappers before removing elements
     html = unwrap_nav_wrappers(&html);
 
     // Step 1: Remove unwanted elements
-    html = remove_unwanted_elements(&html);
+    html = remove_unwanted_elements(&html, options.video_regex());
 
     // Step 2: Remove share buttons and social widgets
     html = remove_share_elements(&html);
@@ -45,90 +48,172 @@ pub fn prep_article(html: &str) -> String {
 /// Remove unwanted elements that are never part of article content
 ///
 /// Removes: forms, fieldsets, footer, aside, object, embed, iframe,
-/// input, textarea, select, button
-fn remove_unwanted_elements(html: &str) -> String {
+/// input, textarea, select, button. Objects, embeds and iframes pointing
+/// at an allowed video provider are kept, as are footers inside a
+/// blockquote, which hold the quote's attribution.
+fn remove_unwanted_elements(html: &str, video_regex: &Regex) -> String {
+    static UNWANTED_REGEXES: Lazy<Vec<(&str, Regex)>> = Lazy::new(|| {
+        [
+            ("form", r"(?is)<form\b[^>]*?>.*?</form>"),
+            ("fieldset", r"(?is)<fieldset\b[^>]*?>.*?</fieldset>"),
+            ("footer", r"(?is)<footer\b[^>]*?>.*?</footer>"),
+            ("aside", r"(?is)<aside\b[^>]*?>.*?</aside>"),
+            ("object", r"(?is)<object\b[^>]*?>.*?</object>"),
+            (
+                "embed",
+                r"(?is)<embed\b[^>]*?>.*?</embed>|<embed\b[^>]*?/?>",
+            ),
+            ("iframe", r"(?is)<iframe\b[^>]*?>.*?</iframe>"),
+            (
+                "input",
+                r"(?is)<input\b[^>]*?>.*?</input>|<input\b[^>]*?/?>",
+            ),
+            ("textarea", r"(?is)<textarea\b[^>]*?>.*?</textarea>"),
+            ("select", r"(?is)<select\b[^>]*?>.*?</select>"),
+            ("button", r"(?is)<button\b[^>]*?>.*?</button>"),
+            ("link", r"(?is)<link\b[^>]*?>.*?</link>|<link\b[^>]*?/?>"),
+        ]
+        .into_iter()
+        .map(|(name, pattern)| (name, Regex::new(pattern).unwrap()))
+        .collect()
+    });
+
     let mut result = html.to_string();
-    let tags = vec![
-        ("form", r"(?is)<form\b[^>]*?>.*?</form>"),
-        ("fieldset", r"(?is)<fieldset\b[^>]*?>.*?</fieldset>"),
-        ("footer", r"(?is)<footer\b[^>]*?>.*?</footer>"),
-        ("aside", r"(?is)<aside\b[^>]*?>.*?</aside>"),
-        ("object", r"(?is)<object\b[^>]*?>.*?</object>"),
-        (
-            "embed",
-            r"(?is)<embed\b[^>]*?>.*?</embed>|<embed\b[^>]*?/?>",
-        ),
-        ("iframe", r"(?is)<iframe\b[^>]*?>.*?</iframe>"),
-        (
-            "input",
-            r"(?is)<input\b[^>]*?>.*?</input>|<input\b[^>]*?/?>",
-        ),
-        ("textarea", r"(?is)<textarea\b[^>]*?>.*?</textarea>"),
-        ("select", r"(?is)<select\b[^>]*?>.*?</select>"),
-        ("button", r"(?is)<button\b[^>]*?>.*?</button>"),
-        ("link", r"(?is)<link\b[^>]*?>.*?</link>|<link\b[^>]*?/?>"),
-    ];
-
-    for (_name, pattern) in tags {
-        let re = Regex::new(pattern).unwrap();
-        result = re.replace_all(&result, "").to_string();
+    for (name, re) in UNWANTED_REGEXES.iter() {
+        let name = *name;
+        let keeps_videos = matches!(name, "object" | "embed" | "iframe");
+        let blockquotes = if name == "footer" {
+            blockquote_depths(&result)
+        } else {
+            Vec::new()
+        };
+        result = re
+            .replace_all(&result, |caps: &Captures| {
+                let start = caps.get(0).map_or(0, |m| m.start());
+                if (keeps_videos && video_regex.is_match(&caps[0]))
+                    || (name == "footer" && is_inside_blockquote(&blockquotes, start))
+                {
+                    caps[0].to_string()
+                } else {
+                    String::new()
+                }
+            })
+            .to_string();
     }
 
     result
 }
 
+/// Offsets of the `<blockquote>` tags in `html`, each with how many
+/// blockquotes are open after it
+fn blockquote_depths(html: &str) -> Vec<(usize, isize)> {
+    static OPEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<blockquote\b").unwrap());
+    static CLOSE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)</blockquote\s*>").unwrap());
+
+    let mut tags: Vec<(usize, isize)> = OPEN_REGEX
+        .find_iter(html)
+        .map(|tag| (tag.start(), 1))
+        .chain(CLOSE_REGEX.find_iter(html).map(|tag| (tag.start(), -1)))
+        .collect();
+    tags.sort_unstable();
+
+    let mut depth = 0;
+    for (_, tag_depth) in &mut tags {
+        depth += *tag_depth;
+        *tag_depth = depth;
+    }
+    tags
+}
+
+/// Check whether `pos` lies inside an open `<blockquote>` element, given the
+/// tag depths from [`blockquote_depths`]
+fn is_inside_blockquote(depths: &[(usize, isize)], pos: usize) -> bool {
+    let preceding = depths.partition_point(|&(offset, _)| offset < pos);
+    preceding > 0 && depths[preceding - 1].1 > 0
+}
+
 /// Remove share buttons and social widgets
 ///
-/// Removes elements with "share" or "social" in their class/id
+/// Removes elements whose class/id matches a share pattern and whose text is
+/// made up only of network names and share verbs. Blocks holding real prose,
+/// such as an author's "share your story" section, are kept.
 fn remove_share_elements(html: &str) -> String {
-    let mut result = html.to_string();
-    let tags = vec!["div", "span", "aside", "section"];
-    let keywords = vec!["share", "social", "sharedaddy"];
-
-    for tag in &tags {
-        for keyword in &keywords {
-            let class_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?class="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&class_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
-
-            let id_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?id="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&id_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
+    static SHARE_REGEXES: Lazy<Vec<Regex>> = Lazy::new(|| {
+        let tags = ["div", "span", "aside", "section"];
+        let keywords = ["share", "social", "sharedaddy", "addthis", "sharethis"];
+        let mut regexes = Vec::new();
+        for tag in tags {
+            for keyword in keywords {
+                for attr in ["class", "id"] {
+                    let pattern = format!(
+                        r#"(?is)<{tag}\b[^>]*?{attr}="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
+                    );
+                    regexes.push(Regex::new(&pattern).unwrap());
+                }
+            }
         }
+        regexes
+    });
+
+    let mut result = html.to_string();
+    for re in SHARE_REGEXES.iter() {
+        result = re
+            .replace_all(&result, |caps: &Captures| {
+                if is_share_widget_text(&caps[0]) {
+                    String::new()
+                } else {
+                    caps[0].to_string()
+                }
+            })
+            .to_string();
     }
 
     result
 }
 
+/// Check whether a fragment's text consists only of share-bar vocabulary
+fn is_share_widget_text(fragment: &str) -> bool {
+    static TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+    let text = TAG_REGEX.replace_all(fragment, " ");
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .all(|word| {
+            word.chars().all(|c| c.is_ascii_digit())
+                || SHARE_WIDGET_WORDS.contains(&word.to_lowercase().as_str())
+        })
+}
+
 /// Remove navigation lists and menu sections
 fn remove_navigation_elements(html: &str) -> String {
     let mut result = html.to_string();
 
     static NAV_REGEX: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"(?is)<nav\b[^>]*?>.*?</nav>").unwrap());
-    result = NAV_REGEX.replace_all(&result, "").to_string();
-
-    let tags = vec!["div", "section", "ul", "ol"];
-    let keywords = vec!["nav", "navbar", "menu", "breadcrumbs"];
-
-    for tag in &tags {
-        for keyword in &keywords {
-            let class_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?class="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&class_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
-
-            let id_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?id="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&id_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
+    // One class and one id pattern per tag and keyword, applied in this order
+    static NAV_SECTION_REGEXES: Lazy<Vec<Regex>> = Lazy::new(|| {
+        let tags = ["div", "section", "ul", "ol"];
+        let keywords = ["nav", "navbar", "menu", "breadcrumbs"];
+        let mut regexes = Vec::new();
+        for tag in tags {
+            for keyword in keywords {
+                for attr in ["class", "id"] {
+                    let pattern = format!(
+                        r#"(?is)<{tag}\b[^>]*?{attr}="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
+                    );
+                    regexes.push(Regex::new(&pattern).unwrap());
+                }
+            }
         }
+        regexes
+    });
+
+    result = NAV_REGEX
+        .replace_all(&result, |caps: &Captures| keep_in_page_toc(&caps[0]))
+        .to_string();
+
+    for re in NAV_SECTION_REGEXES.iter() {
+        result = re.replace_all(&result, "").to_string();
     }
 
     result
@@ -153,6 +238,7 @@ fn remove_empty_paragraphs(html: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::REGEXPS;
 
     #[test]
     fn test_remove_unwanted_elements() {
@@ -165,7 +251,7 @@ mod tests {
             </article>
         "#;
 
-        let cleaned = remove_unwanted_elements(html);
+        let cleaned = remove_unwanted_elements(html, &REGEXPS.videos);
 
         assert!(cleaned.contains("<h1>Title</h1>"));
         assert!(cleaned.contains("<p>Content</p>"));
@@ -173,6 +259,44 @@ mod tests {
         assert!(!cleaned.contains("<form"));
     }
 
+    #[test]
+    fn test_remove_unwanted_elements_keeps_allowed_videos() {
+        let html = r#"<p>Intro</p><iframe src="https://www.youtube-nocookie.com/embed/abc"></iframe><iframe src="https://ads.example.com/frame"></iframe>"#;
+        let cleaned = remove_unwanted_elements(html, &REGEXPS.videos);
+        assert!(cleaned.contains("youtube-nocookie.com/embed/abc"));
+        assert!(!cleaned.contains("ads.example.com"));
+
+        let custom = Regex::new(r"(?i)//videos\.example\.com").unwrap();
+        let html = r#"<iframe src="https://videos.example.com/v/1"></iframe><iframe src="https://player.vimeo.com/video/2"></iframe>"#;
+        let cleaned = remove_unwanted_elements(html, &custom);
+        assert!(cleaned.contains("videos.example.com"));
+        assert!(!cleaned.contains("vimeo"));
+    }
+
+    #[test]
+    fn test_remove_unwanted_elements_keeps_quote_attribution() {
+        let html = r#"<blockquote cite="https://example.org/speech"><p>Ask not.</p><footer>— <cite>John F. Kennedy</cite></footer></blockquote><footer>Page footer</footer>"#;
+        let cleaned = remove_unwanted_elements(html, &REGEXPS.videos);
+        assert!(cleaned.contains(r#"<blockquote cite="https://example.org/speech">"#));
+        assert!(cleaned.contains("<footer>— <cite>John F. Kennedy</cite></footer>"));
+        assert!(!cleaned.contains("Page footer"));
+    }
+
+    #[test]
+    fn test_blockquote_depths() {
+        let html = "<footer>a</footer><blockquote><blockquote></blockquote><footer>b</footer></BLOCKQUOTE ><footer>c</footer>";
+        let depths = blockquote_depths(html);
+        let inside = |footer: &str| is_inside_blockquote(&depths, html.find(footer).unwrap());
+
+        assert_eq!(
+            depths.iter().map(|&(_, depth)| depth).collect::<Vec<_>>(),
+            [1, 2, 1, 0]
+        );
+        assert!(!inside("<footer>a"));
+        assert!(inside("<footer>b"));
+        assert!(!inside("<footer>c"));
+    }
+
     #[test]
     fn test_remove_empty_paragraphs() {
         let html = r#"
@@ -213,6 +337,27 @@ mod tests {
         assert!(!cleaned.contains("social-media"));
     }
 
+    #[test]
+    fn test_remove_share_elements_sharedaddy() {
+        let html = r##"<p>Post body.</p><div class="sharedaddy sd-sharing-enabled"><div class="robots-nocontent sd-block sd-social"><h3 class="sd-title">Share this:</h3><div class="sd-content"><ul><li class="share-twitter"><a href="?share=twitter" title="Click to share on Twitter"><span>Twitter</span></a></li><li class="share-facebook"><a href="?share=facebook"><span>Facebook</span></a></li><li class="share-email"><a href="?share=email"><span>Email</span></a></li><li class="share-print"><a href="#print"><span>Print</span></a></li></ul></div></div></div><p>More post.</p>"##;
+
+        let cleaned = remove_share_elements(html);
+
+        assert!(cleaned.contains("<p>Post body.</p>"));
+        assert!(cleaned.contains("<p>More post.</p>"));
+        assert!(!cleaned.contains("Twitter"));
+        assert!(!cleaned.contains("sharedaddy"));
+    }
+
+    #[test]
+    fn test_remove_share_elements_keeps_prose() {
+        let html = r#"<div class="share-story"><p>When I started sharing my story with other parents, I learned how many of them had gone through the same thing.</p></div>"#;
+
+        let cleaned = remove_share_elements(html);
+
+        assert_eq!(cleaned, html);
+    }
+
     #[test]
     fn test_remove_navigation_elements() {
         let html = r##"
@@ -249,7 +394,7 @@ mod tests {
             </article>
         "#;
 
-        let cleaned = prep_article(html);
+        let cleaned = prep_article(html, &ReadabilityOptions::default());
 
         assert!(cleaned.contains("<h1>Article Title</h1>"));
         assert!(cleaned.contains("<p>First paragraph</p>"));
@@ -27,16 +27,20 @@
 //! ```
 
 use crate::{
-    article::Article,
+    article::{Article, Timings},
     cleaner,
+    constants::MAX_PROCESSED_NESTING_DEPTH,
     content_extractor::grab_article,
     dom_utils,
     error::{ReadabilityError, Result},
     metadata::{get_article_metadata, get_json_ld, Metadata},
     options::ReadabilityOptions,
+    readerable::{is_input_readerable, ReaderableOptions},
     utils,
 };
 use scraper::{ElementRef, Html, Selector};
+use std::borrow::Cow;
+use std::time::Instant;
 
 /// The main Readability parser.
 ///
@@ -108,7 +112,8 @@ pub struct Readability {
     /// Original HTML string (stored for preprocessing before content extraction)
     html: String,
 
-    /// Base URL for resolving relative links
+    /// Normalized base URL for resolving relative links (the document's
+    /// `<base href>` if any)
     base_url: Option<String>,
 
     /// Configuration options
@@ -116,6 +121,12 @@ pub struct Readability {
 
     /// Extracted metadata
     metadata: Metadata,
+
+    /// Phase timings, when `collect_timings` is enabled
+    timings: Option<Timings>,
+
+    /// Clock reading at the start of `new`, when `collect_timings` is enabled
+    started: Option<Instant>,
 }
 
 impl Readability {
@@ -123,69 +134,241 @@ impl Readability {
     ///
     /// # Arguments
     /// * `html` - The HTML content to parse
-    /// * `url` - Optional base URL for resolving relative links
+    /// * `url` - Optional URL of the document, used for resolving relative links
+    ///   unless the document has a `<base href>`. A URL that does not parse, or
+    ///   that relative links cannot resolve against, is an
+    ///   [`InvalidUrl`](ReadabilityError::InvalidUrl) error
     /// * `options` - Optional configuration options
     ///
     /// # Returns
     /// Result containing the Readability instance or an error
     pub fn new(html: &str, url: Option<&str>, options: Option<ReadabilityOptions>) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let mut timings = options.collect_timings.then(Timings::default);
+        let started = Timings::start(&timings);
+        if options.max_input_bytes > 0 && html.len() > options.max_input_bytes {
+            return Err(ReadabilityError::InputTooLarge(html.len()));
+        }
+        // Huge inline scripts and styles can't contribute to extraction
+        let html = cleaner::strip_oversized_raw_text(html, options.max_script_style_bytes);
+
         // Parse raw HTML for metadata extraction
         // Preprocessing happens later in parse() before content extraction
-        let document = Html::parse_document(html);
+        let mut document = Timings::record(
+            &mut timings,
+            |t| &mut t.html_parse,
+            || Html::parse_document(&html),
+        );
+        let depth = dom_utils::get_nesting_depth(&document);
+        if options.max_nesting_depth > 0 && depth > options.max_nesting_depth {
+            return Err(ReadabilityError::DocumentTooDeep(depth));
+        }
+        // Pathological nesting is flattened rather than processed level by level
+        let html = if depth > MAX_PROCESSED_NESTING_DEPTH {
+            if options.debug {
+                eprintln!(
+                    "Document is nested {depth} levels deep, flattening past {MAX_PROCESSED_NESTING_DEPTH}"
+                );
+            }
+            dom_utils::flatten_deep_nesting(&mut document, MAX_PROCESSED_NESTING_DEPTH);
+            Cow::Owned(document.html())
+        } else {
+            html
+        };
 
-        // Validate base URL if provided
+        // Validate and normalize the document URL if provided. A URL that
+        // relative links cannot resolve against (`mailto:`, `data:`) is rejected
         let base_url = url
-            .map(|u| {
-                url::Url::parse(u)
-                    .map(|_| u.to_string())
-                    .map_err(|_| ReadabilityError::InvalidUrl(u.to_string()))
+            .map(|u| match url::Url::parse(u) {
+                Ok(url) if !url.cannot_be_a_base() => Ok(url.to_string()),
+                _ => Err(ReadabilityError::InvalidUrl(u.to_string())),
             })
             .transpose()?;
 
-        let options = options.unwrap_or_default();
+        let base_url = resolve_base_url(
+            &document,
+            base_url,
+            options.assume_https_for_protocol_relative,
+            options.debug,
+        );
 
         Ok(Self {
             document,
-            html: html.to_string(),
+            html: html.into_owned(),
             base_url,
             options,
             metadata: Metadata::default(),
+            timings,
+            started,
         })
     }
 
+    /// Create a Readability instance from the body of an HTTP response
+    ///
+    /// The body is decoded with the encoding the WHATWG sniffing order picks: a
+    /// byte order mark, then the `charset` of the `Content-Type` header, then a
+    /// `<meta charset>` or `http-equiv` declaration in the first 1024 bytes, and
+    /// UTF-8 when none is given. Malformed bytes become U+FFFD.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw response body
+    /// * `content_type` - The `Content-Type` header value, if any
+    /// * `final_url` - The URL the response came from after redirects, used as
+    ///   the document URL for resolving relative links
+    /// * `options` - Optional configuration options
+    ///
+    /// # Returns
+    /// Result containing the Readability instance or an error, as for
+    /// [`Readability::new`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use readabilityrs::Readability;
+    ///
+    /// let body = b"<html><head><meta charset=\"windows-1252\"><title>Caf\xe9</title></head></html>";
+    /// let readability = Readability::from_http_response(
+    ///     body,
+    ///     Some("text/html"),
+    ///     "https://example.com/menu",
+    ///     None,
+    /// )?;
+    /// # Ok::<(), readabilityrs::ReadabilityError>(())
+    /// ```
+    #[cfg(feature = "http")]
+    pub fn from_http_response(
+        bytes: &[u8],
+        content_type: Option<&str>,
+        final_url: &str,
+        options: Option<ReadabilityOptions>,
+    ) -> Result<Self> {
+        let html = crate::http::decode_body(bytes, content_type);
+        Self::new(&html, Some(final_url), options)
+    }
+
+    /// Create a Readability instance only if the page passes the readerable check
+    ///
+    /// Same as calling [`is_probably_readerable`](crate::is_probably_readerable)
+    /// with default thresholds before [`Readability::new`], but the HTML is only
+    /// parsed once.
+    ///
+    /// # Returns
+    /// `Ok(None)` for pages that are probably not articles, otherwise the result
+    /// of [`Readability::new`]
+    ///
+    /// ```rust
+    /// use readabilityrs::Readability;
+    ///
+    /// let html = format!("<html><body><p>{}</p></body></html>", "Article text. ".repeat(40));
+    /// assert!(Readability::new_if_readerable(&html, None, None)?.is_some());
+    ///
+    /// let html = "<html><body><p>Sign in</p></body></html>";
+    /// assert!(Readability::new_if_readerable(html, None, None)?.is_none());
+    /// # Ok::<(), readabilityrs::ReadabilityError>(())
+    /// ```
+    pub fn new_if_readerable(
+        html: &str,
+        url: Option<&str>,
+        options: Option<ReadabilityOptions>,
+    ) -> Result<Option<Self>> {
+        let readability = Self::new(html, url, options)?;
+        Ok(readability
+            .is_probably_readerable(None)
+            .then_some(readability))
+    }
+
+    /// Check whether the parsed document is likely to be readerable
+    ///
+    /// Runs [`is_probably_readerable`](crate::is_probably_readerable) on the
+    /// document parsed by [`Readability::new`] instead of parsing the HTML again.
+    pub fn is_probably_readerable(&self, options: Option<ReaderableOptions>) -> bool {
+        is_input_readerable(&self.document, &self.html, &options.unwrap_or_default())
+    }
+
     /// Parse the document and extract article content
     ///
+    /// Consumes the parser, so each document tree and intermediate HTML string
+    /// is freed as soon as the following phase no longer needs it.
+    ///
     /// # Returns
     /// `Option<Article>` - Some(article) if successful, None if no article found
     pub fn parse(mut self) -> Option<Article> {
-        let json_ld = if !self.options.disable_json_ld {
-            get_json_ld(&self.document)
-        } else {
-            Metadata::default()
-        };
+        let mut timings = self.timings.take();
+        self.metadata = Timings::record(
+            &mut timings,
+            |t| &mut t.metadata,
+            || {
+                let json_ld = if !self.options.disable_json_ld {
+                    get_json_ld(&self.document)
+                } else {
+                    Metadata::default()
+                };
+                get_article_metadata(&self.document, json_ld, &self.options)
+            },
+        );
 
-        self.metadata = get_article_metadata(&self.document, json_ld);
+        // The source document is only read for metadata and the direction
+        // fallback, so it is freed before extraction builds its own trees
+        let document_dir = dom_utils::get_article_direction(&self.document);
+        self.document = Html::new_document();
+
+        let html = std::mem::take(&mut self.html);
+        let preprocessed_html = Timings::record(
+            &mut timings,
+            |t| &mut t.prep,
+            || cleaner::prep_document(&html),
+        );
+        drop(html);
+        let preprocessed_doc = Timings::record(
+            &mut timings,
+            |t| &mut t.html_parse,
+            || Html::parse_document(&preprocessed_html),
+        );
+        drop(preprocessed_html);
 
-        let preprocessed_html = cleaner::prep_document(&self.html);
-        let preprocessed_doc = Html::parse_document(&preprocessed_html);
+        let grabbed = grab_article(&preprocessed_doc, &self.options, &mut timings);
+        drop(preprocessed_doc);
 
-        match grab_article(&preprocessed_doc, &self.options) {
+        match grabbed {
             Ok(Some(content_html)) => {
-                let cleaned_wrapper_html =
-                    cleaner::clean_article_content_light(&content_html, self.base_url.as_deref())
-                        .unwrap_or_else(|_| content_html.clone());
-
-                let prepped_html = crate::post_processor::prep_article(&cleaned_wrapper_html);
-                let cleaned_html =
-                    match cleaner::clean_article_content(&prepped_html, self.base_url.as_deref()) {
-                        Ok(html) => html,
-                        Err(e) => {
-                            if self.options.debug {
-                                eprintln!("Error cleaning content: {e}");
-                            }
-                            prepped_html
-                        }
-                    };
+                let cleanup_started = Timings::start(&timings);
+                let cleaned_wrapper_html = cleaner::clean_article_content_light(&content_html)
+                    .unwrap_or_else(|_| content_html.clone());
+
+                let prepped_html =
+                    crate::post_processor::prep_article(&cleaned_wrapper_html, &self.options);
+                drop(cleaned_wrapper_html);
+                let mut cleaned_html = cleaner::clean_article_content(
+                    &prepped_html,
+                    self.base_url.as_deref(),
+                    &self.options,
+                )
+                .unwrap_or_else(|e| {
+                    if self.options.debug {
+                        eprintln!("Error cleaning content: {e}");
+                    }
+                    prepped_html
+                });
+
+                // Each pass replaces the previous HTML rather than shadowing it,
+                // so only one copy of the article is alive at a time
+                if self.options.sanitize {
+                    cleaned_html = cleaner::sanitize_content(&cleaned_html);
+                }
+
+                if self.options.normalize_headings {
+                    cleaned_html =
+                        cleaner::normalize_headings(&cleaned_html, self.metadata.title.as_deref());
+                }
+
+                // A byline taken from the page often still opens the content
+                if let Some(byline) = &self.metadata.byline {
+                    cleaned_html = cleaner::remove_byline_duplicate(
+                        &cleaned_html,
+                        byline,
+                        &self.options.byline_words(),
+                    );
+                }
 
                 let text_content = self.get_text_content(&cleaned_html);
                 let length = text_content.len();
@@ -197,8 +380,22 @@ impl Readability {
                         .or_else(|| self.generate_excerpt_from_text(&text_content))
                 });
 
-                // Extract text direction from document
-                let dir = crate::dom_utils::get_article_direction(&self.document);
+                cleaned_html = cleaner::clean_attributes(&cleaned_html, &self.options);
+
+                // Text direction of the article container, falling back to the document's
+                let dir = dom_utils::get_content_direction(&cleaned_html).or(document_dir);
+
+                let authors = self
+                    .metadata
+                    .byline
+                    .as_deref()
+                    .map(|byline| utils::split_byline_authors(byline, &self.options.byline_words()))
+                    .unwrap_or_default();
+
+                Timings::stop(&mut timings, |t| &mut t.cleanup, cleanup_started);
+                if let (Some(timings), Some(started)) = (timings.as_mut(), self.started) {
+                    timings.total = started.elapsed();
+                }
 
                 Some(Article {
                     title: self.metadata.title,
@@ -209,10 +406,14 @@ impl Readability {
                     excerpt,
                     image: self.metadata.image,
                     byline: self.metadata.byline,
+                    authors,
+                    contributors: self.metadata.contributors,
                     dir,
                     site_name: self.metadata.site_name,
                     lang: self.metadata.lang,
                     published_time: self.metadata.published_time,
+                    resolved_base_url: self.base_url,
+                    timings,
                 })
             }
             Ok(None) => None,
@@ -253,7 +454,7 @@ impl Readability {
                 continue;
             }
 
-            if utils::looks_like_bracket_menu(trimmed) {
+            if utils::looks_like_menu(trimmed) {
                 continue;
             }
 
@@ -266,7 +467,7 @@ impl Readability {
                 continue;
             }
 
-            let looks_like_byline = utils::looks_like_byline(trimmed)
+            let looks_like_byline = utils::looks_like_byline(trimmed, &self.options.byline_words())
                 || class_lower.contains("byline")
                 || class_lower.contains("author")
                 || id_lower.contains("byline")
@@ -358,14 +559,14 @@ impl Readability {
                 continue;
             }
 
-            if utils::looks_like_bracket_menu(para_trimmed) {
+            if utils::looks_like_menu(para_trimmed) {
                 continue;
             }
 
             return Some(self.truncate_text(para_trimmed, 300));
         }
 
-        if utils::looks_like_bracket_menu(cleaned) {
+        if utils::looks_like_menu(cleaned) {
             return None;
         }
 
@@ -408,9 +609,69 @@ impl Readability {
     }
 }
 
+/// Work out the base URL relative links resolve against
+///
+/// As in the HTML spec, the first `<base>` element with an `href` wins and is
+/// itself resolved against the document URL. A base that cannot be resolved
+/// falls back to the document URL. Without a document URL, a protocol-relative
+/// base only resolves when `assume_https` is set.
+fn resolve_base_url(
+    document: &Html,
+    document_url: Option<String>,
+    assume_https: bool,
+    debug: bool,
+) -> Option<String> {
+    let Some(href) = dom_utils::get_base_href(document) else {
+        return document_url;
+    };
+
+    let resolved = match &document_url {
+        Some(document_url) => url::Url::parse(document_url).and_then(|url| url.join(&href)),
+        None if assume_https && utils::classify_url(&href) == utils::UrlKind::ProtocolRelative => {
+            url::Url::parse(&format!("https:{}", href.trim()))
+        }
+        None => url::Url::parse(&href),
+    };
+    match resolved {
+        Ok(base) => Some(base.to_string()),
+        Err(e) => {
+            if debug {
+                eprintln!("Ignoring invalid <base href=\"{href}\">: {e}");
+            }
+            document_url
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::article::{Contributor, ContributorRole};
+    use crate::options::UrlContext;
+    use regex::Regex;
+
+    /// Two paragraphs of filler prose, enough for a page to read as an article
+    const FILLER: &str = "<p>Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam.</p><p>Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident.</p>";
+
+    /// A page whose `<article>` holds `body` followed by the filler prose
+    fn article_page(body: &str) -> String {
+        format!("<html><body><article>{body}{FILLER}</article></body></html>")
+    }
+
+    fn parse_page(html: &str) -> Article {
+        parse_page_with(html, None, None)
+    }
+
+    fn parse_page_with(
+        html: &str,
+        url: Option<&str>,
+        options: Option<ReadabilityOptions>,
+    ) -> Article {
+        Readability::new(html, url, options)
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
 
     #[test]
     fn test_new_readability() {
@@ -426,6 +687,41 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_deeply_nested_document() {
+        fn nested(depth: usize) -> String {
+            format!(
+                "<html><body>{}<p>This paragraph is deeply nested, with enough text, to be picked up as article content.</p>{}</body></html>",
+                "<div>".repeat(depth),
+                "</div>".repeat(depth)
+            )
+        }
+
+        // Run on a small stack so any recursive walk would overflow
+        std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(|| {
+                let limited = ReadabilityOptions::builder().max_nesting_depth(512).build();
+                let result = Readability::new(&nested(1_000), None, Some(limited));
+                assert!(matches!(result, Err(ReadabilityError::DocumentTooDeep(_))));
+
+                for depth in [10_000, 200] {
+                    let unlimited = ReadabilityOptions::builder().max_nesting_depth(0).build();
+                    let article = parse_page_with(&nested(depth), None, Some(unlimited));
+                    assert!(
+                        article
+                            .text_content
+                            .unwrap()
+                            .contains("This paragraph is deeply nested"),
+                        "{depth}"
+                    );
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn test_parse_simple() {
         let html = r#"
@@ -445,6 +741,1089 @@ mod tests {
         // Full functionality will be tested once implementation is complete
     }
 
+    #[test]
+    fn test_readerable_check_on_parsed_document() {
+        let article = format!(
+            "<html><body><article><p>{}</p></article></body></html>",
+            "A paragraph of article text that goes on for a while. ".repeat(10)
+        );
+        let stub = "<html><body><nav><p>Home</p><p>About</p></nav></body></html>";
+
+        for html in [article.as_str(), stub] {
+            let readability = Readability::new(html, None, None).unwrap();
+            assert_eq!(
+                readability.is_probably_readerable(None),
+                crate::is_probably_readerable(html, None)
+            );
+        }
+
+        let strict = ReaderableOptions {
+            min_content_length: 140,
+            min_score: 1000.0,
+            ..Default::default()
+        };
+        let readability = Readability::new(&article, None, None).unwrap();
+        assert!(readability.is_probably_readerable(None));
+        assert!(!readability.is_probably_readerable(Some(strict)));
+
+        assert!(Readability::new_if_readerable(&article, None, None)
+            .unwrap()
+            .is_some());
+        assert!(Readability::new_if_readerable(stub, None, None)
+            .unwrap()
+            .is_none());
+        assert!(Readability::new_if_readerable(stub, Some("not a url"), None).is_err());
+    }
+
+    #[test]
+    fn test_write_content_matches_content() {
+        let article = parse_page(&article_page("<h1>Harbour at dawn</h1>"));
+        let mut streamed = String::new();
+        article.write_content(&mut streamed).unwrap();
+        assert_eq!(Some(streamed), article.content);
+
+        let mut empty = String::new();
+        Article::default().write_content(&mut empty).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_timings() {
+        let html = format!(
+            "<html><head><title>Harbour at dawn</title></head><body><article><h1>Harbour at dawn</h1>{}</article></body></html>",
+            "<p>Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.</p>".repeat(20)
+        );
+
+        let article = parse_page(&html);
+        assert_eq!(article.timings, None);
+
+        let options = ReadabilityOptions::builder().collect_timings(true).build();
+        let article = parse_page_with(&html, None, Some(options));
+        let timings = article.timings.unwrap();
+        for phase in [
+            timings.html_parse,
+            timings.prep,
+            timings.scoring,
+            timings.candidate_selection,
+            timings.cleanup,
+            timings.serialization,
+            timings.metadata,
+        ] {
+            assert!(!phase.is_zero(), "{timings:?}");
+        }
+        assert!(timings.phases_total() <= timings.total, "{timings:?}");
+        assert!(timings.phases_total() * 2 >= timings.total, "{timings:?}");
+    }
+
+    #[test]
+    fn parse_keeps_short_figure_captions() {
+        let html = article_page(
+            r#"<h1>Harbour at dawn</h1><div class="photo-wrapper">
+                <figure><img src="/img/harbour.jpg"><figcaption>Photo: <a href="/staff/jane">Jane Doe</a></figcaption></figure>
+            </div>"#,
+        );
+
+        let article = parse_page(&html);
+        let content = article.content.unwrap();
+        assert!(content
+            .contains("<figcaption>Photo: <a href=\"/staff/jane\">Jane Doe</a></figcaption>"));
+        assert!(content.contains("/img/harbour.jpg"));
+    }
+
+    #[test]
+    fn parse_keeps_wikipedia_formulas() {
+        let count_formulas =
+            |html: &str| html.matches("math/render").count() + html.matches("<math").count();
+        let source = std::fs::read_to_string("tests/test-pages/wikipedia-3/source.html").unwrap();
+        let expected =
+            std::fs::read_to_string("tests/test-pages/wikipedia-3/expected.html").unwrap();
+
+        let article = parse_page_with(&source, Some("http://fakehost/test/page.html"), None);
+        assert!(count_formulas(&article.content.unwrap()) >= count_formulas(&expected));
+    }
+
+    #[test]
+    fn parse_keeps_inline_svg_charts() {
+        let html = article_page(
+            r##"<h1>Quarterly numbers</h1>
+                <div class="chart"><svg viewBox="0 0 100 50"><title>Revenue</title><defs><path id="bar" d="M0 0 H10 V40 H0 Z"/></defs><use xlink:href="#bar" x="10"/><use xlink:href="#bar" x="30"/></svg></div>
+                <div class="chart"><svg viewBox="0 0 100 50"><title>Costs</title><circle cx="50" cy="25" r="20"/></svg></div>"##,
+        );
+
+        let article = parse_page(&html);
+        let content = article.content.unwrap();
+        assert_eq!(content.matches("<svg").count(), 2);
+        assert!(content.contains(r##"xlink:href="#bar""##));
+        assert!(content.contains("<title>Costs</title>"));
+    }
+
+    #[test]
+    fn parse_keeps_video_embed_in_short_post() {
+        let html = r#"
+            <html><body><article class="post">
+                <h1>Launch recap</h1>
+                <p>Here is the full recording of yesterday's launch event, including the keynote, the product demos and the question and answer session with the team. Grab a coffee, it runs for about an hour.</p>
+                <div class="video-wrapper"><div class="responsive-embed"><iframe src="https://player.vimeo.com/video/12345" width="640" height="360" frameborder="0"></iframe></div></div>
+            </article></body></html>
+        "#;
+
+        let article = parse_page_with(html, Some("https://example.com/blog/launch"), None);
+        let content = article.content.unwrap();
+        assert!(content.contains(r#"src="https://player.vimeo.com/video/12345""#));
+        assert!(content.contains(r#"width="640""#));
+        assert!(content.contains("allowfullscreen"));
+        assert!(!content.contains("frameborder"));
+    }
+
+    #[test]
+    fn parse_keeps_podcast_audio_player() {
+        let html = article_page(
+            r#"<h1>Episode 42: Rust in production</h1>
+                <div class="episode-player">
+                    <audio controls autoplay preload="none">
+                        <source src="/media/ep42.mp3" type="audio/mpeg">
+                        <source src="/media/ep42.ogg" type="audio/ogg">
+                    </audio>
+                </div>"#,
+        );
+
+        let article = parse_page_with(&html, Some("https://podcast.example.com/episodes/42"), None);
+        let content = article.content.unwrap();
+        assert!(content.contains("<audio"));
+        assert!(content.contains(r#"src="https://podcast.example.com/media/ep42.mp3""#));
+        assert!(content.contains(r#"src="https://podcast.example.com/media/ep42.ogg""#));
+        assert!(content.contains("controls"));
+        assert!(!content.contains("autoplay"));
+    }
+
+    #[test]
+    fn parse_keeps_footnote_anchors() {
+        let paragraphs: String = (1..=20)
+            .map(|i| format!(r##"<p>Section {i} of the essay makes its argument at some length, with enough words to read as prose.<sup id="fnref{i}"><a href="#fn{i}">{i}</a></sup> It then moves on.</p>"##))
+            .collect();
+        let notes: String = (1..=20)
+            .map(|i| format!(r##"<li id="fn{i}"><a href="https://archive.example.org/box/{i}">Letters, box {i}</a>, folder 2. <a href="#fnref{i}">↩</a></li>"##))
+            .collect();
+        let html = format!(
+            r#"<html><body><article><h1>On archives</h1>{paragraphs}<section class="footnotes"><h2>Notes</h2><ol>{notes}</ol></section></article></body></html>"#
+        );
+
+        let article = parse_page_with(&html, Some("https://example.com/essays/archives"), None);
+        let content = article.content.unwrap();
+        for i in [1, 20] {
+            assert!(content.contains(&format!(r##"<sup id="fnref{i}"><a href="#fn{i}">"##)));
+            assert!(content.contains(&format!(r#"<li id="fn{i}">"#)));
+            assert!(content.contains(&format!(r##"<a href="#fnref{i}">"##)));
+        }
+    }
+
+    #[test]
+    fn parse_keeps_quote_citations() {
+        let html = article_page(
+            r#"<h1>Why we went</h1>
+                <blockquote cite="/sources/rice-speech.html"><p>We choose to go to the Moon in this decade and do the other things, not because they are easy, but because they are hard.</p><footer>— <cite>John F. Kennedy</cite></footer></blockquote>
+                <p>Years later, an engineer on the program said it was <q cite="https://example.org/interview">the hardest thing any of us ever did</q>, and few disagreed with her.</p>
+                <figure><blockquote><p>Not because they are easy.</p></blockquote><figcaption>John F. Kennedy, 1962</figcaption></figure>"#,
+        );
+
+        let article = parse_page_with(&html, Some("https://example.com/history/moon"), None);
+        let content = article.content.unwrap();
+        assert!(
+            content.contains(r#"<blockquote cite="https://example.com/sources/rice-speech.html">"#)
+        );
+        assert!(content.contains("<footer>— <cite>John F. Kennedy</cite></footer>"));
+        assert!(content.contains(
+            r#"<q cite="https://example.org/interview">the hardest thing any of us ever did</q>"#
+        ));
+        assert!(content.contains("<figure><blockquote><p>Not because they are easy.</p></blockquote><figcaption>John F. Kennedy, 1962</figcaption></figure>"));
+    }
+
+    #[test]
+    fn parse_keeps_definition_list_pairs() {
+        let pairs: String = (1..=30)
+            .map(|i| format!("<dt>-{i}</dt><dd>Sets option number {i}, which changes how the tool reads its input.</dd>"))
+            .collect();
+        let html = format!(
+            r#"<html><body><div class="page"><h1>tool(1)</h1><h2>OPTIONS</h2><dl>{pairs}</dl></div>
+            <div class="links"><p>Other manual pages that you might want to read next, in no particular order.</p></div></body></html>"#
+        );
+
+        let article = parse_page(&html);
+        let content = article.content.unwrap();
+        let mut position = 0;
+        for i in 1..=30 {
+            let pair = format!("<dt>-{i}</dt><dd>Sets option number {i},");
+            let found = content[position..]
+                .find(&pair)
+                .map(|offset| position + offset);
+            assert!(found.is_some(), "pair {i} missing or out of order");
+            position = found.unwrap();
+        }
+    }
+
+    #[test]
+    fn parse_sanitizes_content_by_default() {
+        let html = article_page(
+            r#"<h1>Release notes</h1>
+                <p onclick="track()">This release fixes a crash on startup. <a href="javascript:alert(document.cookie)">Details</a></p>"#,
+        );
+
+        let sanitized = parse_page(&html);
+        let content = sanitized.content.unwrap();
+        assert!(!content.contains("onclick"));
+        assert!(!content.contains("javascript:"));
+
+        let options = ReadabilityOptions::builder().sanitize(false).build();
+        let raw = parse_page_with(&html, None, Some(options));
+        assert!(raw.content.unwrap().contains("javascript:"));
+    }
+
+    #[test]
+    fn parse_drops_tracking_pixels() {
+        let html = r#"
+            <html><body><div class="newsletter-issue">
+                <img src="https://www.facebook.com/tr?id=123&ev=PageView" height="1" width="1" style="display:block">
+                <h1>Weekly digest #12</h1>
+                <p>This week we look at the new transit budget, the reopening of the riverside library and the results of the community garden survey that many of you answered.</p>
+                <p><img src="https://mail.example.com/track/open?id=abc" alt=""></p>
+                <p><img src="https://cdn.example.com/library.jpg" width="600" height="300" alt="The library"></p>
+                <p>The library reopens on Monday with longer evening hours, a new children's corner and a small cafe run by volunteers from the neighbourhood association.</p>
+                <img src="data:image/gif;base64,R0lGODlhAQABAIAAAP///wAAACwAAAAAAQABAAACAkQBADs=" width="1" height="1">
+            </div></body></html>
+        "#;
+
+        let article = parse_page_with(html, Some("https://news.example.com/digest/12"), None);
+        let content = article.content.unwrap();
+        assert!(content.contains("https://cdn.example.com/library.jpg"));
+        assert!(!content.contains("facebook.com/tr"));
+        assert!(!content.contains("track/open"));
+        assert!(!content.contains("data:image/gif"));
+    }
+
+    #[test]
+    fn parse_skips_hidden_duplicate_headline() {
+        let html = r#"
+            <html><head><title>Harbour ferry returns after a decade</title></head><body><article>
+                <h1 aria-hidden="true" class="print-only">Harbour ferry returns after a decade</h1>
+                <h1>Harbour ferry returns after a decade</h1>
+                <p>The ferry between the old harbour and the island resumed service on Saturday, ten years after the last boat was withdrawn for budget reasons.</p>
+                <p>Hundreds of residents queued for the first crossing, and the operator says it will add an evening sailing during the summer months.</p>
+                <div hidden><p>Harbour ferry returns after a decade. Printed from the city news website.</p></div>
+            </article></body></html>
+        "#;
+
+        let article = parse_page(html);
+        let text = article.text_content.unwrap();
+        assert_eq!(
+            text.matches("Harbour ferry returns after a decade").count(),
+            1
+        );
+        assert!(!text.contains("Printed from"));
+    }
+
+    #[test]
+    fn parse_keeps_direction_of_mixed_language_quotes() {
+        let html = r#"
+            <html lang="en"><body><div class="story" dir="ltr">
+                <h1>Poets of the old city</h1>
+                <p>The festival closed with readings in both languages, and the audience stayed long after the last poem to talk with the authors about their work.</p>
+                <blockquote dir="rtl" lang="ar" style="font-style: italic"><p>في المدينة القديمة، تحفظ الجدران أسماء من مروا بها.</p></blockquote>
+                <p>Organisers said the bilingual format would return next year, with translations printed alongside every poem so that nobody misses a line.</p>
+                <blockquote lang="en"><p>The walls of the old city keep the names of those who passed through.</p></blockquote>
+                <p>Tickets for the next edition go on sale in the spring, and the programme will be announced on the festival website a few weeks before.</p>
+            </div></body></html>
+        "#;
+
+        let article = parse_page(html);
+        assert_eq!(article.dir.as_deref(), Some("ltr"));
+
+        let content = article.content.unwrap();
+        assert!(content.starts_with("<div dir=\"ltr\""), "{content}");
+        assert!(content.contains(r#"<blockquote dir="rtl" lang="ar">"#));
+        assert!(content.contains(r#"<blockquote lang="en">"#));
+    }
+
+    #[test]
+    fn parse_keeps_in_page_table_of_contents() {
+        let html = r##"
+            <html><head><title>Widget guide</title></head><body><main><article>
+                <h1>Widget guide</h1>
+                <nav class="toc"><ol>
+                    <li><a href="#install">Installation</a></li>
+                    <li><a href="#configure">Configuration</a></li>
+                    <li><a href="#faq">FAQ</a></li>
+                </ol></nav>
+                <h2 id="install">Installation</h2>
+                <p>Download the latest release archive, unpack it into a directory of your choice and add the bin folder to your path so the widget command is available from any shell.</p>
+                <h2><a name="configure"></a>Configuration</h2>
+                <p>The widget reads its settings from a small file in your home directory. Every option has a sensible default, so most people only need to set their account name and region. See <a href="#faq">the FAQ</a>.</p>
+                <h2 id="faq">FAQ</h2>
+                <p>Questions about licensing, supported platforms and upgrading from older releases are answered here, along with a few tips for running the widget behind a proxy server.</p>
+            </article></main></body></html>
+        "##;
+
+        let article = parse_page_with(html, Some("https://docs.example.com/widget/"), None);
+        let content = article.content.unwrap();
+        for target in ["install", "configure", "faq"] {
+            assert!(
+                content.contains(&format!(r##"href="#{target}""##)),
+                "{content}"
+            );
+        }
+        assert!(content.contains(r#"<h2 id="install">"#));
+        assert!(content.contains(r#"<a name="configure">"#));
+        assert!(content.contains(r#"<h2 id="faq">"#));
+        assert!(!content.contains("docs.example.com/widget/#"));
+    }
+
+    #[test]
+    fn parse_resolves_links_against_base_element() {
+        let source = include_str!("../tests/test-pages/base-url/source.html");
+        let html = source.replace(
+            "<head>",
+            r#"<head><base href="https://cdn.example.org/articles/"><base href="/ignored/">"#,
+        );
+
+        let article = parse_page_with(&html, Some("http://fakehost/test/page.html"), None);
+        let content = article.content.unwrap();
+        assert!(content.contains(r#"href="https://cdn.example.org/articles/foo/bar/baz.html""#));
+        assert!(content.contains(r#"href="https://cdn.example.org/foo/bar/baz.html#foo""#));
+        assert!(content.contains(r#"src="https://cdn.example.org/articles/foo/bar/baz.png""#));
+        assert!(content.contains(r#"href="http://test/foo/bar/baz.html""#));
+        assert!(!content.contains("fakehost"));
+    }
+
+    #[test]
+    fn parse_resolves_protocol_relative_urls() {
+        let source = include_str!("../tests/test-pages/protocol-relative-images/source.html");
+        let content = |url: Option<&str>, options: ReadabilityOptions| {
+            Readability::new(source, url, Some(options))
+                .unwrap()
+                .parse()
+                .unwrap()
+                .content
+                .unwrap()
+        };
+
+        for scheme in ["http", "https"] {
+            let url = format!("{scheme}://www.example-travel.com/ferries/northern");
+            let content = content(Some(&url), ReadabilityOptions::default());
+            assert!(content.contains(&format!(
+                r#"src="{scheme}://cdn.example-media.com/ferries/harbour-640.jpg""#
+            )));
+            assert!(content.contains(&format!(
+                "{scheme}://cdn.example-media.com/ferries/harbour-1280.jpg 1280w"
+            )));
+            assert!(content.contains(&format!(
+                r#"srcset="{scheme}://cdn.example-media.com/ferries/island.webp""#
+            )));
+            assert!(content.contains(&format!(
+                r#"href="{scheme}://www.example-travel.com/ferries/tickets""#
+            )));
+            assert!(!content.contains("//cdn.example-media.com/ferries/cdn"));
+            assert!(!content.contains("\"//"));
+        }
+
+        let unresolved = content(None, ReadabilityOptions::default());
+        assert!(unresolved.contains(r#"src="//cdn.example-media.com/ferries/island.jpg""#));
+
+        let options = ReadabilityOptions::builder()
+            .assume_https_for_protocol_relative(true)
+            .build();
+        let assumed = content(None, options);
+        assert!(assumed.contains(r#"src="https://cdn.example-media.com/ferries/island.jpg""#));
+        assert!(assumed.contains("https://cdn.example-media.com/ferries/harbour-1280.jpg 1280w"));
+        assert!(!assumed.contains("\"//"));
+    }
+
+    #[test]
+    fn parse_strips_tracking_params() {
+        let html = r#"<html><head>
+            <meta property="og:image" content="https://cdn.example.com/lead.jpg?utm_source=og&amp;w=1200">
+            </head><body><article>
+            <p>The council approved the new cycle lanes on Tuesday after months of debate, with the
+            first stretch along the river due to open in spring. <a href="https://example.com/plan?utm_source=newsletter&amp;utm_medium=email&amp;section=2&amp;fbclid=IwAR0#map">Read the plan</a>.</p>
+            <p>Residents who objected to the loss of parking spaces will be offered permits in the
+            neighbouring streets, the council said, and a review is planned after a year of use.
+            <a href="/letters?author=J%C3%B6rg&amp;gclid=abc">Letters</a></p>
+            </article></body></html>"#;
+        let parse =
+            |options| parse_page_with(html, Some("https://example.com/news/lanes"), Some(options));
+
+        let article = parse(
+            ReadabilityOptions::builder()
+                .strip_tracking_params(true)
+                .build(),
+        );
+        let content = article.content.unwrap();
+        assert!(content.contains(r#"href="https://example.com/plan?section=2#map""#));
+        assert!(content.contains(r#"href="https://example.com/letters?author=J%C3%B6rg""#));
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://cdn.example.com/lead.jpg?utm_source=og&w=1200")
+        );
+
+        let article = parse(
+            ReadabilityOptions::builder()
+                .strip_tracking_params_from_metadata(true)
+                .build(),
+        );
+        assert!(article.content.unwrap().contains("utm_medium=email"));
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://cdn.example.com/lead.jpg?w=1200")
+        );
+    }
+
+    #[test]
+    fn parse_rewrites_image_urls() {
+        let source = include_str!("../tests/test-pages/protocol-relative-images/source.html");
+        let options = ReadabilityOptions::builder()
+            .url_rewriter(|context, url| {
+                (context == UrlContext::Image).then(|| format!("https://proxy.example/?u={url}"))
+            })
+            .build();
+        let content = parse_page_with(
+            source,
+            Some("https://www.example-travel.com/ferries/northern"),
+            Some(options),
+        )
+        .content
+        .unwrap();
+
+        // Images are rewritten after resolution, srcset candidates included
+        assert!(content.contains(
+            r#"src="https://proxy.example/?u=https://cdn.example-media.com/ferries/harbour-640.jpg""#
+        ));
+        assert!(content.contains(
+            "https://proxy.example/?u=https://cdn.example-media.com/ferries/harbour-1280.jpg 1280w"
+        ));
+        assert!(!content.contains(r#"src="https://cdn.example-media.com"#));
+        // Links are left alone
+        assert!(content.contains(r#"href="https://www.example-travel.com/ferries/tickets""#));
+    }
+
+    #[test]
+    fn base_element_resolves_against_document_url() {
+        let relative = Html::parse_document(r#"<head><base href="../cdn/"></head>"#);
+        assert_eq!(
+            resolve_base_url(
+                &relative,
+                Some("https://example.com/a/b.html".into()),
+                false,
+                false
+            ),
+            Some("https://example.com/cdn/".to_string())
+        );
+
+        let malformed = Html::parse_document(r#"<head><base href="https://exa mple.com/"></head>"#);
+        assert_eq!(
+            resolve_base_url(
+                &malformed,
+                Some("https://example.com/a/b.html".into()),
+                false,
+                false
+            ),
+            Some("https://example.com/a/b.html".to_string())
+        );
+        assert_eq!(resolve_base_url(&relative, None, true, false), None);
+
+        let protocol_relative =
+            Html::parse_document(r#"<head><base href="//cdn.example.com/assets/"></head>"#);
+        assert_eq!(
+            resolve_base_url(
+                &protocol_relative,
+                Some("http://example.com/a/b.html".into()),
+                false,
+                false
+            ),
+            Some("http://cdn.example.com/assets/".to_string())
+        );
+        assert_eq!(
+            resolve_base_url(&protocol_relative, None, false, false),
+            None
+        );
+        assert_eq!(
+            resolve_base_url(&protocol_relative, None, true, false),
+            Some("https://cdn.example.com/assets/".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_reports_resolved_base_url() {
+        let body = format!(
+            "<body><article>{}</article></body>",
+            "<p>The ferry timetable changes on Monday, with two extra sailings a day to the islands and a later last boat.</p>"
+                .repeat(6)
+        );
+        let resolved = |head: &str, url: Option<&str>| {
+            Readability::new(
+                &format!("<html><head>{head}</head>{body}</html>"),
+                url,
+                None,
+            )
+            .unwrap()
+            .parse()
+            .unwrap()
+            .resolved_base_url
+        };
+
+        // The document URL, normalized
+        assert_eq!(
+            resolved(
+                "",
+                Some("HTTPS://Example.COM:443/news/./2024/../ferries?id=1")
+            )
+            .as_deref(),
+            Some("https://example.com/news/ferries?id=1")
+        );
+        // A <base> resolved against it takes precedence
+        assert_eq!(
+            resolved(
+                r#"<base href="../static/">"#,
+                Some("http://example.com:80/news/a.html")
+            )
+            .as_deref(),
+            Some("http://example.com/static/")
+        );
+        // An absolute <base> is used even without a document URL
+        assert_eq!(
+            resolved(
+                r#"<base href="https://cdn.example.com:8443/a/../b/">"#,
+                None
+            )
+            .as_deref(),
+            Some("https://cdn.example.com:8443/b/")
+        );
+        // A <base> that does not resolve falls back to the document URL
+        assert_eq!(
+            resolved(
+                r#"<base href="http://exa mple.com/">"#,
+                Some("https://example.com/a")
+            )
+            .as_deref(),
+            Some("https://example.com/a")
+        );
+        assert_eq!(resolved(r#"<base href="static/">"#, None), None);
+        assert_eq!(resolved("", None), None);
+
+        for url in [
+            "example.com/news",
+            "mailto:desk@example.com",
+            "data:text/html,hi",
+        ] {
+            assert!(matches!(
+                Readability::new(&body, Some(url), None),
+                Err(ReadabilityError::InvalidUrl(invalid)) if invalid == url
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_strips_data_attributes() {
+        let paragraph = r#"<p data-module="ArticleBody" data-analytics-region="story-body" data-analytics-position="paragraph" data-component-tracking-id="a1b2c3d4e5f6">The committee met for six hours on Tuesday and agreed a new timetable for the works on the northern line.</p>"#;
+        let html = format!(
+            "<html><body><article><h1>Line works</h1>{}</article></body></html>",
+            paragraph.repeat(8)
+        );
+
+        let stripped = parse_page(&html).content.unwrap();
+        let options = ReadabilityOptions::builder()
+            .strip_data_attributes(false)
+            .build();
+        let kept = parse_page_with(&html, None, Some(options)).content.unwrap();
+
+        assert!(!stripped.contains("data-"));
+        assert!(kept.contains(r#"data-module="ArticleBody""#));
+        assert!(
+            stripped.len() * 2 < kept.len(),
+            "{} vs {}",
+            stripped.len(),
+            kept.len()
+        );
+    }
+
+    #[test]
+    fn parse_keeps_semantic_inline_elements() {
+        let html = r##"
+            <html><body><article>
+                <h1>Combustion basics</h1>
+                <p><font face="Georgia">Burning methane, CH<sub>4</sub>, in oxygen gives CO<sub>2</sub> and H<sub>2</sub>O</font>, releasing about 8.9&nbsp;&times;&nbsp;10<sup>5</sup> J per mole.<sup><a href="#fn1">1</a></sup> The <abbr title="International Union of Pure and Applied Chemistry">IUPAC</abbr> tables were last revised on <time datetime="2023-11-30">30 November 2023</time>.</p>
+                <p><span class="lead"><span>Incomplete combustion</span></span> produces CO instead, which is why heaters need ventilation and why <mark>detectors</mark> are required by law in many countries.</p>
+                <ol><li id="fn1">Standard enthalpy of combustion at 298 K and one atmosphere.</li></ol>
+            </article></body></html>
+        "##;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        for fragment in [
+            "CH<sub>4</sub>",
+            "CO<sub>2</sub>",
+            "10<sup>5</sup>",
+            r##"<sup><a href="#fn1">1</a></sup>"##,
+            r#"<abbr title="International Union of Pure and Applied Chemistry">IUPAC</abbr>"#,
+            r#"<time datetime="2023-11-30">"#,
+            "<mark>detectors</mark>",
+        ] {
+            assert!(
+                content.contains(fragment),
+                "missing {fragment} in {content}"
+            );
+        }
+        assert!(!content.contains("<span"));
+        assert!(!content.contains("<font"));
+    }
+
+    #[test]
+    fn parse_strips_image_credits_when_enabled() {
+        let html = article_page(
+            r#"<h1>Storm clean-up begins</h1>
+                <figure><img src="https://example.com/storm.jpg"><figcaption>A fallen oak blocks Station Road. Photo: Kim Park/AFP</figcaption></figure>"#,
+        );
+
+        let article = parse_page(&html);
+        assert!(article.content.unwrap().contains("Photo: Kim Park/AFP"));
+
+        let options = ReadabilityOptions::builder()
+            .strip_image_credits(true)
+            .build();
+        let article = parse_page_with(&html, None, Some(options));
+        let content = article.content.unwrap();
+        assert!(content.contains("<figcaption>A fallen oak blocks Station Road.</figcaption>"));
+        assert!(!content.contains("Kim Park"));
+    }
+
+    #[test]
+    fn parse_applies_extra_unlikely_patterns() {
+        let html = std::fs::read_to_string("tests/test-pages/german-werbung/source.html").unwrap();
+        let parse = |options: ReadabilityOptions| {
+            parse_page_with(&html, None, Some(options)).content.unwrap()
+        };
+
+        // The built-in patterns alone leave German ad containers in place
+        let content = parse(ReadabilityOptions::default());
+        assert!(content.contains("E-Bike-Abo"));
+        assert!(content.contains("Ihre Werbung hier"));
+
+        let werbung = Regex::new(r"(?i)werbung").unwrap();
+        let content = parse(
+            ReadabilityOptions::builder()
+                .extra_unlikely_candidates(werbung.clone())
+                .build(),
+        );
+        assert!(!content.contains("E-Bike-Abo"));
+        assert!(!content.contains("Ihre Werbung hier"));
+        assert!(content.contains("Zählstellen"));
+
+        let content = parse(
+            ReadabilityOptions::builder()
+                .extra_unlikely_candidates(werbung)
+                .extra_ok_maybe_candidates(Regex::new(r"(?i)container").unwrap())
+                .build(),
+        );
+        assert!(content.contains("E-Bike-Abo"));
+        assert!(!content.contains("Ihre Werbung hier"));
+    }
+
+    #[test]
+    fn parse_keeps_collapsed_details_sections() {
+        let html = r#"
+            <html><head><title>Release v2.4.0</title></head><body>
+                <header><nav><a href="/">Home</a> <a href="/acme/notes/issues">Issues</a></nav></header>
+                <main><div class="release-entry"><h1>v2.4.0</h1><div class="markdown-body">
+                    <p>Offline sync and a command palette.</p>
+                    <details><summary>New features</summary><ul>
+                        <li>Offline sync for notes and attachments, with conflict resolution when two devices edit the same note while disconnected.</li>
+                        <li>A command palette that lists every action in the app together with its keyboard shortcut.</li>
+                    </ul></details>
+                    <details><summary>Bug fixes</summary><ul>
+                        <li>Fixed a crash when importing very large archives on machines with little free memory.</li>
+                        <li>The search index is no longer rebuilt on every start, which made launches slow on older laptops.</li>
+                    </ul></details>
+                    <div><details><summary>Contributors</summary>Over ninety commits from twelve contributors went into this release; thank you to everyone who filed issues and tested the betas.</details></div>
+                </div></div></main>
+            </body></html>
+        "#;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        assert_eq!(content.matches("<details>").count(), 3, "{content}");
+        for summary in ["New features", "Bug fixes", "Contributors"] {
+            assert!(content.contains(&format!("<summary>{summary}</summary>")));
+        }
+        assert!(content.contains("conflict resolution"));
+        assert!(content.contains("launches slow"));
+        assert!(content.contains("twelve contributors"));
+
+        let options = ReadabilityOptions::builder().open_details(true).build();
+        let article = parse_page_with(html, None, Some(options));
+        assert_eq!(
+            article
+                .content
+                .unwrap()
+                .matches(r#"<details open="">"#)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn parse_collects_photo_credits_as_contributors() {
+        let html = article_page(
+            r#"<h1>Winter on the Range</h1>
+                <p class="byline">Photography by Ansel Adams</p>
+                <figure><img src="https://example.com/meadow.jpg"><figcaption>The upper meadow at dawn. Photo by Jane Doe</figcaption></figure>
+                <p>Video by AP</p>"#,
+        );
+
+        let article = parse_page(&html);
+        assert_eq!(article.byline, None);
+        assert!(article.authors.is_empty());
+        assert_eq!(
+            article.contributors,
+            vec![
+                Contributor {
+                    role: ContributorRole::Photography,
+                    name: "Ansel Adams".to_string(),
+                },
+                Contributor {
+                    role: ContributorRole::Video,
+                    name: "AP".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_attributes_health_article_to_writer_not_reviewer() {
+        let html = article_page(
+            r#"<h1>Managing Seasonal Allergies</h1>
+                <div class="reviewedBy">Medically reviewed by Dr. Sam Lee</div>
+                <div class="byline">By Jane Doe</div>
+                <div class="editedBy">Edited by Chris Park</div>"#,
+        );
+
+        let article = parse_page(&html);
+        assert_eq!(article.byline.as_deref(), Some("By Jane Doe"));
+        assert_eq!(
+            article.contributors,
+            vec![
+                Contributor {
+                    role: ContributorRole::Review,
+                    name: "Dr. Sam Lee".to_string(),
+                },
+                Contributor {
+                    role: ContributorRole::Editing,
+                    name: "Chris Park".to_string(),
+                },
+            ]
+        );
+
+        let source = include_str!("../tests/test-pages/webmd-1/source.html");
+        let article = parse_page(source);
+        assert!(article.byline.unwrap().starts_with("By Brenda"));
+        assert_eq!(
+            article.contributors,
+            vec![Contributor {
+                role: ContributorRole::Review,
+                name: "Hansa D. Bhargava, MD".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_splits_byline_into_authors() {
+        let html = article_page(
+            r#"<h1>Neue Straßenbahn</h1><p class="byline">Von Anna Schmidt und Peter Weber</p>"#,
+        );
+
+        let article = parse_page(&html);
+        assert_eq!(
+            article.byline.as_deref(),
+            Some("Von Anna Schmidt und Peter Weber")
+        );
+        assert_eq!(article.authors, vec!["Anna Schmidt", "Peter Weber"]);
+    }
+
+    #[test]
+    fn parse_detects_arabic_byline() {
+        let html = r#"
+            <html lang="ar" dir="rtl"><head><meta charset="utf-8"><title>افتتاح مكتبة عامة جديدة في وسط المدينة</title></head><body>
+                <main><article>
+                    <h1>افتتاح مكتبة عامة جديدة في وسط المدينة</h1>
+                    <p>بقلم أحمد علي</p>
+                    <p>افتتحت البلدية صباح اليوم مكتبة عامة جديدة في وسط المدينة، بعد أعمال بناء استمرت أكثر من ثلاث سنوات، وتضم المكتبة قاعات للقراءة ومساحة مخصصة للأطفال.</p>
+                    <p>وقال رئيس البلدية في كلمته خلال حفل الافتتاح إن المشروع يهدف إلى تشجيع القراءة بين الشباب، وإن المكتبة ستفتح أبوابها يومياً حتى ساعة متأخرة من المساء.</p>
+                    <p>وتحتوي المكتبة على أكثر من مئة ألف كتاب باللغتين العربية والإنجليزية، إضافة إلى أرشيف رقمي للصحف المحلية يعود إلى بداية القرن الماضي.</p>
+                </article></main>
+            </body></html>
+        "#;
+
+        let article = parse_page(html);
+        assert_eq!(article.byline.as_deref(), Some("بقلم أحمد علي"));
+        assert_eq!(article.authors, vec!["أحمد علي"]);
+        assert_eq!(article.dir.as_deref(), Some("rtl"));
+    }
+
+    #[test]
+    fn parse_detects_japanese_byline() {
+        let html = r#"
+            <html lang="ja"><head><meta charset="utf-8"><title>新しい路面電車の建設が決定 | 港湾日報</title></head><body>
+                <header><nav><a href="/">トップ</a> <a href="/national">国内</a> <a href="/world">国際</a></nav></header>
+                <main><article>
+                    <h1>新しい路面電車の建設が決定</h1>
+                    <p>記者 田中太郎</p>
+                    <p><time datetime="2024-03-12">2024年3月12日</time></p>
+                    <p>市議会は十二日、港湾地区と大学を結ぶ新しい路面電車の建設計画を賛成多数で可決した。十年以上にわたって議論が続いてきた計画がようやく動き出すことになる。</p>
+                    <p>工事は来年春に始まる予定で、全長およそ九キロの路線に十四の停留所が設けられる。市は一日平均で四万人の利用を見込んでおり、周辺の渋滞緩和にもつながると説明している。</p>
+                    <p>一方で、建設費の増加を懸念する声も根強く、市民団体はバス路線の拡充を優先すべきだと主張している。市は今後、住民説明会を各地区で開く方針だ。</p>
+                </article></main>
+                <footer>© 港湾日報</footer>
+            </body></html>
+        "#;
+
+        let article = parse_page(html);
+        assert_eq!(article.byline.as_deref(), Some("記者 田中太郎"));
+        assert_eq!(article.authors, vec!["田中太郎"]);
+        assert!(article.excerpt.unwrap().starts_with("市議会は十二日"));
+    }
+
+    #[test]
+    fn parse_keeps_line_breaks_in_poetry() {
+        let html = r#"
+            <html><head><title>Evening Verses</title></head><body>
+                <div id="main"><article class="post"><h1>Evening Verses</h1><div class="entry-content">
+                    <p>This week I have been writing in the evenings again, after a long pause. What follows is a small poem about the walk home along the river and the allotments behind the old mill.</p>
+                    <div class="poem">The river keeps its counsel in the dusk,<br>the herons stand like question marks of grey,<br><br>the allotments smell of rain and turned-up earth,<br>a wheelbarrow is resting on its side.</div>
+                    <p>Chorus, as we sang it at the gate:<br>row, row, <em>row<br>the boat</em><br>down to the weir</p>
+                    <blockquote><em>first verse<br>sung at the mill<br><br>second verse<br>sung at the gate</em></blockquote>
+                    <pre>line one<br>line two
+
+line four</pre>
+                    <p>Thanks for reading. I will post another one next week, probably about the winter market and the man who sells chestnuts outside the station every year.</p>
+                </div></article></div>
+            </body></html>
+        "#;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        assert!(content.contains(
+            "<p>The river keeps its counsel in the dusk,<br>the herons stand like question marks of grey,</p>\n<p>the allotments smell of rain and turned-up earth,<br>a wheelbarrow is resting on its side.</p>"
+        ), "{content}");
+        assert!(content.contains(
+            "<p>Chorus, as we sang it at the gate:<br>row, row, <em>row<br>the boat</em><br>down to the weir</p>"
+        ));
+        assert!(content.contains(
+            "<blockquote><em>first verse<br>sung at the mill<br><br>second verse<br>sung at the gate</em></blockquote>"
+        ));
+        assert!(content.contains("<pre>line one\nline two\n\nline four</pre>"));
+    }
+
+    #[test]
+    fn parse_strips_table_of_contents_when_enabled() {
+        let html = r##"
+            <html><head><title>Widget guide</title></head><body><main><article>
+            <h1>Widget guide</h1>
+            <p>This guide walks through everything needed to get the widget running, from downloading a release to tuning it for large installations.</p>
+            <div class="toc"><h2>Table of contents</h2><ol>
+            <li><a href="#install">Installation</a></li>
+            <li><a href="#configure">Configuration</a><ol><li><a href="#regions">Regions</a></li></ol></li>
+            <li><a href="#faq">FAQ</a></li>
+            </ol></div>
+            <h2 id="install">Installation</h2>
+            <p>Download the latest release archive, unpack it into a directory of your choice and add the bin folder to your path so the widget command is available from any shell.</p>
+            <h2 id="configure">Configuration</h2>
+            <p>The widget reads its settings from a small file in your home directory. Every option has a sensible default, so most people only need to set their account name and region.</p>
+            <h3 id="regions">Regions</h3>
+            <p>Pick the region closest to your users; the widget falls back to the nearest healthy region automatically when one is unavailable.</p>
+            <h2 id="faq">FAQ</h2>
+            <p>Questions about licensing, supported platforms and upgrading from older releases are answered here, along with a few tips for running the widget behind a proxy server.</p>
+            <p>Steps you might need:</p><ul><li><a href="#install">Reinstall</a></li><li><a href="#configure">Reconfigure</a></li><li><a href="#faq">Ask</a></li></ul>
+            </article></main></body></html>
+        "##;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        assert!(content.contains("Table of contents"), "{content}");
+        for (target, title) in [
+            ("install", "Installation"),
+            ("regions", "Regions"),
+            ("faq", "FAQ"),
+        ] {
+            assert!(content.contains(&format!(r##"<a href="#{target}">{title}</a>"##)));
+            assert!(content.contains(&format!(r#" id="{target}">{title}</h"#)));
+        }
+
+        let options = ReadabilityOptions::builder().strip_toc(true).build();
+        let article = parse_page_with(html, None, Some(options));
+        let content = article.content.unwrap();
+        assert!(!content.contains("Table of contents"), "{content}");
+        assert!(!content.contains(r##"href="#regions""##));
+        assert!(content.contains(r#"<h3 id="regions">Regions</h3>"#));
+        assert!(content.contains(r##"<a href="#install">Reinstall</a>"##));
+    }
+
+    #[test]
+    fn parse_removes_pull_quotes_repeating_body_text() {
+        let html = r#"
+            <html><head><title>The last lighthouse keeper</title></head><body><article class="feature">
+                <h1>The last lighthouse keeper</h1>
+                <p>For thirty-one years Margaret Doyle climbed the hundred and twelve steps of the Eastpoint light every evening at dusk, long after the rest of the coast had switched to automatic lamps and remote monitoring.</p>
+                <blockquote class="pullquote" style="font-size: 2em">“The sea never forgives a keeper who stops paying attention, not even once.”</blockquote>
+                <p>She remembers the winter of the great storm best. "The sea never forgives a keeper who stops paying attention, not even once," she says, pouring tea in the small kitchen at the foot of the tower.</p>
+                <div class="story-pull-out"><p>The lamp had to be wound by hand every four hours through the night.</p></div>
+                <p>Until the mechanism was replaced in 1994, the lamp had to be wound by hand every four hours through the night, whatever the weather was doing outside.</p>
+                <blockquote><p>A lighthouse is not a building, it is a promise made to strangers you will never meet.</p></blockquote>
+                <p>That line, from her father's notebook, is how she explains staying on. A promise made to strangers, she says, is still a promise, and she intends to keep it for as long as the board allows her to live here.</p>
+            </article></body></html>
+        "#;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        assert_eq!(
+            content.matches("stops paying attention").count(),
+            1,
+            "{content}"
+        );
+        assert_eq!(content.matches("wound by hand every four hours").count(), 1);
+        assert!(content.contains("<p>She remembers the winter"));
+        assert!(content.contains("a promise made to strangers you will never meet."));
+    }
+
+    #[test]
+    fn parse_removes_infinite_scroll_placeholders() {
+        let html = r#"
+            <html><head><title>Council approves new tram line</title></head><body>
+                <div id="feed"><article class="story">
+                    <h1>Council approves new tram line</h1>
+                    <p>The city council voted on Tuesday to approve a new tram line linking the harbour district with the university campus, ending a debate that has run for more than a decade.</p>
+                    <div class="article-placeholder"></div>
+                    <div class="skeleton-card"><div class="skeleton-line shimmer"></div><div class="skeleton-line shimmer"></div><svg class="spinner" viewBox="0 0 10 10"><circle r="4"></circle></svg></div>
+                    <p>Construction is expected to begin next spring. The line will have fourteen stops and is projected to carry around forty thousand passengers on an average weekday.</p>
+                    <p class="loading-indicator">Loading more stories…</p>
+                    <figure class="lazy-placeholder"><img src="https://news.example.com/tram.jpg" alt="Tram"></figure>
+                    <p>Opponents argued the money would be better spent on buses, but supporters pointed to the success of similar lines in neighbouring cities over the last few years.</p>
+                    <div class="load-more"><a href="/news?page=2">Load more</a></div>
+                </article></div>
+            </body></html>
+        "#;
+
+        let article = parse_page(html);
+        let content = article.content.unwrap();
+        assert!(!content.to_lowercase().contains("loading"), "{content}");
+        assert!(!content.contains("Load more"));
+        assert!(!content.contains("skeleton"));
+        assert!(!content.contains("<svg"));
+        assert!(content.contains(r#"src="https://news.example.com/tram.jpg""#));
+        assert!(content.contains("fourteen stops"));
+        assert!(content.contains("similar lines"));
+    }
+
+    #[test]
+    fn parse_removes_byline_repeated_in_content() {
+        let source = include_str!("../tests/test-pages/seattletimes-1/source.html");
+        let article = parse_page(source);
+        assert_eq!(article.byline.as_deref(), Some("Benjamin Romano"));
+        assert!(!article.content.unwrap().contains("By Benjamin Romano"));
+
+        let html = r#"
+            <html><head><title>Harbour plan approved</title><meta name="author" content="Jane Doe"></head><body><article>
+                <h1>Harbour plan approved</h1>
+                <p>By Jane Doe</p>
+                <p>Jane Doe has covered the harbour for the paper since 2009, and the council's vote on Tuesday ends a planning fight that lasted most of that time.</p>
+                <p>The plan adds a ferry terminal, widens the sea wall and turns the old customs house into a library, with work due to start next spring.</p>
+            </article></body></html>
+        "#;
+        let content = parse_page(html).content.unwrap();
+        assert!(!content.contains("<p>By Jane Doe</p>"));
+        assert!(content.contains("Jane Doe has covered the harbour"));
+    }
+
+    #[test]
+    fn parse_removes_standalone_ad_labels() {
+        let source = include_str!("../tests/test-pages/cnet/source.html");
+        let article = parse_page(source);
+        assert!(!article.content.unwrap().contains("<p>ADVERTISING</p>"));
+
+        let html = r#"
+            <html><head><title>Storm closes coastal roads</title></head><body><article class="story">
+                <h1>Storm closes coastal roads</h1>
+                <p>Heavy rain and gale-force winds closed several coastal roads on Sunday, and the weather service warned that conditions would get worse overnight before easing on Monday.</p>
+                <div class="ad-slot"><p>ADVERTISEMENT</p></div>
+                <p>Emergency crews cleared fallen trees from the main road into town, while residents near the river were asked to move cars to higher ground as a precaution.</p>
+                <p>&nbsp;Sponsored&nbsp;</p>
+                <p>The advertisement for the harbour festival, printed before the forecast changed, still promises sunshine and a fireworks display on Saturday night.</p>
+                <p>— Anzeige —</p>
+                <p>Schools in the district will open as normal, officials said, although buses on the coastal route may run late.</p>
+                <p>Promoted</p>
+            </article></body></html>
+        "#;
+
+        let options = ReadabilityOptions::builder()
+            .ad_labels(vec!["Promoted".to_string()])
+            .build();
+        let article = parse_page_with(html, None, Some(options));
+        let content = article.content.unwrap();
+        for label in ["ADVERTISEMENT", "Sponsored", "Anzeige", "Promoted"] {
+            assert!(!content.contains(label), "{content}");
+        }
+        assert!(content.contains("The advertisement for the harbour festival"));
+    }
+
+    #[test]
+    fn excerpt_skips_localized_bylines() {
+        let html = r#"
+        <p>Von Anna Schmidt und Jonas Weber, Berlin</p>
+        <p>Af Mette Jensen, korrespondent i Aarhus</p>
+        <p>Die Stadt hat am Dienstag den Bau einer neuen Straßenbahnlinie beschlossen.</p>
+        "#;
+        let reader = Readability::new(html, None, None).unwrap();
+        assert_eq!(
+            reader.generate_excerpt_from_html(html),
+            Some("Af Mette Jensen, korrespondent i Aarhus".to_string())
+        );
+
+        let options = ReadabilityOptions::builder()
+            .byline_prefixes(vec!["af".to_string()])
+            .build();
+        let reader = Readability::new(html, None, Some(options)).unwrap();
+        assert_eq!(
+            reader.generate_excerpt_from_html(html),
+            Some(
+                "Die Stadt hat am Dienstag den Bau einer neuen Straßenbahnlinie beschlossen."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_accepts_stylized_author_names() {
+        let body = r#"
+                <main><article>
+                    <h1>Teaching to Transgress, Thirty Years On</h1>
+                    <p>BYLINE_TEXT</p>
+                    <p>Three decades after its publication, the essay collection still turns up on syllabi in education departments, and teachers still argue about what it asks of them in the classroom.</p>
+                    <p>Its central claim is simple enough to state and hard to live by: that learning is a practice of freedom, and that a classroom can be a place where students and teachers both take risks.</p>
+                    <p>Former students describe seminars that began with a question and ended somewhere nobody had planned, which is exactly what the book argued teaching ought to feel like.</p>
+                </article></main>
+        "#;
+        let page = |byline: &str| {
+            format!(
+                "<html><head><title>Teaching to Transgress</title></head><body>{}</body></html>",
+                body.replace("BYLINE_TEXT", byline)
+            )
+        };
+
+        let article = parse_page(&page("by bell hooks"));
+        assert_eq!(article.byline.as_deref(), Some("by bell hooks"));
+
+        let options = ReadabilityOptions::builder()
+            .title_case_bylines(true)
+            .build();
+        let article = parse_page_with(&page("BY JOHN SMITH"), None, Some(options));
+        assert_eq!(article.byline.as_deref(), Some("By John Smith"));
+    }
+
     #[test]
     fn excerpt_skips_hatnote_paragraphs() {
         let html = r#"
@@ -461,4 +1840,37 @@ mod tests {
             )
         );
     }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn from_http_response_decodes_header_charset() {
+        let paragraph = "新しい路面電車の路線は来年の春に開業する予定で、市は駅周辺の再開発も同時に進めると発表しました。";
+        let page = format!(
+            r#"<html><head><title>路面電車の新路線</title></head><body><article>
+            <p>{paragraph}</p><p>{paragraph}</p><p>{paragraph}</p>
+            <p><a href="../map.html">路線図</a></p>
+            </article></body></html>"#
+        );
+        // The charset is only declared in the header
+        let (body, _, _) = encoding_rs::SHIFT_JIS.encode(&page);
+        assert!(std::str::from_utf8(&body).is_err());
+
+        let article = Readability::from_http_response(
+            &body,
+            Some("text/html; charset=Shift_JIS"),
+            "https://news.example.jp/2024/tram/index.html",
+            None,
+        )
+        .unwrap()
+        .parse()
+        .unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("路面電車の新路線"));
+        assert!(article.text_content.unwrap().contains(paragraph));
+        // Relative links resolve against the final URL
+        assert!(article
+            .content
+            .unwrap()
+            .contains(r#"href="https://news.example.jp/2024/map.html""#));
+    }
 }
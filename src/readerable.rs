@@ -26,12 +26,75 @@
 //! }
 //! ```
 //!
+//! When the page will be parsed anyway, [`Readability::new_if_readerable`] and
+//! [`Readability::is_probably_readerable`] run the same check on the document
+//! the parser already holds, so the HTML is parsed once.
+//!
+//! [`Readability::new_if_readerable`]: crate::Readability::new_if_readerable
+//! [`Readability::is_probably_readerable`]: crate::Readability::is_probably_readerable
+//!
+//! ## Fragments and Partial Documents
+//!
+//! The check only looks at body content and never needs `<head>` metadata.
+//! Input without an `<html>`, `<head>` or `<body>` tag is parsed as a body
+//! fragment. For input that may be cut short, such as the first bytes of a
+//! streamed page, set [`ReaderableOptions::assume_truncated`] to lower the
+//! thresholds when the HTML looks cut off.
+//!
 //! ## Performance
 //!
 //! This check is significantly faster than a full parse because it only looks
 //! for basic content signals without doing deep analysis or scoring.
 
-use scraper::{Html, Selector};
+use crate::constants::{
+    is_unlikely_class_id, INTERSTITIAL_FORM_SHARE, INTERSTITIAL_PHRASES,
+    INTERSTITIAL_SIGNAL_PENALTY, READERABLE_BOILERPLATE_PENALTY, READERABLE_LANDMARK_BONUS,
+    TRUNCATED_THRESHOLD_SCALE,
+};
+use crate::{dom_utils, utils};
+use memchr::memmem;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexSet, RegexSetBuilder};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Callback deciding whether a candidate node counts as visible.
+///
+/// See [`ReaderableOptions::visibility_checker`].
+pub type VisibilityChecker = Arc<dyn Fn(&NodeInfo<'_>) -> bool + Send + Sync>;
+
+/// What a [`VisibilityChecker`] gets to see of a candidate node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeInfo<'a> {
+    /// Lowercase tag name, e.g. `"p"`
+    pub tag: &'a str,
+    /// The `class` attribute, if present
+    pub class: Option<&'a str>,
+    /// The `id` attribute, if present
+    pub id: Option<&'a str>,
+    /// The `style` attribute, if present
+    pub style: Option<&'a str>,
+    /// Whether the node carries the `hidden` attribute
+    pub hidden: bool,
+    /// Whether the node has `aria-hidden="true"`
+    pub aria_hidden: bool,
+}
+
+impl<'a> NodeInfo<'a> {
+    fn from_element(element: ElementRef<'a>) -> Self {
+        let node = element.value();
+        Self {
+            tag: node.name(),
+            class: node.attr("class"),
+            id: node.attr("id"),
+            style: node.attr("style"),
+            hidden: node.attr("hidden").is_some(),
+            aria_hidden: node.attr("aria-hidden") == Some("true"),
+        }
+    }
+}
 
 /// Options for the readability pre-flight check.
 ///
@@ -48,11 +111,12 @@ use scraper::{Html, Selector};
 /// let options = ReaderableOptions {
 ///     min_content_length: 200,
 ///     min_score: 30.0,
+///     ..Default::default()
 /// };
 ///
 /// let is_readerable = is_probably_readerable(html, Some(options));
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ReaderableOptions {
     /// Minimum content length to consider a paragraph.
     ///
@@ -69,6 +133,81 @@ pub struct ReaderableOptions {
     ///
     /// Default: `20.0`
     pub min_score: f64,
+
+    /// Factor applied to `min_content_length` for paragraphs whose text is
+    /// mostly Chinese, Japanese or Korean, which say more per character.
+    ///
+    /// Default: `0.4`
+    pub cjk_length_factor: f64,
+
+    /// Treat the input as possibly cut short, e.g. the first bytes of a page
+    /// fetched with a range request.
+    ///
+    /// When set and the HTML ends inside a tag, or opens `<html>` or `<body>`
+    /// without closing it, `min_content_length` and `min_score` are both
+    /// halved to make up for the text that is missing. Complete inputs are
+    /// checked with the usual thresholds.
+    ///
+    /// Default: `false`
+    pub assume_truncated: bool,
+
+    /// Class/id pattern replacing the built-in list of unlikely candidates
+    /// ("sidebar", "comment", "menu", ...).
+    ///
+    /// Like Readability.js, nodes whose `class` or `id` look unlikely are left
+    /// out of the score, unless they also match the built-in "maybe a
+    /// candidate" pattern or `extra_ok_maybe_candidates`.
+    ///
+    /// Default: `None`
+    pub unlikely_candidates_override: Option<Regex>,
+
+    /// Extra class/id pattern for unlikely candidates, matched in addition to
+    /// the built-in list or `unlikely_candidates_override`. Works like
+    /// [`ReadabilityOptions::extra_unlikely_candidates`].
+    ///
+    /// ```rust
+    /// use readabilityrs::ReaderableOptions;
+    /// use regex::Regex;
+    ///
+    /// let options = ReaderableOptions {
+    ///     extra_unlikely_candidates: Some(Regex::new(r"(?i)navigasjon|werbung").unwrap()),
+    ///     ..Default::default()
+    /// };
+    /// ```
+    ///
+    /// [`ReadabilityOptions::extra_unlikely_candidates`]: crate::ReadabilityOptions::extra_unlikely_candidates
+    ///
+    /// Default: `None`
+    pub extra_unlikely_candidates: Option<Regex>,
+
+    /// Extra class/id pattern for nodes that count even though they match an
+    /// unlikely pattern. Works like
+    /// [`ReadabilityOptions::extra_ok_maybe_candidates`].
+    ///
+    /// [`ReadabilityOptions::extra_ok_maybe_candidates`]: crate::ReadabilityOptions::extra_ok_maybe_candidates
+    ///
+    /// Default: `None`
+    pub extra_ok_maybe_candidates: Option<Regex>,
+
+    /// Decides whether each candidate node is visible, like Readability.js's
+    /// `visibilityChecker`.
+    ///
+    /// Only nodes it accepts count towards the score. When unset, nodes hidden
+    /// by style, `hidden` or `aria-hidden`, or inside such an ancestor, are
+    /// skipped.
+    ///
+    /// ```rust
+    /// use readabilityrs::ReaderableOptions;
+    /// use std::sync::Arc;
+    ///
+    /// let options = ReaderableOptions {
+    ///     visibility_checker: Some(Arc::new(|node| !node.hidden)),
+    ///     ..Default::default()
+    /// };
+    /// ```
+    ///
+    /// Default: `None`
+    pub visibility_checker: Option<VisibilityChecker>,
 }
 
 impl Default for ReaderableOptions {
@@ -76,6 +215,63 @@ impl Default for ReaderableOptions {
         Self {
             min_content_length: 140,
             min_score: 20.0,
+            cjk_length_factor: 0.4,
+            assume_truncated: false,
+            unlikely_candidates_override: None,
+            extra_unlikely_candidates: None,
+            extra_ok_maybe_candidates: None,
+            visibility_checker: None,
+        }
+    }
+}
+
+impl fmt::Debug for ReaderableOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderableOptions")
+            .field("min_content_length", &self.min_content_length)
+            .field("min_score", &self.min_score)
+            .field("cjk_length_factor", &self.cjk_length_factor)
+            .field("assume_truncated", &self.assume_truncated)
+            .field(
+                "unlikely_candidates_override",
+                &self.unlikely_candidates_override,
+            )
+            .field("extra_unlikely_candidates", &self.extra_unlikely_candidates)
+            .field("extra_ok_maybe_candidates", &self.extra_ok_maybe_candidates)
+            .field(
+                "visibility_checker",
+                &self.visibility_checker.as_ref().map(|_| "<fn>"),
+            )
+            .finish()
+    }
+}
+
+impl ReaderableOptions {
+    /// These options with both thresholds scaled down for a cut-off input
+    fn for_truncated_input(&self) -> Self {
+        Self {
+            min_content_length: (self.min_content_length as f64 * TRUNCATED_THRESHOLD_SCALE).round()
+                as usize,
+            min_score: self.min_score * TRUNCATED_THRESHOLD_SCALE,
+            ..self.clone()
+        }
+    }
+
+    fn is_unlikely_candidate(&self, element: ElementRef) -> bool {
+        let node = element.value();
+        is_unlikely_class_id(
+            node.attr("class").unwrap_or(""),
+            node.attr("id").unwrap_or(""),
+            self.unlikely_candidates_override.as_ref(),
+            self.extra_unlikely_candidates.as_ref(),
+            self.extra_ok_maybe_candidates.as_ref(),
+        )
+    }
+
+    fn is_visible(&self, element: ElementRef) -> bool {
+        match &self.visibility_checker {
+            Some(checker) => checker(&NodeInfo::from_element(element)),
+            None => dom_utils::is_probably_visible(element),
         }
     }
 }
@@ -125,6 +321,7 @@ impl Default for ReaderableOptions {
 /// let options = ReaderableOptions {
 ///     min_content_length: 200,
 ///     min_score: 30.0,
+///     ..Default::default()
 /// };
 ///
 /// if is_probably_readerable(html, Some(options)) {
@@ -134,41 +331,110 @@ impl Default for ReaderableOptions {
 ///
 /// ## Algorithm
 ///
-/// The function finds all `<p>`, `<pre>`, and `<article>` elements in the document,
-/// then filters out paragraphs shorter than the configured `min_content_length`. A score
-/// is calculated based on the remaining content length, and the function returns `true`
-/// if this score exceeds the `min_score` threshold.
+/// The function finds all visible `<p>`, `<pre>`, and `<article>` elements in the document,
+/// skipping those whose class or id looks unlikely to be content, then filters out
+/// paragraphs shorter than the configured `min_content_length` (in bytes, or in characters
+/// scaled by `cjk_length_factor` for mostly CJK paragraphs). A score is calculated based
+/// on the remaining content length, weighted up inside `<article>`, `<main>` or
+/// `[role=main]` and down inside `<aside>`, `<nav>` or `<footer>`, and the function
+/// returns `true` if this score exceeds the `min_score` threshold. Paragraphs that look
+/// like part of a consent screen, login wall or bot check subtract from the score instead.
 ///
 /// ## Performance
 ///
 /// This function is much faster than a full parse, making it ideal for batch processing
 /// large numbers of URLs, pre-filtering in crawlers or scrapers, and quick content
-/// classification tasks.
+/// classification tasks. It returns as soon as the score crosses `min_score`, so
+/// most of the time on readerable pages is spent parsing the HTML.
 pub fn is_probably_readerable(html: &str, options: Option<ReaderableOptions>) -> bool {
-    let options = options.unwrap_or_default();
-    let document = Html::parse_document(html);
+    let document = parse_document_or_fragment(html);
+    is_input_readerable(&document, html, &options.unwrap_or_default())
+}
+
+/// Parse a full document, or a body fragment when there is no `<html>`,
+/// `<head>` or `<body>` tag to build one around.
+///
+/// Parsing a bare fragment as a document hoists leading `<meta>`, `<style>`,
+/// `<noscript>` and the like into a made-up `<head>`.
+fn parse_document_or_fragment(html: &str) -> Html {
+    if is_full_document(html) {
+        Html::parse_document(html)
+    } else {
+        Html::parse_fragment(html)
+    }
+}
+
+fn is_full_document(html: &str) -> bool {
+    static DOCUMENT_TAG: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)<(?:!doctype|html|head|body)[\s>/]").unwrap());
+    DOCUMENT_TAG.is_match(html)
+}
+
+/// Whether the HTML looks cut off: it ends inside a tag, or is a full
+/// document that never closes its `<body>` or `<html>`
+fn looks_truncated(html: &str) -> bool {
+    if html.rfind('<') > html.rfind('>') {
+        return true;
+    }
+
+    // Closing tags sit at the very end, so only the tail needs searching
+    let tail = html.as_bytes()[html.len().saturating_sub(1024)..].to_ascii_lowercase();
+    let closed = |tag: &[u8]| memmem::find(&tail, tag).is_some();
+    is_full_document(html) && !closed(b"</body") && !closed(b"</html")
+}
 
+/// [`is_document_readerable`] with the thresholds lowered when
+/// `assume_truncated` is set and `html`, the source of `document`, looks cut off
+pub(crate) fn is_input_readerable(
+    document: &Html,
+    html: &str,
+    options: &ReaderableOptions,
+) -> bool {
+    if options.assume_truncated && looks_truncated(html) {
+        is_document_readerable(document, &options.for_truncated_input())
+    } else {
+        is_document_readerable(document, options)
+    }
+}
+
+/// [`is_probably_readerable`] on an already parsed document
+pub(crate) fn is_document_readerable(document: &Html, options: &ReaderableOptions) -> bool {
     // TODO: Implement full isProbablyReaderable logic
     // For now, just do a basic check
 
     let p_selector = Selector::parse("p, pre, article").unwrap();
-    let paragraphs: Vec<_> = document.select(&p_selector).collect();
+    let mut score = 0.0;
+    let mut forms = FormShares::new(document);
 
-    if paragraphs.is_empty() {
-        return false;
-    }
+    // Matched lazily, so nodes after the one crossing the threshold are never visited
+    for p in document.select(&p_selector) {
+        if !options.is_visible(p) {
+            continue;
+        }
 
-    let mut score = 0.0;
+        // Like Readability.js, nodes with unlikely classes don't count
+        if options.is_unlikely_candidate(p) {
+            continue;
+        }
 
-    for p in paragraphs {
-        let text = p.text().collect::<String>();
-        let text_len = text.trim().len();
+        let (text_len, mostly_cjk) = dom_utils::get_text_content_stats(p);
+        let min_length = utils::cjk_length_threshold(
+            options.min_content_length,
+            options.cjk_length_factor,
+            mostly_cjk,
+        );
 
-        if text_len < options.min_content_length {
+        if text_len < min_length {
             continue;
         }
 
-        score += ((text_len - options.min_content_length) as f64).sqrt();
+        let paragraph_score = ((text_len - min_length) as f64).sqrt() * landmark_weight(p);
+        match interstitial_signals(p, &mut forms) {
+            0 => score += paragraph_score,
+            // Subtracted rather than rejected outright, so a page with a consent
+            // form next to a real article still passes
+            signals => score -= paragraph_score * signals as f64 * INTERSTITIAL_SIGNAL_PENALTY,
+        }
 
         if score > options.min_score {
             return true;
@@ -178,6 +444,101 @@ pub fn is_probably_readerable(html: &str, options: Option<ReaderableOptions>) ->
     false
 }
 
+/// Whether forms hold most of the document's text, measured once per form.
+///
+/// ASP.NET WebForms and similar frameworks wrap the whole page in a `<form>`,
+/// so sitting in one only says something when the form is a small part of it.
+struct FormShares<'a> {
+    document: &'a Html,
+    document_len: Option<usize>,
+    wraps_page: HashMap<ego_tree::NodeId, bool>,
+}
+
+impl<'a> FormShares<'a> {
+    fn new(document: &'a Html) -> Self {
+        Self {
+            document,
+            document_len: None,
+            wraps_page: HashMap::new(),
+        }
+    }
+
+    fn wraps_page(&mut self, form: ElementRef) -> bool {
+        let document = self.document;
+        let document_len = *self
+            .document_len
+            .get_or_insert_with(|| dom_utils::get_text_content_length(document.root_element()));
+        *self.wraps_page.entry(form.id()).or_insert_with(|| {
+            let form_len = dom_utils::get_text_content_length(form);
+            form_len as f64 > document_len as f64 * INTERSTITIAL_FORM_SHARE
+        })
+    }
+}
+
+/// Count the signs that a paragraph belongs to a consent screen, login wall
+/// or bot check: it sits in a form, has a password field next to it or an
+/// email field in its form, or is made of the boilerplate phrases such pages
+/// use.
+///
+/// Email fields elsewhere are left alone, since newsletter sign-ups sit next
+/// to plenty of real articles. A form holding most of the page only counts
+/// alongside another signal.
+fn interstitial_signals(element: ElementRef, forms: &mut FormShares) -> usize {
+    static PHRASES: Lazy<RegexSet> = Lazy::new(|| {
+        RegexSetBuilder::new(
+            INTERSTITIAL_PHRASES
+                .iter()
+                .map(|phrase| regex::escape(phrase)),
+        )
+        .case_insensitive(true)
+        .build()
+        .unwrap()
+    });
+    static PASSWORD_INPUT: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("input[type=password]").unwrap());
+    static EMAIL_INPUT: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("input[type=email]").unwrap());
+
+    let form = element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|ancestor| ancestor.value().name() == "form");
+    let near_credentials = element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .take_while(|ancestor| !matches!(ancestor.value().name(), "body" | "html"))
+        .take(2)
+        .any(|ancestor| ancestor.select(&PASSWORD_INPUT).next().is_some())
+        || form.is_some_and(|form| form.select(&EMAIL_INPUT).next().is_some());
+    let text: String = element.text().collect();
+    let phrase_heavy = PHRASES.matches(&text).iter().count() >= 2;
+    let in_form =
+        form.is_some_and(|form| near_credentials || phrase_heavy || !forms.wraps_page(form));
+
+    [in_form, near_credentials, phrase_heavy]
+        .into_iter()
+        .filter(|&signal| signal)
+        .count()
+}
+
+/// Score multiplier from the nearest landmark around a paragraph.
+///
+/// Text inside `<article>` or `<main>` is stronger evidence of an article than
+/// the same text in generic containers, and text in asides, navigation or
+/// footers is weaker.
+fn landmark_weight(element: ElementRef) -> f64 {
+    for ancestor in element.ancestors().filter_map(ElementRef::wrap) {
+        let node = ancestor.value();
+        match node.name() {
+            "article" | "main" => return READERABLE_LANDMARK_BONUS,
+            "aside" | "nav" | "footer" => return READERABLE_BOILERPLATE_PENALTY,
+            _ if node.attr("role") == Some("main") => return READERABLE_LANDMARK_BONUS,
+            _ => {}
+        }
+    }
+    1.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +562,24 @@ mod tests {
         assert!(is_probably_readerable(html, None));
     }
 
+    #[test]
+    fn test_hidden_content_not_readerable() {
+        let html = r#"
+            <html>
+                <body>
+                    <div hidden>
+                        <p>This is a long enough paragraph that would make the content readerable if it were shown.
+                        It has sufficient content to pass the minimum threshold check, but it sits in a hidden container.</p>
+                    </div>
+                    <p aria-hidden="true">Another paragraph with more content to increase the score. This paragraph is also long
+                    enough to count, but screen readers and browsers are told to skip it, so it should not count either.</p>
+                </body>
+            </html>
+        "#;
+
+        assert!(!is_probably_readerable(html, None));
+    }
+
     #[test]
     fn test_not_readerable() {
         let html = r#"
@@ -213,4 +592,254 @@ mod tests {
 
         assert!(!is_probably_readerable(html, None));
     }
+
+    #[test]
+    fn test_score_must_exceed_min_score() {
+        // One paragraph 400 characters over the minimum scores exactly 20
+        let page =
+            |length: usize| format!("<html><body><p>{}</p></body></html>", "a".repeat(length));
+
+        assert!(!is_probably_readerable(&page(540), None));
+        assert!(is_probably_readerable(&page(541), None));
+    }
+
+    #[test]
+    fn test_landmarks_weight_score() {
+        let page = |open: &str, close: &str, length: usize| {
+            format!(
+                "<html><body>{open}<div><p>{}</p></div>{close}</body></html>",
+                "a".repeat(length)
+            )
+        };
+
+        // Scores 17 on its own, 25.5 inside a main landmark
+        assert!(!is_probably_readerable(&page("<div>", "</div>", 429), None));
+        assert!(is_probably_readerable(
+            &page("<article>", "</article>", 429),
+            None
+        ));
+        assert!(is_probably_readerable(
+            &page("<main>", "</main>", 429),
+            None
+        ));
+        assert!(is_probably_readerable(
+            &page(r#"<div role="main">"#, "</div>", 429),
+            None
+        ));
+
+        // Scores 23 on its own, 17.25 inside an aside, navigation or footer
+        assert!(is_probably_readerable(&page("<div>", "</div>", 669), None));
+        assert!(!is_probably_readerable(
+            &page("<aside>", "</aside>", 669),
+            None
+        ));
+        assert!(!is_probably_readerable(&page("<nav>", "</nav>", 669), None));
+        assert!(!is_probably_readerable(
+            &page("<footer>", "</footer>", 669),
+            None
+        ));
+
+        // The nearest landmark decides
+        assert!(!is_probably_readerable(
+            &page("<main><footer>", "</footer></main>", 669),
+            None
+        ));
+    }
+
+    #[test]
+    fn test_landmark_pages_readerable() {
+        // The same three paragraphs score 16 in a generic <div>, 24 in an <article>
+        let page = |name: &str| {
+            std::fs::read_to_string(format!("tests/test-pages/{name}/source.html")).unwrap()
+        };
+
+        assert!(is_probably_readerable(&page("landmark-article"), None));
+        assert!(!is_probably_readerable(&page("landmark-div"), None));
+    }
+
+    #[test]
+    fn test_consent_interstitial_not_readerable() {
+        let page = |name: &str| {
+            std::fs::read_to_string(format!("tests/test-pages/{name}/source.html")).unwrap()
+        };
+
+        assert!(!is_probably_readerable(
+            &page("gdpr-consent-interstitial"),
+            None
+        ));
+        assert!(is_probably_readerable(&page("gdpr-article"), None));
+        // The whole page sits in one form, as on ASP.NET WebForms sites
+        assert!(is_probably_readerable(&page("aspnet-webforms"), None));
+    }
+
+    #[test]
+    fn test_interstitial_signals_subtract() {
+        // Scores 23 per paragraph
+        let paragraph = format!("<p>{}</p>", "a".repeat(669));
+        let login_wall = format!(r#"<form>{paragraph}<input type="password"></form>"#);
+        let page = |body: String| format!("<html><body>{body}</body></html>");
+
+        assert!(is_probably_readerable(&page(paragraph.clone()), None));
+        assert!(!is_probably_readerable(&page(login_wall.clone()), None));
+        // In a form and next to a password field, so it takes away a full paragraph
+        assert!(!is_probably_readerable(
+            &page(format!("{login_wall}{paragraph}")),
+            None
+        ));
+        assert!(is_probably_readerable(
+            &page(format!("{login_wall}{paragraph}{paragraph}")),
+            None
+        ));
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        // Cut inside the first tag past the given offset, like a range request would
+        let prefix = |name: &str, offset: usize| {
+            let html =
+                std::fs::read_to_string(format!("tests/test-pages/{name}/source.html")).unwrap();
+            let cut = offset + html[offset..].find('<').unwrap() + 2;
+            html[..cut].to_string()
+        };
+        let truncated = ReaderableOptions {
+            assume_truncated: true,
+            ..Default::default()
+        };
+
+        let article = prefix("engadget", 50 * 1024);
+        assert!(looks_truncated(&article));
+        assert!(!is_probably_readerable(&article, None));
+        assert!(is_probably_readerable(&article, Some(truncated.clone())));
+
+        let consent = prefix("gdpr-consent-interstitial", 1024);
+        assert!(!is_probably_readerable(&consent, Some(truncated.clone())));
+
+        // Complete inputs keep the usual thresholds
+        let short = format!("<html><body><p>{}</p></body></html>", "a".repeat(400));
+        assert!(!looks_truncated(&short));
+        assert!(!is_probably_readerable(&short, Some(truncated.clone())));
+        let cut_in_text = short.replace("</p></body></html>", "");
+        assert!(looks_truncated(&cut_in_text));
+        assert!(is_probably_readerable(&cut_in_text, Some(truncated)));
+    }
+
+    #[test]
+    fn test_fragment_input() {
+        let paragraphs = format!("<p>{}</p>", "a".repeat(669));
+        let fragment =
+            format!(r#"<meta charset="utf-8"><style>p {{}}</style><main>{paragraphs}</main>"#);
+
+        assert!(!is_full_document(&fragment));
+        assert!(is_full_document("<!DOCTYPE html><p>text</p>"));
+        assert!(is_full_document("<BODY class=x><p>text</p>"));
+        assert_eq!(
+            is_probably_readerable(&fragment, None),
+            is_probably_readerable(&format!("<html><body>{fragment}</body></html>"), None)
+        );
+        assert!(is_probably_readerable(&fragment, None));
+        assert!(!is_probably_readerable(
+            &format!("<aside>{}</aside>", "<p>Short</p>".repeat(20)),
+            None
+        ));
+    }
+
+    #[test]
+    fn test_unlikely_candidate_patterns() {
+        // Scores 23 unless its class rules it out
+        let page = |class: &str| {
+            format!(
+                r#"<html><body><p class="{class}">{}</p></body></html>"#,
+                "a".repeat(669)
+            )
+        };
+        let pattern = |pattern: &str| Some(Regex::new(pattern).unwrap());
+
+        let menu = page("meny-tekst");
+        let extra = ReaderableOptions {
+            extra_unlikely_candidates: pattern("(?i)meny"),
+            ..Default::default()
+        };
+        assert!(is_probably_readerable(&menu, None));
+        assert!(!is_probably_readerable(&menu, Some(extra.clone())));
+        let rescued = ReaderableOptions {
+            extra_ok_maybe_candidates: pattern("(?i)tekst"),
+            ..extra
+        };
+        assert!(is_probably_readerable(&menu, Some(rescued)));
+
+        // "extra" is on the built-in list
+        let lead = page("ingress extra-tekst");
+        let replaced = ReaderableOptions {
+            unlikely_candidates_override: pattern("(?i)sidebar|meny"),
+            ..Default::default()
+        };
+        assert!(!is_probably_readerable(&lead, None));
+        assert!(is_probably_readerable(&lead, Some(replaced.clone())));
+        assert!(!is_probably_readerable(&menu, Some(replaced)));
+    }
+
+    #[test]
+    fn test_cjk_paragraphs_readerable() {
+        // Four paragraphs of about 110 characters, short of 140 but a full news story
+        let html = std::fs::read_to_string("tests/test-pages/japanese-news/source.html").unwrap();
+        let no_cjk_scaling = ReaderableOptions {
+            cjk_length_factor: 1.0,
+            ..Default::default()
+        };
+
+        assert!(is_probably_readerable(&html, None));
+        assert!(!is_probably_readerable(&html, Some(no_cjk_scaling)));
+    }
+
+    #[test]
+    fn test_non_cjk_paragraphs_measured_in_bytes() {
+        // 105 characters but 193 bytes, so three of them score just over 20
+        let paragraph = "<p>Новая линия трамвая соединит вокзал с университетом и больницей, а поезда будут ходить каждые семь минут.</p>";
+        let page = |count: usize| format!("<html><body>{}</body></html>", paragraph.repeat(count));
+
+        assert!(is_probably_readerable(&page(3), None));
+        assert!(!is_probably_readerable(&page(2), None));
+    }
+
+    #[test]
+    fn test_visibility_checker() {
+        let html = format!(
+            r#"<html><body><p class="ghost story">{}</p><p class="note">Short</p></body></html>"#,
+            "a".repeat(600)
+        );
+        let hide_ghosts = ReaderableOptions {
+            visibility_checker: Some(Arc::new(|node: &NodeInfo| {
+                assert_eq!(node.tag, "p");
+                !node
+                    .class
+                    .is_some_and(|class| class.split_whitespace().any(|c| c == "ghost"))
+            })),
+            ..Default::default()
+        };
+
+        assert!(is_probably_readerable(&html, None));
+        assert!(!is_probably_readerable(&html, Some(hide_ghosts.clone())));
+        assert!(is_probably_readerable(
+            &html.replace("ghost story", "story"),
+            Some(hide_ghosts)
+        ));
+    }
+
+    #[test]
+    fn test_visibility_checker_can_show_hidden_nodes() {
+        let html = format!(
+            "<html><body><p hidden>{}</p></body></html>",
+            "a".repeat(600)
+        );
+        let show_all = ReaderableOptions {
+            visibility_checker: Some(Arc::new(|node: &NodeInfo| {
+                assert!(node.hidden && !node.aria_hidden);
+                true
+            })),
+            ..Default::default()
+        };
+
+        assert!(!is_probably_readerable(&html, None));
+        assert!(is_probably_readerable(&html, Some(show_all)));
+    }
 }
@@ -1,7 +1,7 @@
 //! Content scoring algorithms for determining article quality.
 
-use crate::constants::{ParseFlags, REGEXPS};
-use crate::dom_utils;
+use crate::constants::{truncate_class_id, ParseFlags, REGEXPS};
+use crate::dom_utils::{self, TextStats};
 use scraper::ElementRef;
 
 /// Get an element's class/ID weight using regular expressions.
@@ -20,25 +20,19 @@ pub fn get_class_weight(element: ElementRef, flags: ParseFlags) -> i32 {
 
     let mut weight = 0;
 
-    // Check class names
-    if let Some(class) = element.value().attr("class") {
-        if !class.is_empty() {
-            if REGEXPS.negative.is_match(class) {
-                weight -= 25;
-            } else if REGEXPS.positive.is_match(class) {
-                weight += 25;
-            }
+    // Check class names, then ID
+    for value in [element.value().attr("class"), element.value().attr("id")]
+        .into_iter()
+        .flatten()
+    {
+        if value.is_empty() {
+            continue;
         }
-    }
-
-    // Check ID
-    if let Some(id) = element.value().attr("id") {
-        if !id.is_empty() {
-            if REGEXPS.negative.is_match(id) {
-                weight -= 25;
-            } else if REGEXPS.positive.is_match(id) {
-                weight += 25;
-            }
+        let matches = REGEXPS.match_class_id(truncate_class_id(value));
+        if matches.negative {
+            weight -= 25;
+        } else if matches.positive {
+            weight += 25;
         }
     }
 
@@ -107,25 +101,23 @@ pub fn initialize_node_score(element: ElementRef, flags: ParseFlags) -> f64 {
 /// 4. Link density penalty
 ///
 /// # Arguments
-/// * `element` - The element to score
+/// * `stats` - Text measurements of the element to score
 /// * `link_density_modifier` - Modifier for link density calculation
 ///
 /// # Returns
 /// Content score as a float
-pub fn calculate_content_score(element: ElementRef, link_density_modifier: f64) -> f64 {
-    let inner_text = dom_utils::get_inner_text(element, false);
-    if inner_text.len() < 25 {
+pub fn calculate_content_score(stats: &TextStats, link_density_modifier: f64) -> f64 {
+    if stats.text_length < 25 {
         return 0.0;
     }
 
     let mut score = 1.0;
-    let comma_count = REGEXPS.commas.find_iter(&inner_text).count();
-    score += comma_count as f64;
+    score += stats.comma_count as f64;
 
-    let length_bonus = (inner_text.len() as f64 / 100.0).min(3.0);
+    let length_bonus = (stats.text_length as f64 / 100.0).min(3.0);
     score += length_bonus;
 
-    let link_density = dom_utils::get_link_density(element);
+    let link_density = stats.link_density();
     score *= 1.0 - link_density + link_density_modifier;
 
     score
@@ -147,7 +139,7 @@ pub fn calculate_content_score(element: ElementRef, link_density_modifier: f64)
 pub fn is_valid_byline(element: ElementRef, match_string: &str) -> bool {
     let rel = element.value().attr("rel").unwrap_or("");
     let itemprop = element.value().attr("itemprop").unwrap_or("");
-    let byline_length = dom_utils::get_inner_text(element, false).len();
+    let byline_length = dom_utils::get_inner_text_length(element);
 
     (rel == "author" || (itemprop.contains("author")) || REGEXPS.byline.is_match(match_string))
         && byline_length > 0
@@ -185,6 +177,31 @@ mod tests {
         assert!(get_class_weight(positive_id, flags) > 0);
     }
 
+    #[test]
+    fn test_get_class_weight_keeps_whole_tokens() {
+        let utilities = "flex items-center px-4 py-2 rounded-lg gap-2 ".repeat(8);
+        // `hidden` straddles the cap, which falls right after its `hid`
+        let straddling = format!("{}px-2 hidden", "p-4 ".repeat(62));
+        let html = Html::parse_fragment(&format!(
+            r#"
+            <div id="early" class="sidebar {utilities}">Negative</div>
+            <div id="split" class="{straddling}">Cut between tokens</div>
+        "#
+        ));
+        let weight = |id: &str| {
+            let selector = Selector::parse(id).unwrap();
+            get_class_weight(
+                html.select(&selector).next().unwrap(),
+                ParseFlags::WEIGHT_CLASSES,
+            )
+        };
+
+        assert_eq!(weight("#early"), -25);
+        assert_eq!(weight("#split"), 0);
+        assert_eq!(truncate_class_id(&straddling), &straddling[..252]);
+        assert_eq!(truncate_class_id(&"x".repeat(300)), "");
+    }
+
     #[test]
     fn test_initialize_node_score() {
         let p_html = Html::parse_fragment("<p>Content</p>");
@@ -229,7 +246,7 @@ mod tests {
         let selector = Selector::parse("p").unwrap();
         let elem = html.select(&selector).next().unwrap();
 
-        let score = calculate_content_score(elem, 0.0);
+        let score = calculate_content_score(&TextStats::measure(elem), 0.0);
         assert!(score > 1.0);
     }
 
@@ -239,7 +256,7 @@ mod tests {
         let selector = Selector::parse("p").unwrap();
         let elem = html.select(&selector).next().unwrap();
 
-        let score = calculate_content_score(elem, 0.0);
+        let score = calculate_content_score(&TextStats::measure(elem), 0.0);
         assert_eq!(score, 0.0);
     }
 }
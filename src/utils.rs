@@ -1,105 +1,633 @@
 //! Utility functions for text processing and manipulation.
 
-use crate::constants::REGEXPS;
+use crate::article::{Contributor, ContributorRole};
+use crate::constants::{
+    AUTHOR_CONJUNCTIONS, AUTHOR_DISQUALIFIERS, BYLINE_PREFIXES, INVISIBLE_CHARS,
+    LOCALIZED_MONTH_NAMES, MENU_ITEM_ENCLOSURES, MENU_SEPARATORS, MENU_WORDS, MONTH_NAMES,
+    NAME_CREDENTIALS, NAME_HONORIFICS, NAME_PARTICLES, NAME_SUFFIXES, NEWS_AGENCIES,
+    NON_NAME_WORDS, ORG_CREDIT_KEYWORDS, REGEXPS, RELATIVE_TIME_MARKERS, RELATIVE_TIME_WORDS,
+    SOCIAL_PROFILE_HOSTS, SPACE_LIKE_CHARS, TIMEZONE_TOKENS, TRACKING_PARAMS,
+    TRACKING_PARAM_PREFIXES, WEEKDAY_NAMES, WINDOWS_1252_C1_CHARS,
+};
+use memchr::{memchr2, memchr3, memmem};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::borrow::Cow;
 
-/// Unescape basic and numeric HTML entities in a string.
-pub fn unescape_html_entities(text: &str) -> String {
-    let mut result = String::with_capacity(text.len());
-    let mut i = 0;
-    while i < text.len() {
-        if text.as_bytes()[i] == b'&' {
-            if let Some(semi_offset) = text[i..].find(';') {
-                let end = i + semi_offset + 1;
-                let entity = &text[i..end];
-                if let Some(decoded) = decode_html_entity(entity) {
-                    result.push_str(&decoded);
-                    i = end;
-                    continue;
-                }
+/// Unescape named and numeric HTML entities in a string.
+///
+/// Named references use the full HTML5 table, including multi-codepoint
+/// entities and legacy names without a trailing semicolon ("&copy 2024").
+/// Text without a decodable reference is returned borrowed, without allocating.
+///
+/// ```
+/// use readabilityrs::unescape_html_entities;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(unescape_html_entities("Fish &amp; Chips"), "Fish & Chips");
+/// assert!(matches!(unescape_html_entities("Fish & Chips"), Cow::Borrowed(_)));
+/// ```
+pub fn unescape_html_entities(text: &str) -> Cow<'_, str> {
+    let Some(first_amp) = text.find('&') else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut result = String::new();
+    let mut copied = 0;
+    let mut pos = first_amp;
+    loop {
+        if let Some((decoded, len)) = decode_html_entity(&text[pos..]) {
+            if result.is_empty() {
+                result.reserve(text.len());
             }
+            result.push_str(&text[copied..pos]);
+            result.push_str(&decoded);
+            copied = pos + len;
+            pos = copied;
+        } else {
+            pos += 1;
         }
+        match text[pos..].find('&') {
+            Some(offset) => pos += offset,
+            None => break,
+        }
+    }
 
-        if let Some(ch) = text[i..].chars().next() {
-            result.push(ch);
-            i += ch.len_utf8();
-        } else {
-            break;
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    result.push_str(&text[copied..]);
+    Cow::Owned(result)
+}
+
+/// Like [`unescape_html_entities`], reusing `text` when nothing is decoded
+pub(crate) fn unescape_html_entities_owned(text: String) -> String {
+    match unescape_html_entities(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(decoded) => decoded,
+    }
+}
+
+/// Decode the character reference at the start of `text`, returning the
+/// decoded string and the number of bytes consumed.
+fn decode_html_entity(text: &str) -> Option<(String, usize)> {
+    let body = &text[1..];
+    if let Some(numeric) = body.strip_prefix('#') {
+        let (digits, radix, offset) = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 3),
+            None => (numeric, 10, 2),
+        };
+        // Like browsers, decode up to the first non-digit even without a semicolon
+        let digits_len = digits
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(digits.len());
+        if digits_len == 0 {
+            return None;
         }
+        let semicolon = usize::from(digits[digits_len..].starts_with(';'));
+        let c = decode_numeric_reference(&digits[..digits_len], radix);
+        return Some((c.to_string(), offset + digits_len + semicolon));
     }
-    result
+
+    let name_len = body
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(body.len());
+    if name_len == 0 {
+        return None;
+    }
+    if body[name_len..].starts_with(';') {
+        if let Some(decoded) = lookup_named_entity(&body[..=name_len]) {
+            return Some((decoded, name_len + 2));
+        }
+    }
+
+    // Legacy names ("&amp", "&copy") may omit the semicolon, but like in
+    // attribute values they are left alone when letters or "=" run on ("&copyright")
+    if body[name_len..].starts_with('=') {
+        return None;
+    }
+    lookup_named_entity(&body[..name_len]).map(|decoded| (decoded, name_len + 1))
 }
 
-fn decode_html_entity(entity: &str) -> Option<String> {
-    match entity {
-        "&lt;" => Some("<".to_string()),
-        "&gt;" => Some(">".to_string()),
-        "&amp;" => Some("&".to_string()),
-        "&quot;" => Some("\"".to_string()),
-        "&apos;" | "&#39;" => Some("'".to_string()),
-        _ => {
-            if entity.starts_with("&#x") || entity.starts_with("&#X") {
-                let hex = entity.get(3..entity.len() - 1)?;
-                u32::from_str_radix(hex, 16)
-                    .ok()
-                    .and_then(std::char::from_u32)
-                    .map(|c| c.to_string())
-            } else if entity.starts_with("&#") && entity.ends_with(';') {
-                let digits = entity.get(2..entity.len() - 1)?;
-                digits
-                    .parse::<u32>()
-                    .ok()
-                    .and_then(std::char::from_u32)
-                    .map(|c| c.to_string())
-            } else {
-                None
+/// Map a numeric reference to the character browsers produce: C1 controls
+/// are read as Windows-1252 ("&#146;" is a right single quote), and null,
+/// surrogate, out-of-range and noncharacter code points become U+FFFD, as do
+/// control characters other than tab and newline.
+fn decode_numeric_reference(digits: &str, radix: u32) -> char {
+    let Ok(code) = u32::from_str_radix(digits, radix) else {
+        return char::REPLACEMENT_CHARACTER;
+    };
+    WINDOWS_1252_C1_CHARS
+        .iter()
+        .find(|(c1, _)| *c1 == code)
+        .map(|(_, c)| *c)
+        .or_else(|| {
+            std::char::from_u32(code).filter(|&c| {
+                (!c.is_control() || matches!(c, '\t' | '\n')) && !is_noncharacter(code)
+            })
+        })
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Whether a code point is a Unicode noncharacter (U+FDD0..U+FDEF, U+xFFFE, U+xFFFF)
+fn is_noncharacter(code: u32) -> bool {
+    (0xfdd0..=0xfdef).contains(&code) || code & 0xfffe == 0xfffe
+}
+
+fn lookup_named_entity(name: &str) -> Option<String> {
+    match markup5ever::data::NAMED_ENTITIES.get(name)? {
+        (0, _) => None,
+        &(first, second) => std::iter::once(first)
+            .chain((second != 0).then_some(second))
+            .map(std::char::from_u32)
+            .collect(),
+    }
+}
+
+/// Normalize whitespace in a string.
+///
+/// Typographic spaces (`SPACE_LIKE_CHARS`, such as `&nbsp;`) become plain
+/// spaces, invisible characters (`INVISIBLE_CHARS`) are dropped, and runs of
+/// whitespace collapse to a single space. Already normalized text is returned
+/// borrowed.
+///
+/// ```
+/// use readabilityrs::normalize_whitespace;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(normalize_whitespace("Jane\u{a0}Doe  reports"), "Jane Doe reports");
+/// assert!(matches!(normalize_whitespace("Jane Doe reports"), Cow::Borrowed(_)));
+/// ```
+pub fn normalize_whitespace(text: &str) -> Cow<'_, str> {
+    let spaced = replace_soft_spaces(text);
+    // Spaces outside `SPACE_LIKE_CHARS`, such as the line separator, are rare
+    // enough to leave to the regex
+    let has_other_spaces =
+        !spaced.is_ascii() && spaced.chars().any(|c| !c.is_ascii() && c.is_whitespace());
+    let collapsed = if has_other_spaces {
+        REGEXPS.normalize.replace_all(&spaced, " ")
+    } else {
+        collapse_ascii_whitespace(&spaced)
+    };
+    if let Cow::Owned(collapsed) = collapsed {
+        return Cow::Owned(collapsed);
+    }
+    spaced
+}
+
+/// Whether a byte is ASCII whitespace as matched by `\s`
+fn is_ascii_space(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\x0b' | b'\x0c' | b'\r')
+}
+
+/// Collapse runs of ASCII whitespace to a single space in one pass, matching
+/// `REGEXPS.normalize` on text without non-ASCII spaces
+fn collapse_ascii_whitespace(text: &str) -> Cow<'_, str> {
+    static DOUBLE_SPACE: Lazy<memmem::Finder<'static>> = Lazy::new(|| memmem::Finder::new("  "));
+
+    let bytes = text.as_bytes();
+    // Prose is mostly single spaces, which vectorized scans rule out quickly
+    let only_spaces =
+        memchr3(b'\t', b'\n', b'\r', bytes).is_none() && memchr2(b'\x0b', b'\x0c', bytes).is_none();
+    let first_run = if only_spaces {
+        DOUBLE_SPACE.find(bytes)
+    } else {
+        find_ascii_whitespace_run(bytes, 0)
+    };
+    let Some(mut run_start) = first_run else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut collapsed = String::with_capacity(text.len());
+    let mut copied = 0;
+    loop {
+        let run_end = bytes[run_start..]
+            .iter()
+            .position(|&byte| !is_ascii_space(byte))
+            .map_or(bytes.len(), |len| run_start + len);
+        collapsed.push_str(&text[copied..run_start]);
+        collapsed.push(' ');
+        copied = run_end;
+        match find_ascii_whitespace_run(bytes, run_end) {
+            Some(next) => run_start = next,
+            None => break,
+        }
+    }
+    collapsed.push_str(&text[copied..]);
+    Cow::Owned(collapsed)
+}
+
+/// Start of the first run of two or more ASCII whitespace bytes from `from` on
+fn find_ascii_whitespace_run(bytes: &[u8], from: usize) -> Option<usize> {
+    bytes[from..]
+        .windows(2)
+        .position(|pair| is_ascii_space(pair[0]) && is_ascii_space(pair[1]))
+        .map(|offset| from + offset)
+}
+
+/// Turn typographic spaces into plain spaces and drop invisible characters,
+/// keeping line breaks and runs of whitespace as they are
+fn replace_soft_spaces(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    let mut replaced = String::new();
+    let mut copied = 0;
+    for (i, c) in text.char_indices().filter(|(_, c)| !c.is_ascii()) {
+        let is_space = SPACE_LIKE_CHARS.contains(&c);
+        if is_space || INVISIBLE_CHARS.contains(&c) {
+            replaced.push_str(&text[copied..i]);
+            if is_space {
+                replaced.push(' ');
             }
+            copied = i + c.len_utf8();
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    replaced.push_str(&text[copied..]);
+    Cow::Owned(replaced)
+}
+
+static ASCII_WHITESPACE_RUN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[ \t\n\r\x0c]{2,}").unwrap());
+
+/// Collapse runs of ASCII whitespace to a single space, leaving no-break and
+/// other typographic spaces in place for display strings such as titles.
+pub fn normalize_whitespace_ascii(text: &str) -> String {
+    ASCII_WHITESPACE_RUN_REGEX
+        .replace_all(text, " ")
+        .to_string()
+}
+
+/// What kind of link target a URL string is, see `classify_url`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlKind {
+    /// Has a scheme and parses ("https://example.com/page")
+    Absolute,
+    /// Starts with a host but no scheme ("//cdn.example.com/a.jpg")
+    ProtocolRelative,
+    /// Path from the site root ("/blog/post")
+    RootRelative,
+    /// Path, query or scheme-less host relative to the document ("../a.jpg", "example.com/page")
+    Relative,
+    /// In-page anchor ("#section-2")
+    Fragment,
+    /// A scheme that does not point at a page or resource to resolve
+    Special(SpecialScheme),
+    /// Empty, whitespace-separated text or a URL that does not parse
+    Invalid,
+}
+
+/// Schemes classified as `UrlKind::Special`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialScheme {
+    Mailto,
+    Tel,
+    Javascript,
+    Data,
+}
+
+static URL_SCHEME_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([a-zA-Z][a-zA-Z0-9+.-]*):").unwrap());
+
+/// Classify a URL string as found in `href`, `src` or metadata values.
+///
+/// Like browsers, surrounding spaces and control characters are trimmed and
+/// tabs and newlines inside the URL are ignored.
+pub fn classify_url(s: &str) -> UrlKind {
+    let url: String = s
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    if url.is_empty() {
+        return UrlKind::Invalid;
+    }
+
+    if let Some(caps) = URL_SCHEME_REGEX.captures(&url) {
+        let special = match caps[1].to_ascii_lowercase().as_str() {
+            "mailto" => Some(SpecialScheme::Mailto),
+            "tel" => Some(SpecialScheme::Tel),
+            "javascript" => Some(SpecialScheme::Javascript),
+            "data" => Some(SpecialScheme::Data),
+            _ => None,
+        };
+        return match special {
+            Some(scheme) => UrlKind::Special(scheme),
+            None if url::Url::parse(&url).is_ok() => UrlKind::Absolute,
+            None => UrlKind::Invalid,
+        };
+    }
+
+    if url.contains(char::is_whitespace) {
+        UrlKind::Invalid
+    } else if url.starts_with('#') {
+        UrlKind::Fragment
+    } else if let Some(rest) = url.strip_prefix("//") {
+        let valid = !rest.starts_with('/') && url::Url::parse(&format!("https:{url}")).is_ok();
+        if valid {
+            UrlKind::ProtocolRelative
+        } else {
+            UrlKind::Invalid
         }
+    } else if url.starts_with('/') || url.starts_with('\\') {
+        UrlKind::RootRelative
+    } else {
+        UrlKind::Relative
+    }
+}
+
+/// Whether a URL names a scheme outside the web's hierarchical ones
+/// (`http`, `https`, `ftp`, `ws`, `wss`, `file`), such as `mailto:`, `sms:`,
+/// `geo:` or `urn:`. Such URLs have nothing to resolve against a base.
+pub(crate) fn has_opaque_scheme(s: &str) -> bool {
+    let url: String = s
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    URL_SCHEME_REGEX.captures(&url).is_some_and(|caps| {
+        !matches!(
+            caps[1].to_ascii_lowercase().as_str(),
+            "http" | "https" | "ftp" | "ws" | "wss" | "file"
+        )
+    })
+}
+
+/// Remove tracking query parameters from a URL.
+///
+/// A parameter goes when its name, percent-decoded and compared without case,
+/// starts with one of `TRACKING_PARAM_PREFIXES` or equals one of
+/// `TRACKING_PARAMS` or `extra`. The others keep their exact spelling and
+/// order, and the fragment is kept. `None` when nothing was removed.
+pub(crate) fn strip_tracking_params(url: &str, extra: &[String]) -> Option<String> {
+    let (url, fragment) = url.split_at(url.find('#').unwrap_or(url.len()));
+    let (path, query) = url.split_once('?')?;
+
+    let is_tracking = |pair: &str| {
+        let name = pair.split('=').next().unwrap_or_default();
+        let name = url::form_urlencoded::parse(name.as_bytes())
+            .next()
+            .map(|(name, _)| name.to_lowercase())
+            .unwrap_or_default();
+        TRACKING_PARAM_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+            || TRACKING_PARAMS.contains(&name.as_str())
+            || extra.iter().any(|param| param.eq_ignore_ascii_case(&name))
+    };
+    if !query.split('&').any(is_tracking) {
+        return None;
+    }
+
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !is_tracking(pair))
+        .collect();
+    let mut stripped = path.to_string();
+    if !kept.is_empty() {
+        stripped.push('?');
+        stripped.push_str(&kept.join("&"));
     }
+    stripped.push_str(fragment);
+    Some(stripped)
 }
 
-/// Normalize whitespace in a string
-pub fn normalize_whitespace(text: &str) -> String {
-    REGEXPS.normalize.replace_all(text, " ").to_string()
+static HREF_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)<a\b[^>]*?\shref\s*=\s*["']([^"']*)["']"#).unwrap());
+
+/// Minimum number of in-page links for a block to count as a table of contents
+pub(crate) const MIN_TOC_LINKS: usize = 3;
+
+/// Returns true if an HTML block is an in-page table of contents.
+///
+/// Every link must point at a fragment (`#section-2`) within the same document.
+pub(crate) fn is_in_page_toc(html: &str) -> bool {
+    let hrefs: Vec<&str> = HREF_REGEX
+        .captures_iter(html)
+        .filter_map(|caps| caps.get(1))
+        .map(|href| href.as_str().trim())
+        .collect();
+    hrefs.len() >= MIN_TOC_LINKS && hrefs.iter().all(|href| REGEXPS.hash_url.is_match(href))
 }
 
-/// Check if a string is a valid URL
-pub fn is_url(s: &str) -> bool {
-    url::Url::parse(s).is_ok()
+/// Whether a character is whitespace or invisible, like the no-break and
+/// zero-width spaces wrapping metadata text
+fn is_soft_space(c: char) -> bool {
+    c.is_whitespace() || INVISIBLE_CHARS.contains(&c)
 }
 
-static BY_PREFIX_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?i)^(by|par)[\s:,\-–—]+").unwrap());
+/// Caller-supplied words extending the built-in byline word lists
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BylineWords<'a> {
+    /// Words introducing an author name, added to `BYLINE_PREFIXES`
+    pub prefixes: &'a [String],
+    /// Words marking an organization credit, added to `ORG_CREDIT_KEYWORDS`
+    pub org_keywords: &'a [String],
+    /// Words ruling out a personal name, added to `AUTHOR_DISQUALIFIERS`
+    pub author_disqualifiers: &'a [String],
+}
 
-static SOFT_SPACE_CHARS: &[char] = &['\u{00a0}', '\u{200b}', '\u{feff}'];
+impl BylineWords<'_> {
+    fn prefixes(&self) -> impl Iterator<Item = &str> {
+        BYLINE_PREFIXES
+            .iter()
+            .copied()
+            .chain(self.prefixes.iter().map(String::as_str))
+    }
+}
 
 /// Returns true if the provided text looks like a byline ("By <name> ...").
-pub fn looks_like_byline(text: &str) -> bool {
-    let trimmed = text.trim();
+///
+/// The text must start with one of `BYLINE_PREFIXES` or `words.prefixes`
+/// (case-insensitive), followed by a separator and a capitalized name, so
+/// sentences such as "By clicking below" or "Di solito" are rejected.
+/// All-caps ("BY JOHN SMITH") and, after "By", lowercase ("by bell hooks")
+/// names are accepted when every word reads as part of a name.
+pub fn looks_like_byline(text: &str, words: &BylineWords) -> bool {
+    let trimmed = trim_decorative_symbols(text);
     if trimmed.is_empty() {
         return false;
     }
-    if !BY_PREFIX_REGEX.is_match(trimmed) {
-        return false;
+
+    if let Some(names) = strip_cjk_byline_marker(trimmed) {
+        return names
+            .split(['、', '・'])
+            .all(|name| looks_like_author_name(name, words));
     }
 
-    let remainder = BY_PREFIX_REGEX.replace(trimmed, "");
-    let remainder = remainder.trim_start();
+    let remainder = words
+        .prefixes()
+        .find_map(|prefix| Some((prefix, strip_byline_prefix(trimmed, prefix)?)));
+    let Some((prefix, remainder)) = remainder else {
+        return false;
+    };
     match remainder.chars().next() {
-        Some(ch) => ch.is_uppercase(),
-        None => false,
+        Some(ch) if ch.is_uppercase() && !is_all_caps(remainder) => true,
+        Some(ch) if ch.is_uppercase() => looks_like_stylized_name(remainder, words),
+        // Lowercase names ("bell hooks") are an English convention; elsewhere a
+        // lowercase remainder is ordinary prose ("Di solito ...")
+        Some(ch) if ch.is_lowercase() && prefix.eq_ignore_ascii_case("by") => {
+            looks_like_stylized_name(remainder, words)
+        }
+        Some(ch) if is_rtl_letter(ch) => looks_like_rtl_name(remainder),
+        _ => false,
+    }
+}
+
+/// Whether `text` has cased letters and all of them are uppercase
+fn is_all_caps(text: &str) -> bool {
+    let mut letters = text.chars().filter(|c| c.is_alphabetic()).peekable();
+    letters.peek().is_some() && letters.all(|c| !c.is_lowercase())
+}
+
+/// Whether a lowercase ("bell hooks") or all-caps ("JOHN SMITH") byline
+/// remainder reads as a name rather than a phrase such as "BY CLICKING SUBMIT"
+fn looks_like_stylized_name(text: &str, words: &BylineWords) -> bool {
+    let tokens: Vec<String> = text
+        .split_whitespace()
+        .map(|word| word.trim_end_matches([',', '.']).to_lowercase())
+        .collect();
+    if !(2..=4).contains(&tokens.len()) {
+        return false;
+    }
+    let is_name_word = |word: &String| {
+        !word.is_empty()
+            && word
+                .chars()
+                .all(|c| c.is_alphabetic() || matches!(c, '\'' | '’' | '-' | '.'))
+            && !NON_NAME_WORDS.contains(&word.as_str())
+    };
+    tokens.iter().all(is_name_word)
+        && !tokens[0].ends_with("ing")
+        && looks_like_author_name(text, words)
+}
+
+/// Title-case an all-caps byline ("BY JOHN SMITH" becomes "By John Smith"),
+/// keeping credentials and generational suffixes in their usual spelling.
+/// Text with any lowercase letter is returned unchanged.
+pub(crate) fn title_case_caps_byline(text: &str) -> String {
+    if !is_all_caps(text) {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut after_comma = false;
+    for (index, word) in text.split(' ').enumerate() {
+        if index > 0 {
+            result.push(' ');
+        }
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        let credential = NAME_CREDENTIALS
+            .iter()
+            .find(|credential| credential.eq_ignore_ascii_case(core));
+        let follows_comma = std::mem::replace(&mut after_comma, word.ends_with(','));
+        if let Some(credential) = credential.filter(|_| follows_comma) {
+            result.push_str(&word.replacen(core, credential, 1));
+            continue;
+        }
+        if matches!(core, "II" | "III" | "IV") {
+            result.push_str(word);
+            continue;
+        }
+        // Capitalize each hyphenated part and after a one-letter elision ("O'Brien"),
+        // leaving particles inside the name lowercase ("von der Leyen", "al-Sayed")
+        let mut run = 0;
+        let mut capitalize = index == 0 || !is_name_particle(core);
+        for ch in word.chars() {
+            if !ch.is_alphabetic() {
+                capitalize = !matches!(ch, '\'' | '’') || run == 1;
+                run = 0;
+                result.push(ch);
+                continue;
+            }
+            if capitalize {
+                result.push(ch);
+            } else {
+                result.extend(ch.to_lowercase());
+            }
+            capitalize = false;
+            run += 1;
+        }
     }
+    result
+}
+
+/// Strip a byline prefix and the separators after it from the start of `text`
+fn strip_byline_prefix<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let prefix = prefix.trim();
+    if prefix.is_empty() {
+        return None;
+    }
+    let head = text.get(..prefix.len())?;
+    if head.to_lowercase() != prefix.to_lowercase() {
+        return None;
+    }
+    let rest = &text[prefix.len()..];
+    let remainder = rest.trim_start_matches(|c: char| {
+        c.is_whitespace() || matches!(c, ':' | ',' | '-' | '–' | '—')
+    });
+    (remainder.len() < rest.len()).then_some(remainder)
 }
 
 /// Remove invisible space characters that frequently wrap metadata text.
-fn trim_soft_space(text: &str) -> &str {
-    text.trim_matches(|c| SOFT_SPACE_CHARS.contains(&c))
+pub(crate) fn trim_soft_space(text: &str) -> &str {
+    text.trim_matches(is_soft_space)
+}
+
+static HANDLE_PATH_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\w/@[\w.]+").unwrap());
+
+/// An email address ("jane.doe@example.com"), with an optional "Email:" label,
+/// `mailto:` scheme and enclosing brackets
+static EMAIL_ADDRESS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:\b(?:e-?mail|contact)\s*:?\s*)?[(<\[]?(?:mailto:)?[\w.+-]+@[\w-]+(?:\.[\w-]+)*\.[a-z]{2,}\b[)>\]]?",
+    )
+    .unwrap()
+});
+
+/// Remove email addresses from a byline, keeping the rest of each line
+/// ("Jane Doe, jane@example.com" becomes "Jane Doe"). Lines left empty are dropped.
+fn remove_email_addresses(text: &str) -> Option<String> {
+    if !EMAIL_ADDRESS_REGEX.is_match(text) {
+        return None;
+    }
+    let lines: Vec<String> = text
+        .split('\n')
+        .filter_map(|line| {
+            if !EMAIL_ADDRESS_REGEX.is_match(line) {
+                return Some(line.to_string());
+            }
+            let stripped = EMAIL_ADDRESS_REGEX.replace_all(line, "");
+            let kept = stripped
+                .trim_end_matches(|c: char| {
+                    c.is_whitespace() || matches!(c, ',' | '|' | '·' | '/' | '-' | '–' | '—')
+                })
+                .trim_start();
+            (!kept.is_empty()).then(|| kept.to_string())
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Whether `text` contains `host_path` ("x.com/") as a whole host, not as the
+/// tail of a longer one ("box.com/")
+fn contains_host_path(text: &str, host_path: &str) -> bool {
+    text.match_indices(host_path).any(|(index, _)| {
+        !text[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '-')
+    })
 }
 
 fn looks_like_social_handle(text: &str) -> bool {
-    let normalized = text.trim().to_lowercase();
+    // An "@" inside an email address is not a handle
+    let normalized = EMAIL_ADDRESS_REGEX
+        .replace_all(text.trim(), "")
+        .trim()
+        .to_lowercase();
     if normalized.is_empty() {
         return false;
     }
@@ -108,7 +636,15 @@ fn looks_like_social_handle(text: &str) -> bool {
         return true;
     }
 
-    if normalized.contains("twitter.com/") || normalized.contains("facebook.com/") {
+    if SOCIAL_PROFILE_HOSTS
+        .iter()
+        .any(|host| contains_host_path(&normalized, host))
+    {
+        return true;
+    }
+
+    // Profile paths on other hosts ("mastodon.example/@jane", "medium.com/@jane")
+    if HANDLE_PATH_REGEX.is_match(&normalized) {
         return true;
     }
 
@@ -132,12 +668,31 @@ fn looks_like_social_handle(text: &str) -> bool {
 }
 
 /// Heuristic check for anchor text that looks like a personal name.
-pub fn looks_like_author_name(text: &str) -> bool {
-    let trimmed = trim_soft_space(text.trim());
+///
+/// Honorifics ("Dr.", "Prof.", "Rev.") and trailing credentials ("MD", "PhD",
+/// "Jr.") are accepted around the name; a name must still follow an honorific.
+/// Names containing a word from `AUTHOR_DISQUALIFIERS` or
+/// `words.author_disqualifiers` ("Staff", "Editor") are rejected.
+pub fn looks_like_author_name(text: &str, words: &BylineWords) -> bool {
+    let trimmed = strip_name_credentials(trim_decorative_symbols(text));
     if trimmed.is_empty() || trimmed.len() > 80 {
         return false;
     }
 
+    if looks_like_cjk_author_name(trimmed) {
+        return true;
+    }
+
+    if let Some((first, rest)) = trimmed.split_once(char::is_whitespace) {
+        let next = rest.split_whitespace().next().unwrap_or("");
+        if is_name_honorific(first)
+            && !next.starts_with(char::is_uppercase)
+            && !is_name_particle(next)
+        {
+            return false;
+        }
+    }
+
     if !trimmed.chars().any(char::is_whitespace) {
         return false;
     }
@@ -156,45 +711,197 @@ pub fn looks_like_author_name(text: &str) -> bool {
         return false;
     }
 
-    const DISQUALIFIERS: [&str; 24] = [
-        "reporter",
-        "editor",
-        "writer",
-        "staff",
-        "senior",
-        "team",
-        "desk",
-        "anchor",
-        "producer",
-        "analyst",
-        "correspondent",
-        "contributor",
-        "technologist",
-        "developer",
-        "developers",
-        "news",
-        "press",
-        "service",
-        "bureau",
-        "foreign",
-        "android",
-        "buzzfeed",
-        "telegraph",
-        "view",
-    ];
-
-    !lower
-        .split_whitespace()
-        .any(|token| DISQUALIFIERS.contains(&token))
+    !lower.split_whitespace().any(|token| {
+        AUTHOR_DISQUALIFIERS.contains(&token)
+            || words
+                .author_disqualifiers
+                .iter()
+                .any(|word| word.to_lowercase() == token)
+    })
+}
+
+/// Split a byline into the names of its authors.
+///
+/// A leading byline prefix ("By", "Von", ...) is dropped, then the names are
+/// split on commas and on the conjunctions in `AUTHOR_CONJUNCTIONS` ("and",
+/// "y", "und", "et", "、", ...). When any part does not look like a personal
+/// name, the whole byline is returned as a single author. Honorifics and
+/// generational suffixes are kept ("Dr. Jane Rivera", "John Smith, Jr."), while
+/// trailing credentials are removed ("Brenda Goodman, MA" becomes "Brenda Goodman").
+pub fn split_byline_authors(byline: &str, words: &BylineWords) -> Vec<String> {
+    let trimmed = trim_soft_space(byline.trim());
+    let names = words
+        .prefixes()
+        .find_map(|prefix| strip_byline_prefix(trimmed, prefix))
+        .or_else(|| strip_cjk_byline_marker(trimmed))
+        .unwrap_or(trimmed)
+        .trim();
+    if names.is_empty() {
+        return Vec::new();
+    }
+
+    let mut joined = names.replace(';', ",");
+    for conjunction in AUTHOR_CONJUNCTIONS {
+        joined = joined.replace(conjunction, ",");
+    }
+
+    // Credentials after a comma ("Jane Doe, MD, PhD") belong to the name before them
+    let mut parts: Vec<String> = Vec::new();
+    for part in joined.split(',').map(|part| trim_soft_space(part.trim())) {
+        if part.is_empty() {
+            continue;
+        }
+        match parts.last_mut() {
+            Some(previous) if is_name_affix(part) => {
+                previous.push_str(", ");
+                previous.push_str(part);
+            }
+            _ => parts.push(part.to_string()),
+        }
+    }
+
+    if parts.len() > 1 && parts.iter().all(|part| looks_like_author_name(part, words)) {
+        parts
+            .iter()
+            .map(|part| strip_name_credentials(part).to_string())
+            .collect()
+    } else {
+        vec![strip_name_credentials(names).to_string()]
+    }
+}
+
+/// Whether a token is an honorific such as "Dr." or "Prof."
+fn is_name_honorific(token: &str) -> bool {
+    let token = token.trim_end_matches('.').to_lowercase();
+    NAME_HONORIFICS.contains(&token.as_str())
+}
+
+/// Whether a token is a lowercase surname particle ("von", "wa", "al-")
+fn is_name_particle(token: &str) -> bool {
+    let token = token.to_lowercase();
+    let token = token.split('-').next().unwrap_or_default();
+    NAME_PARTICLES.contains(&token)
+}
+
+/// Whether a token is a credential ("PhD", "M.D.") or a generational suffix ("Jr.")
+fn is_name_affix(token: &str) -> bool {
+    let token: String = token.chars().filter(|&c| c != '.').collect();
+    NAME_CREDENTIALS.contains(&token.as_str())
+        || NAME_SUFFIXES.contains(&token.to_lowercase().as_str())
+}
+
+/// Strip trailing credentials from a name ("Jane Doe, MD, PhD" becomes "Jane Doe").
+///
+/// Generational suffixes such as "Jr." or "III" are part of the name and kept.
+fn strip_name_credentials(name: &str) -> &str {
+    let mut name = name.trim();
+    while let Some((head, last)) = name.rsplit_once(|c: char| c == ',' || c.is_whitespace()) {
+        let head = head.trim_end_matches(|c: char| c == ',' || c.is_whitespace());
+        let credential: String = last.chars().filter(|&c| c != '.').collect();
+        if head.is_empty() || !NAME_CREDENTIALS.contains(&credential.as_str()) {
+            break;
+        }
+        name = head;
+    }
+    name
+}
+
+/// Heuristic check for a Chinese, Japanese or Korean personal name.
+///
+/// These names are written without spaces ("王小明", "田中太郎", "김지수"), so
+/// two to four Han, kana or Hangul characters are accepted as a name.
+fn looks_like_cjk_author_name(text: &str) -> bool {
+    (2..=4).contains(&text.chars().count()) && text.chars().all(is_cjk_char)
 }
 
-fn contains_author_like_segment(text: &str) -> bool {
-    if looks_like_author_name(text) {
+/// Whether a character is a Han ideograph, kana or Hangul syllable
+fn is_cjk_char(ch: char) -> bool {
+    matches!(ch,
+        '\u{3040}'..='\u{30ff}' // Hiragana, Katakana
+        | '\u{3400}'..='\u{4dbf}' // CJK Extension A
+        | '\u{4e00}'..='\u{9fff}' // CJK Unified Ideographs
+        | '\u{ac00}'..='\u{d7af}' // Hangul Syllables
+    )
+}
+
+/// Whether most letters and digits in `text` are Chinese, Japanese or Korean
+pub(crate) fn is_mostly_cjk(text: &str) -> bool {
+    if text.is_ascii() {
+        return false;
+    }
+    let (mut cjk, mut alphanumeric) = (0, 0);
+    for ch in text.chars().filter(|ch| ch.is_alphanumeric()) {
+        alphanumeric += 1;
+        if is_cjk_char(ch) {
+            cjk += 1;
+        }
+    }
+    cjk * 2 > alphanumeric
+}
+
+/// Scale a character-count threshold down by `cjk_factor` for mostly CJK text.
+///
+/// Chinese, Japanese and Korean say far more per character than space-delimited
+/// languages, so thresholds tuned for the latter would reject real CJK articles.
+pub(crate) fn cjk_length_threshold(threshold: usize, cjk_factor: f64, mostly_cjk: bool) -> usize {
+    if mostly_cjk {
+        (threshold as f64 * cjk_factor).round() as usize
+    } else {
+        threshold
+    }
+}
+
+/// Whether a character belongs to the Hebrew or Arabic script (including Persian
+/// and Urdu letters and presentation forms), combining marks included
+fn is_rtl_letter(ch: char) -> bool {
+    matches!(ch,
+        '\u{0591}'..='\u{05f4}' // Hebrew
+        | '\u{fb1d}'..='\u{fb4f}' // Hebrew presentation forms
+        | '\u{0610}'..='\u{061a}' | '\u{0620}'..='\u{065f}' | '\u{066e}'..='\u{06d3}' // Arabic
+        | '\u{06d5}'..='\u{06ff}' | '\u{0750}'..='\u{077f}' // Arabic, Arabic Supplement
+        | '\u{fb50}'..='\u{fdff}' | '\u{fe70}'..='\u{fefc}' // Arabic presentation forms
+        | '\u{200c}' // Zero-width non-joiner inside Persian words
+    )
+}
+
+/// Whether a byline remainder in Hebrew or Arabic script reads as a name.
+///
+/// These scripts have no case, so two to five words made only of their letters
+/// are required instead of a capitalized first letter.
+fn looks_like_rtl_name(text: &str) -> bool {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    (2..=5).contains(&words.len())
+        && words
+            .iter()
+            .all(|word| word.chars().all(is_rtl_letter) && word.chars().any(char::is_alphabetic))
+}
+
+/// Strip a CJK byline marker ("文/王小明", "記者 田中太郎", "王小明 记者") from a byline
+fn strip_cjk_byline_marker(text: &str) -> Option<&str> {
+    static MARKER_PREFIX_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^(?:(?:撰)?文\s*[/／]|(?:記者|记者|기자)\s*[:：/／]?)\s*").unwrap()
+    });
+    static MARKER_SUFFIX_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\s*(?:記者|记者|기자)$").unwrap());
+
+    let text = text.trim();
+    let name = if let Some(marker) = MARKER_PREFIX_REGEX.find(text) {
+        &text[marker.end()..]
+    } else {
+        let marker = MARKER_SUFFIX_REGEX.find(text)?;
+        &text[..marker.start()]
+    };
+    let name = name.trim();
+    (!name.is_empty()).then_some(name)
+}
+
+fn contains_author_like_segment(text: &str, words: &BylineWords) -> bool {
+    if looks_like_author_name(text, words) {
         return true;
     }
 
     for segment in split_candidate_segments(text) {
-        if looks_like_author_name(segment) {
+        if looks_like_author_name(segment, words) {
             return true;
         }
     }
@@ -222,6 +929,89 @@ fn split_candidate_segments(text: &str) -> Vec<&str> {
     segments
 }
 
+/// Numeric UTC offsets such as "+05:30", "-0800" or "UTC+5"
+static UTC_OFFSET_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:utc|gmt)[+−-]\d{1,2}(?::?\d{2})?\b|(?:^|[\s(])[+−-](?:0\d|1[0-4]):?[0-5]\d\b")
+        .unwrap()
+});
+
+/// Numeric dates such as "15.03.2023", "15/03/2023" or "2023-03-15"
+static NUMERIC_DATE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{1,2}[./-]\d{1,2}[./-](?:\d{4}|\d{2})|\d{4}-\d{1,2}-\d{1,2})\b").unwrap()
+});
+
+/// Whether lowercased text names a month, in English (also as an
+/// abbreviation) or spelled out in one of `LOCALIZED_MONTH_NAMES`
+fn mentions_month_name(lower: &str) -> bool {
+    MONTH_NAMES.iter().any(|month| lower.contains(month))
+        || lower
+            .split(|c: char| !c.is_alphabetic())
+            .any(|word| LOCALIZED_MONTH_NAMES.contains(&word))
+}
+
+/// Whether lowercased text names a day of the week from `WEEKDAY_NAMES`
+fn mentions_weekday_name(lower: &str) -> bool {
+    lower
+        .split(|c: char| !c.is_alphabetic())
+        .any(|word| WEEKDAY_NAMES.contains(&word))
+}
+
+/// Whether lowercased text holds a relative timestamp in one of the languages
+/// of `RELATIVE_TIME_WORDS` and `RELATIVE_TIME_MARKERS` ("hace 2 días", "gestern")
+fn mentions_localized_relative_time(lower: &str) -> bool {
+    let words: String = lower
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '\'' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    let words = format!(" {} ", normalize_whitespace(&words));
+    // Phrases in caseless scripts ("2 時間前") aren't separated by spaces
+    let mentions = |phrase: &&str| {
+        if phrase.chars().any(char::is_lowercase) {
+            words.contains(&format!(" {phrase} "))
+        } else {
+            lower.contains(phrase)
+        }
+    };
+    let has_digit = lower.chars().any(|c| c.is_ascii_digit());
+    RELATIVE_TIME_WORDS.iter().any(mentions)
+        || (has_digit && RELATIVE_TIME_MARKERS.iter().any(mentions))
+}
+
+/// Whether lowercased text holds a clock time followed by an AM/PM marker or a
+/// timezone from `TIMEZONE_TOKENS` ("3 pm", "8:02pm", "10.30 IST"), or a numeric
+/// UTC offset ("+05:30", "UTC+5"). Tokens must directly follow the time, so
+/// words such as German "ist" elsewhere in the text don't count.
+fn mentions_timezone(lower: &str) -> bool {
+    static CLOCK_TIME_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\d{1,2}(?:[:.h]\d{2}){0,2}$").unwrap());
+
+    if UTC_OFFSET_REGEX.is_match(lower) {
+        return true;
+    }
+
+    let mut after_time = false;
+    for token in lower.split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '(' | ')')) {
+        let token = token.trim_end_matches('.');
+        if token.is_empty() {
+            continue;
+        }
+        let (time, zone) = token.split_at(token.find(char::is_alphabetic).unwrap_or(token.len()));
+        let is_time = CLOCK_TIME_REGEX.is_match(time);
+        if TIMEZONE_TOKENS.contains(&zone) && (is_time || (time.is_empty() && after_time)) {
+            return true;
+        }
+        after_time = is_time && zone.is_empty();
+    }
+
+    false
+}
+
 fn looks_like_datetime_segment(segment: &str) -> bool {
     let lower = segment.trim().to_lowercase();
     if lower.is_empty() {
@@ -229,47 +1019,14 @@ fn looks_like_datetime_segment(segment: &str) -> bool {
     }
 
     let has_digit = lower.chars().any(|c| c.is_ascii_digit());
-    let mentions_month = [
-        "jan",
-        "feb",
-        "mar",
-        "apr",
-        "may",
-        "jun",
-        "jul",
-        "aug",
-        "sep",
-        "sept",
-        "oct",
-        "nov",
-        "dec",
-        "january",
-        "february",
-        "march",
-        "april",
-        "june",
-        "july",
-        "august",
-        "september",
-        "october",
-        "november",
-        "december",
-    ]
-    .iter()
-    .any(|month| lower.contains(month));
+    let mentions_month = mentions_month_name(&lower) || NUMERIC_DATE_REGEX.is_match(&lower);
 
     if lower.contains("ago")
         || lower.contains("updated")
         || lower.contains("yesterday")
         || lower.contains("today")
-        || (has_digit
-            && (lower.contains("am")
-                || lower.contains("pm")
-                || lower.contains("utc")
-                || lower.contains("gmt")
-                || lower.contains("est")
-                || lower.contains("pst")
-                || lower.contains("cet")))
+        || mentions_localized_relative_time(&lower)
+        || mentions_timezone(&lower)
         || (has_digit && mentions_month)
     {
         return true;
@@ -282,22 +1039,53 @@ fn looks_like_datetime_segment(segment: &str) -> bool {
     false
 }
 
+/// Reading-time estimates such as "5 min read", "3 minute read" or "Reading time: 4 minutes"
+static READING_TIME_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(?:\d+\s*(?:min|mins|minutes?)\.?(?:\s+read(?:ing)?(?:\s+time)?)?|read(?:ing)?\s+time:?\s*\d+\s*(?:min|mins|minutes?)\.?)$",
+    )
+    .unwrap()
+});
+
+/// Comment counts such as "651 comments" or "1.2k replies"
+static COMMENT_COUNT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\d[\d,.]*k?\s+(?:comments?|replies|reply|responses?)$").unwrap()
+});
+
+/// Whether a byline clause is page metadata: a datetime, a reading-time
+/// estimate or a comment count
+fn looks_like_trailing_metadata_segment(segment: &str) -> bool {
+    let segment = segment.trim();
+    looks_like_datetime_segment(segment)
+        || READING_TIME_REGEX.is_match(segment)
+        || COMMENT_COUNT_REGEX.is_match(segment)
+}
+
+/// Remove trailing clauses such as "| Updated 3 hours ago | 5 min read" one at
+/// a time, stopping at the first clause that is not page metadata.
 fn strip_trailing_datetime_clause<'a>(text: &'a str, allow_strip: bool) -> Cow<'a, str> {
     if !allow_strip {
         return Cow::Borrowed(text);
     }
 
-    let lower = text.to_lowercase();
-    for separator in [" | ", " - ", " – ", " — ", " · "] {
-        if let Some(idx) = lower.rfind(separator) {
-            let tail = lower[idx + separator.len()..].trim();
-            if looks_like_datetime_segment(tail) {
-                return Cow::Owned(text[..idx].trim_end().to_string());
+    let mut head = text;
+    'clauses: loop {
+        for separator in [" | ", " - ", " – ", " — ", " · "] {
+            if let Some(idx) = head.rfind(separator) {
+                if looks_like_trailing_metadata_segment(&head[idx + separator.len()..]) {
+                    head = head[..idx].trim_end();
+                    continue 'clauses;
+                }
             }
         }
+        break;
     }
 
-    Cow::Borrowed(text)
+    if head.len() == text.len() {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(head.to_string())
+    }
 }
 
 fn remove_timestamp_lines(text: &str) -> Option<String> {
@@ -333,42 +1121,18 @@ fn looks_like_live_timestamp_segment(segment: &str) -> bool {
         || lower.contains("update")
         || lower.contains("yesterday")
         || lower.contains("today")
+        || mentions_localized_relative_time(&lower)
     {
         return true;
     }
 
-    // Check if this is an absolute date (has a month name)
-    // Absolute dates like "March 11, 2015 3:46 PM" should be kept, not removed
-    let has_month = [
-        "jan",
-        "feb",
-        "mar",
-        "apr",
-        "may",
-        "jun",
-        "jul",
-        "aug",
-        "sep",
-        "sept",
-        "oct",
-        "nov",
-        "dec",
-        "january",
-        "february",
-        "march",
-        "april",
-        "june",
-        "july",
-        "august",
-        "september",
-        "october",
-        "november",
-        "december",
-    ]
-    .iter()
-    .any(|month| lower.contains(month));
-
-    if has_month {
+    // Check if this is an absolute date (has a month or weekday name or a numeric date)
+    // Absolute dates like "March 11, 2015 3:46 PM" or "Friday at 9:00" should be kept, not removed
+    let has_date = mentions_month_name(&lower)
+        || mentions_weekday_name(&lower)
+        || NUMERIC_DATE_REGEX.is_match(&lower);
+
+    if has_date {
         return false;
     }
 
@@ -380,120 +1144,195 @@ fn looks_like_live_timestamp_segment(segment: &str) -> bool {
     }
 
     // Match time-only indicators like "3 PM" or "14:30 UTC" (without dates)
-    if has_digit
-        && (lower.contains("am")
-            || lower.contains("pm")
-            || lower.contains("a.m")
-            || lower.contains("p.m")
-            || lower.contains("utc")
-            || lower.contains("gmt")
-            || lower.contains("est")
-            || lower.contains("pst")
-            || lower.contains("cet"))
-    {
+    if mentions_timezone(&lower) {
         return true;
     }
 
     false
 }
 
-pub(crate) fn looks_like_org_credit(text: &str) -> bool {
-    if contains_author_like_segment(text) {
+/// Whether a byline credits an organization rather than a person ("Our Foreign
+/// Staff", "Reuters"). Two words from `ORG_CREDIT_KEYWORDS` are needed, while a
+/// single word from `words.org_keywords` ("Redaktion") is enough.
+pub(crate) fn looks_like_org_credit(text: &str, words: &BylineWords) -> bool {
+    let normalized = normalize_whitespace(text).to_lowercase();
+    if normalized.is_empty() {
         return false;
     }
 
-    let normalized = normalize_whitespace(text).to_lowercase();
-    if normalized.is_empty() {
+    if !words.org_keywords.is_empty() {
+        let extra_keywords: Vec<String> = words
+            .org_keywords
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect();
+        if normalized
+            .split_whitespace()
+            .any(|token| extra_keywords.iter().any(|word| word == token))
+        {
+            return true;
+        }
+    }
+
+    if contains_author_like_segment(text, words) {
         return false;
     }
 
-    const EXACT_AGENCIES: [&str; 10] = [
-        "afp",
-        "ap",
-        "associated press",
-        "reuters",
-        "bloomberg",
-        "press association",
-        "kyodo",
-        "ansa",
-        "dpa",
-        "upi",
-    ];
-
-    if EXACT_AGENCIES.contains(&normalized.as_str()) {
+    if NEWS_AGENCIES.contains(&normalized.as_str()) {
         return true;
     }
 
-    let keywords = [
-        "staff",
-        "news",
-        "newsroom",
-        "desk",
-        "team",
-        "press",
-        "service",
-        "bureau",
-        "foreign",
-        "reporter",
-        "reporters",
-        "developers",
-        "android",
-        "buzzfeed",
-        "wire",
-        "agency",
-        "agencies",
-        "telegraph",
-        "our",
-        "editors",
-        "view",
-    ];
-
     let hits = normalized
         .split_whitespace()
-        .filter(|token| keywords.contains(token))
+        .filter(|token| ORG_CREDIT_KEYWORDS.contains(token))
         .count();
 
     hits >= 2
 }
 
+/// Returns the byline when it credits a wire agency on its own ("Reuters",
+/// "By Associated Press") or ends with an agency tag ("Staff and wire (AP)").
+pub(crate) fn agency_byline<'a>(text: &'a str, words: &BylineWords) -> Option<&'a str> {
+    let trimmed = trim_decorative_symbols(text);
+    let credit = words
+        .prefixes()
+        .find_map(|prefix| strip_byline_prefix(trimmed, prefix))
+        .unwrap_or(trimmed);
+    let normalized = normalize_whitespace(credit).trim().to_lowercase();
+    let is_agency = NEWS_AGENCIES.contains(&normalized.as_str())
+        || normalized
+            .strip_suffix(')')
+            .and_then(|rest| rest.rsplit_once('('))
+            .is_some_and(|(_, tag)| NEWS_AGENCIES.contains(&tag.trim()));
+    (is_agency && !trimmed.contains('\n')).then_some(trimmed)
+}
+
+/// Words allowed in lowercase inside a credited name ("Jane Doe for The Times")
+const CREDIT_CONNECTORS: &[&str] = &["and", "for", "of", "via", "the", "&"];
+
+/// Returns true if text reads like the source named in a photo credit.
+///
+/// The text may list several sources separated by `/` or `,`. Each must be a
+/// known agency or newsroom, or a short capitalized name such as "Getty Images".
+pub(crate) fn looks_like_image_credit(text: &str) -> bool {
+    let text = text.trim().trim_end_matches('.');
+    if text.is_empty() || text.chars().count() > 80 {
+        return false;
+    }
+
+    text.split(['/', ',', ';'])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .all(|part| {
+            let words: Vec<&str> = part.split_whitespace().collect();
+            looks_like_org_credit(part, &BylineWords::default())
+                || (words.len() <= 8
+                    && words.iter().all(|word| {
+                        word.chars().next().is_some_and(|c| c.is_uppercase())
+                            || CREDIT_CONNECTORS.contains(&word.to_lowercase().as_str())
+                    }))
+        })
+}
+
+/// Whether text is a navigation menu rather than prose or a byline, either
+/// enclosed (`[Home] [About]`, `(Home) (About) (Contact)`) or separated
+/// (`Home · About · Contact`), see `looks_like_bracket_menu` and
+/// `looks_like_delimited_menu`.
+pub fn looks_like_menu(text: &str) -> bool {
+    looks_like_bracket_menu(text) || looks_like_delimited_menu(text)
+}
+
+/// Whether text is a run of enclosed menu items such as `[Home] [About]`.
+///
+/// Square brackets need two items of any text; parentheses and angle quotes
+/// need three short capitalized single words. Anything after the items must be
+/// empty or numeric.
 pub fn looks_like_bracket_menu(text: &str) -> bool {
     let mut remainder = text.trim();
-    if !remainder.starts_with('[') {
+    let Some(&(open, close)) = MENU_ITEM_ENCLOSURES
+        .iter()
+        .find(|(open, _)| remainder.starts_with(*open))
+    else {
         return false;
-    }
+    };
+    let square = open == '[';
 
     let mut matched = 0;
-    while remainder.starts_with('[') {
-        if let Some(end) = remainder.find(']') {
-            let token = remainder[1..end].trim();
-            if token.is_empty() {
+    while remainder.starts_with(open) {
+        if let Some(end) = remainder.find(close) {
+            let token = remainder[open.len_utf8()..end].trim();
+            if token.is_empty() || (!square && !is_menu_item_token(token)) {
                 return false;
             }
             matched += 1;
-            remainder = remainder[end + 1..].trim_start();
+            remainder = remainder[end + close.len_utf8()..].trim_start();
         } else {
             break;
         }
     }
 
-    if matched < 2 {
+    if matched < if square { 2 } else { 3 } {
         return false;
     }
 
     let remainder_trimmed = remainder.trim();
     remainder_trimmed.is_empty()
-        || remainder_trimmed.starts_with("Versions")
+        || (square && remainder_trimmed.starts_with("Versions"))
         || remainder_trimmed
             .chars()
             .all(|c| c.is_ascii_digit() || c.is_whitespace())
 }
 
+/// Whether text is three or more menu items separated consistently by one of
+/// `MENU_SEPARATORS` (`Home · About · Contact`).
+///
+/// Items must be short capitalized single words, at least half of them from
+/// `MENU_WORDS`, so surname-only bylines ("Smith · Jones · Brown") are kept.
+/// Anything after the last item must be numeric.
+pub fn looks_like_delimited_menu(text: &str) -> bool {
+    let text = text.trim();
+    let mut separators = MENU_SEPARATORS.iter().filter(|sep| text.contains(**sep));
+    let (Some(&separator), None) = (separators.next(), separators.next()) else {
+        return false;
+    };
+
+    let mut items: Vec<&str> = text.split(separator).map(str::trim).collect();
+    let last = items.pop().unwrap_or_default();
+    let (last_item, trailing) = last.split_once(char::is_whitespace).unwrap_or((last, ""));
+    items.push(last_item);
+
+    items.len() >= 3
+        && items.iter().all(|item| is_menu_item_token(item))
+        && items
+            .iter()
+            .filter(|item| MENU_WORDS.contains(&item.to_lowercase().as_str()))
+            .count()
+            * 2
+            >= items.len()
+        && trailing
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace())
+}
+
+/// Whether a menu item is a short capitalized single word ("Home", "Sign-in")
+fn is_menu_item_token(token: &str) -> bool {
+    token.chars().count() <= 20
+        && token.starts_with(char::is_uppercase)
+        && token
+            .chars()
+            .all(|c| c.is_alphabetic() || matches!(c, '-' | '&'))
+}
+
 pub(crate) fn looks_like_dateline(text: &str) -> bool {
     let trimmed = text.trim();
     if trimmed.is_empty() || trimmed.len() > 40 {
         return false;
     }
 
+    if looks_like_mixed_case_dateline(trimmed) {
+        return true;
+    }
+
     let stripped = trimmed
         .trim_start_matches(['-', '–', '—'])
         .trim_end_matches(['-', '–', '—']);
@@ -501,13 +1340,18 @@ pub(crate) fn looks_like_dateline(text: &str) -> bool {
         return false;
     }
 
+    if looks_like_byline(stripped, &BylineWords::default()) {
+        return false;
+    }
+
     let mut has_letters = false;
     for word in stripped.split(|c: char| c.is_whitespace() || c == ',' || c == '—' || c == '-') {
         let word = word.trim_matches(|c: char| !c.is_alphanumeric());
         if word.is_empty() {
             continue;
         }
-        if word.chars().any(|c| c.is_lowercase()) {
+        // Caseless scripts such as CJK can't be told apart from body text by case
+        if word.chars().any(|c| c.is_alphabetic() && !c.is_uppercase()) {
             return false;
         }
         if word.chars().any(|c| c.is_alphabetic()) {
@@ -518,11 +1362,82 @@ pub(crate) fn looks_like_dateline(text: &str) -> bool {
     has_letters
 }
 
-/// Check if text looks like a navigation menu (multiple pipes, location pairs, etc.)
-fn looks_like_navigation_menu(text: &str) -> bool {
-    let pipe_count = text.chars().filter(|&c| c == '|').count();
-    if pipe_count >= 2 {
-        return true;
+/// Place followed by a parenthesized wire agency ("San Francisco (Reuters)")
+static AGENCY_DATELINE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.*?)\s*\(([^()]+)\)$").unwrap());
+
+/// Whether text is a mixed-case dateline ending in a dash, either a place with
+/// its region ("Cairo, Egypt —") or a place crediting a wire agency from
+/// `NEWS_AGENCIES` ("San Francisco (Reuters) –").
+fn looks_like_mixed_case_dateline(text: &str) -> bool {
+    let Some(clause) = text.strip_suffix(['—', '–', '-']) else {
+        return false;
+    };
+    let clause = clause.trim_end();
+    let (place, has_agency) = match AGENCY_DATELINE_REGEX.captures(clause) {
+        Some(caps) if NEWS_AGENCIES.contains(&caps[2].trim().to_lowercase().as_str()) => {
+            (caps.get(1).map_or("", |m| m.as_str()), true)
+        }
+        Some(_) => return false,
+        None => (clause, false),
+    };
+    // A lone capitalized word before a dash is as likely a sentence opening
+    // with a city name ("Paris — the city of light — ...")
+    if !has_agency && !place.contains(',') {
+        return false;
+    }
+
+    let words: Vec<&str> = place
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .collect();
+    let is_place_word = |word: &&str| {
+        word.starts_with(char::is_uppercase)
+            && word
+                .chars()
+                .all(|c| c.is_alphabetic() || matches!(c, '.' | '\'' | '’' | '-'))
+    };
+    (1..=5).contains(&words.len())
+        && words.iter().all(is_place_word)
+        && !BylineWords::default()
+            .prefixes()
+            .any(|prefix| words[0].eq_ignore_ascii_case(prefix))
+}
+
+/// Byte offset where the text after a leading dateline starts, for a lede such
+/// as "CAIRO — Egyptian officials said..." or "San Francisco (Reuters) - Shares...".
+///
+/// The text after the dash must open a new sentence, and a dash later in that
+/// sentence marks the first one as stylistic ("London — not for the first time — ...").
+pub(crate) fn split_leading_dateline(text: &str) -> Option<usize> {
+    let lead = text.trim_start();
+    let (idx, dash) = lead
+        .char_indices()
+        .take_while(|(idx, _)| *idx <= 45)
+        .find(|&(idx, c)| {
+            matches!(c, '—' | '–')
+                || (c == '-' && lead[..idx].ends_with(' ') && lead[idx + 1..].starts_with(' '))
+        })?;
+    if !looks_like_dateline(&lead[..idx + dash.len_utf8()]) {
+        return None;
+    }
+
+    let rest = lead[idx + dash.len_utf8()..].trim_start();
+    let first_sentence = rest.split(['.', '!', '?']).next().unwrap_or(rest);
+    let opens_sentence = rest.starts_with(|c: char| {
+        c.is_uppercase() || c.is_ascii_digit() || matches!(c, '"' | '“' | '‘' | '\'')
+    });
+    if !opens_sentence || first_sentence.contains(['—', '–']) {
+        return None;
+    }
+    Some(text.len() - rest.len())
+}
+
+/// Check if text looks like a navigation menu (multiple pipes, location pairs, etc.)
+fn looks_like_navigation_menu(text: &str) -> bool {
+    let pipe_count = text.chars().filter(|&c| c == '|').count();
+    if pipe_count >= 2 {
+        return true;
     }
 
     // Check for location-pair pattern (e.g., "HOLLYWOOD\nNEW YORK")
@@ -571,15 +1486,127 @@ fn looks_like_navigation_menu(text: &str) -> bool {
     false
 }
 
-/// Normalize byline text by trimming whitespace and removing dangling separators.
+/// Result of cleaning byline text
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CleanBylineOutcome {
+    /// The cleaned byline
     Accepted(String),
+    /// The text credits an organization ("Our Foreign Staff", "Promoted by")
     DroppedOrgCredit,
+    /// The text is not a byline (a timestamp, a menu, a social handle, ...)
     Dropped,
 }
 
-pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
-    let trimmed = trim_soft_space(text.trim());
+bitflags::bitflags! {
+    /// What cleaning a byline found or removed, see `clean_byline_text_detailed`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct BylineSignals: u8 {
+        /// The byline opens with a prefix such as "By" or "Par"
+        const BY_PREFIX = 0x1;
+        /// A part of the byline reads as a person's name
+        const AUTHOR_SEGMENT = 0x2;
+        /// A trailing date clause or timestamp line was removed
+        const TIMESTAMP_STRIPPED = 0x4;
+        /// A line holding a social handle or profile link was removed
+        const SOCIAL_LINE_REMOVED = 0x8;
+        /// A job title after the name was removed
+        const ROLE_STRIPPED = 0x10;
+        /// Words from `ORG_CREDIT_KEYWORDS` or the extra org keywords appear
+        const ORG_KEYWORDS = 0x20;
+        /// The text reads as a navigation menu
+        const NAVIGATION_MENU = 0x40;
+    }
+}
+
+/// Outcome of cleaning a byline together with how much it can be trusted
+#[derive(Debug, Clone, PartialEq)]
+pub struct BylineDetails {
+    /// The cleaned byline, or why it was dropped
+    pub outcome: CleanBylineOutcome,
+    /// Confidence in [0, 1] that the accepted text names the author; 0 when dropped
+    pub confidence: f32,
+    /// What cleaning found or removed
+    pub signals: BylineSignals,
+    /// Number of organization keywords in the text
+    pub org_keyword_hits: usize,
+}
+
+/// Clean byline text like `clean_byline_text`, reporting what was found and a
+/// confidence score.
+///
+/// An accepted "By Jane Doe" scores higher than a bare "Jane Doe", and each
+/// organization keyword lowers the score.
+///
+/// # Example
+///
+/// ```rust
+/// use readabilityrs::{clean_byline_text_detailed, BylineSignals, BylineWords};
+///
+/// let prefixed = clean_byline_text_detailed("By Jane Doe", &BylineWords::default());
+/// let bare = clean_byline_text_detailed("Jane Doe", &BylineWords::default());
+/// assert!(prefixed.signals.contains(BylineSignals::BY_PREFIX));
+/// assert!(prefixed.confidence > bare.confidence);
+/// ```
+pub fn clean_byline_text_detailed(text: &str, words: &BylineWords) -> BylineDetails {
+    let mut signals = BylineSignals::empty();
+    let outcome = clean_byline(text, words, &mut signals);
+    let org_keyword_hits = count_org_keywords(text, words);
+    if org_keyword_hits > 0 {
+        signals |= BylineSignals::ORG_KEYWORDS;
+    }
+
+    let confidence = match outcome {
+        CleanBylineOutcome::Accepted(_) => {
+            let mut confidence: f32 = 0.4;
+            if signals.contains(BylineSignals::BY_PREFIX) {
+                confidence += 0.3;
+            }
+            if signals.contains(BylineSignals::AUTHOR_SEGMENT) {
+                confidence += 0.2;
+            }
+            if signals.contains(BylineSignals::ROLE_STRIPPED) {
+                confidence += 0.05;
+            }
+            (confidence - 0.15 * org_keyword_hits as f32).clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    };
+
+    BylineDetails {
+        outcome,
+        confidence,
+        signals,
+        org_keyword_hits,
+    }
+}
+
+/// Count words from `ORG_CREDIT_KEYWORDS` and `words.org_keywords` in text
+fn count_org_keywords(text: &str, words: &BylineWords) -> usize {
+    normalize_whitespace(text)
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| {
+            ORG_CREDIT_KEYWORDS.contains(token)
+                || words
+                    .org_keywords
+                    .iter()
+                    .any(|word| word.to_lowercase() == *token)
+        })
+        .count()
+}
+
+pub fn clean_byline_text_with_reason(text: &str, words: &BylineWords) -> CleanBylineOutcome {
+    clean_byline(text, words, &mut BylineSignals::empty())
+}
+
+fn clean_byline(
+    text: &str,
+    words: &BylineWords,
+    signals: &mut BylineSignals,
+) -> CleanBylineOutcome {
+    // Entity-encoded bylines ("John&nbsp;Smith") only read as names once decoded
+    let decoded = replace_soft_spaces(&unescape_html_entities(text)).into_owned();
+    let trimmed = trim_decorative_symbols(&decoded);
     if trimmed.is_empty() {
         return CleanBylineOutcome::Dropped;
     }
@@ -599,11 +1626,44 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
     let mut canonical = cleaned.replace("\r\n", "\n");
     canonical = collapse_blank_lines_preserve_indent(&canonical);
 
-    let has_author_segment = contains_author_like_segment(&canonical);
-    canonical = strip_trailing_datetime_clause(&canonical, has_author_segment).into_owned();
+    // Photo and illustration credits name someone other than the author
+    if let Some(filtered) = remove_contributor_credits(&canonical) {
+        if filtered.trim().is_empty() {
+            return CleanBylineOutcome::Dropped;
+        }
+        canonical = filtered;
+    }
+
+    // Review and editing credits may follow the author, but never stand in for one
+    if is_only_contributor_credits(&canonical) {
+        return CleanBylineOutcome::Dropped;
+    }
+
+    if let Some(filtered) = remove_email_addresses(&canonical) {
+        if filtered.trim().is_empty() {
+            return CleanBylineOutcome::Dropped;
+        }
+        canonical = filtered;
+    }
+
+    // Only a name after the prefix makes one; "By clicking Submit" opens with
+    // the same word
+    if looks_like_byline(&canonical, words) {
+        *signals |= BylineSignals::BY_PREFIX;
+    }
+
+    let has_author_segment = contains_author_like_segment(&canonical, words);
+    if has_author_segment {
+        *signals |= BylineSignals::AUTHOR_SEGMENT;
+    }
+    if let Cow::Owned(stripped) = strip_trailing_datetime_clause(&canonical, has_author_segment) {
+        *signals |= BylineSignals::TIMESTAMP_STRIPPED;
+        canonical = stripped;
+    }
 
     if has_author_segment {
         if let Some(filtered) = remove_timestamp_lines(&canonical) {
+            *signals |= BylineSignals::TIMESTAMP_STRIPPED;
             if filtered.trim().is_empty() {
                 return CleanBylineOutcome::Dropped;
             }
@@ -612,6 +1672,7 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
     }
 
     if let Some(filtered) = remove_social_handle_lines(&canonical) {
+        *signals |= BylineSignals::SOCIAL_LINE_REMOVED;
         if filtered.trim().is_empty() {
             return CleanBylineOutcome::Dropped;
         }
@@ -619,11 +1680,28 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
     }
 
     let trimmed_lower = canonical.trim_start().to_lowercase();
-    if trimmed_lower.starts_with("posted by") || trimmed_lower.starts_with("promoted by") {
+    if trimmed_lower.starts_with("promoted by") {
         return CleanBylineOutcome::DroppedOrgCredit;
     }
 
-    if looks_like_navigation_menu(&canonical) {
+    // Blog attributions ("Posted by Jane Doe") keep just the name; a platform
+    // or team after "posted by" is an organization credit
+    if let Some((label, remainder)) = ATTRIBUTION_LABELS
+        .iter()
+        .find_map(|label| Some((*label, strip_byline_prefix(canonical.trim_start(), label)?)))
+    {
+        if !remainder.contains('\n')
+            && looks_like_author_name(remainder, words)
+            && !looks_like_org_credit(remainder, words)
+        {
+            canonical = remainder.to_string();
+        } else if matches!(label, "posted by" | "guest post by") {
+            return CleanBylineOutcome::DroppedOrgCredit;
+        }
+    }
+
+    if looks_like_navigation_menu(&canonical) || looks_like_menu(&canonical) {
+        *signals |= BylineSignals::NAVIGATION_MENU;
         return CleanBylineOutcome::Dropped;
     }
 
@@ -640,20 +1718,248 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
         return CleanBylineOutcome::Dropped;
     }
 
-    if looks_like_org_credit(&canonical) {
+    if let Some(filtered) = strip_inner_decorative_symbols(&canonical, words) {
+        canonical = filtered;
+    }
+
+    if let Some(name) = strip_byline_role(&canonical, words) {
+        *signals |= BylineSignals::ROLE_STRIPPED;
+        canonical = name.to_string();
+    }
+
+    if looks_like_org_credit(&canonical, words) {
         return CleanBylineOutcome::DroppedOrgCredit;
     }
 
+    // Filtering lines or clauses can leave a symbol from the middle at an edge
+    let stripped = trim_decorative_symbols(&canonical);
+    if stripped.len() != canonical.trim().len() {
+        canonical = stripped.to_string();
+    }
+
     CleanBylineOutcome::Accepted(canonical)
 }
 
-pub fn clean_byline_text(text: &str) -> Option<String> {
-    match clean_byline_text_with_reason(text) {
+/// Labels attributing a post to its author, stripped when a name follows
+const ATTRIBUTION_LABELS: &[&str] = &["guest post by", "posted by", "written by", "contributed by"];
+
+/// "Photography by <name>" style credits for work other than writing
+static CONTRIBUTOR_CREDIT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(photography|photographs?|photos?|illustrations?|illustrated|videos?|graphics?|(?:medically\s+)?reviewed|edited|fact[-\s]?checked|updated)\s+by\s*:?\s+(.+)$",
+    )
+    .unwrap()
+});
+
+/// Parse a credit such as "Photography by Ansel Adams", "Video by AP" or
+/// "Medically reviewed by Dr. Sam Lee".
+///
+/// The credited name must read like a person or agency (see `looks_like_image_credit`).
+pub(crate) fn parse_contributor_credit(text: &str) -> Option<Contributor> {
+    let caps = CONTRIBUTOR_CREDIT_REGEX.captures(trim_soft_space(text))?;
+    let role = match caps[1].to_lowercase().as_str() {
+        label if label.starts_with("photo") => ContributorRole::Photography,
+        label if label.starts_with("illustr") => ContributorRole::Illustration,
+        label if label.starts_with("video") => ContributorRole::Video,
+        label if label.contains("review") => ContributorRole::Review,
+        label if label.starts_with("edit") => ContributorRole::Editing,
+        label if label.starts_with("fact") => ContributorRole::FactCheck,
+        label if label.starts_with("updated") => ContributorRole::Update,
+        _ => ContributorRole::Graphics,
+    };
+    let name = caps[2].trim_end_matches(['.', ',', ';']).trim();
+    looks_like_image_credit(name).then(|| Contributor {
+        role,
+        name: name.to_string(),
+    })
+}
+
+/// Whether a credit is for editorial work (reviewing, editing, fact-checking)
+/// rather than for visuals
+fn is_editorial_role(role: ContributorRole) -> bool {
+    matches!(
+        role,
+        ContributorRole::Review
+            | ContributorRole::Editing
+            | ContributorRole::FactCheck
+            | ContributorRole::Update
+    )
+}
+
+/// Whether every line or clause of a byline is a contributor credit
+fn is_only_contributor_credits(text: &str) -> bool {
+    text.split(['\n', '|', '·'])
+        .map(str::trim)
+        .filter(|clause| !clause.is_empty())
+        .all(|clause| parse_contributor_credit(clause).is_some())
+}
+
+/// Remove visual contributor credits ("Photos by Ansel Adams") from byline text,
+/// as whole lines or as clauses after " | " or " · "
+fn remove_contributor_credits(text: &str) -> Option<String> {
+    let mut changed = false;
+    let lines: Vec<String> = text
+        .split('\n')
+        .filter_map(|line| {
+            let clauses: Vec<&str> = line
+                .split(" | ")
+                .flat_map(|clause| clause.split(" · "))
+                .collect();
+            let kept: Vec<&str> = clauses
+                .iter()
+                .copied()
+                .filter(|clause| {
+                    parse_contributor_credit(clause)
+                        .is_none_or(|credit| is_editorial_role(credit.role))
+                })
+                .collect();
+            if kept.len() == clauses.len() {
+                return Some(line.to_string());
+            }
+            changed = true;
+            (!kept.is_empty()).then(|| kept.join(" | "))
+        })
+        .collect();
+    changed.then(|| lines.join("\n"))
+}
+
+/// Strip a job title after the last comma of a one-line byline ("By Jane Doe,
+/// Science Editor" becomes "By Jane Doe"), returning the name part.
+///
+/// The title must be a few words including one from `AUTHOR_DISQUALIFIERS`
+/// or `words.author_disqualifiers`, and the part before it must be a name.
+fn strip_byline_role<'a>(byline: &'a str, words: &BylineWords) -> Option<&'a str> {
+    if byline.contains('\n') {
+        return None;
+    }
+    let (name, role) = byline.rsplit_once(',')?;
+    let role = role.trim().to_lowercase();
+    let role_words: Vec<&str> = role
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .collect();
+    let is_role = (1..=5).contains(&role_words.len())
+        && !role.chars().any(|c| c.is_ascii_digit())
+        && !AUTHOR_CONJUNCTIONS
+            .iter()
+            .any(|conjunction| format!(" {role} ").contains(conjunction))
+        && role_words.iter().any(|word| {
+            AUTHOR_DISQUALIFIERS.contains(word)
+                || words
+                    .author_disqualifiers
+                    .iter()
+                    .any(|extra| extra.to_lowercase() == *word)
+        });
+    let name = name.trim_end();
+    (is_role && looks_like_author_name(name, words)).then_some(name)
+}
+
+/// Whether a character is an emoji or decorative pictograph ("✍️", "🖋️", "★"),
+/// including the variation selectors and joiners that combine them
+fn is_decorative_symbol(ch: char) -> bool {
+    matches!(ch,
+        '\u{2190}'..='\u{21ff}' // Arrows
+        | '\u{2300}'..='\u{23ff}' // Miscellaneous Technical
+        | '\u{25a0}'..='\u{27bf}' // Geometric Shapes, Miscellaneous Symbols, Dingbats
+        | '\u{2b00}'..='\u{2bff}' // Miscellaneous Symbols and Arrows
+        | '\u{1f000}'..='\u{1faff}' // Emoji and pictographs
+        | '\u{200d}' | '\u{20e3}' | '\u{fe0e}' | '\u{fe0f}' // Joiner, keycap, variation selectors
+        | '\u{e0020}'..='\u{e007f}' // Tag characters of flag sequences
+    )
+}
+
+/// Drop emoji standing between the words of a byline ("Jane Doe 🖋️ Senior
+/// Writer"). A job title after the last one is set off with a comma instead,
+/// so `strip_byline_role` removes it.
+fn strip_inner_decorative_symbols(text: &str, words: &BylineWords) -> Option<String> {
+    let is_symbols = |token: &str| token.chars().all(is_decorative_symbol);
+    if !text.split_whitespace().any(is_symbols) {
+        return None;
+    }
+
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| {
+            if !line.split_whitespace().any(is_symbols) {
+                return line.to_string();
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let joined = |tokens: &[&str]| {
+                tokens
+                    .iter()
+                    .copied()
+                    .filter(|token| !is_symbols(token))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let last = tokens
+                .iter()
+                .rposition(|token| is_symbols(token))
+                .unwrap_or(tokens.len());
+            let before = joined(&tokens[..last]);
+            let after = joined(tokens.get(last + 1..).unwrap_or_default());
+            if before.is_empty() || after.is_empty() {
+                return format!("{before}{after}");
+            }
+            let with_role = format!("{before}, {after}");
+            if strip_byline_role(&with_role, words).is_some() {
+                with_role
+            } else {
+                format!("{before} {after}")
+            }
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Trim whitespace, invisible characters and emoji from both ends of a byline
+fn trim_decorative_symbols(text: &str) -> &str {
+    text.trim_matches(|c: char| is_soft_space(c) || is_decorative_symbol(c))
+}
+
+/// Clean a byline candidate, returning `None` when it isn't a byline.
+/// With `title_case_caps`, all-caps bylines are title-cased.
+pub fn clean_byline_text(text: &str, words: &BylineWords, title_case_caps: bool) -> Option<String> {
+    match clean_byline_text_with_reason(text, words) {
+        CleanBylineOutcome::Accepted(value) if title_case_caps => {
+            Some(title_case_caps_byline(&value))
+        }
         CleanBylineOutcome::Accepted(value) => Some(value),
         _ => None,
     }
 }
 
+/// Whether block text only repeats the byline, optionally after a prefix
+/// ("By") and followed by a date or social handle ("By Jane Doe, March 3, 2024").
+///
+/// Text naming the author inside a sentence ("Jane Doe said ...") does not match.
+pub(crate) fn is_byline_duplicate(text: &str, byline: &str, words: &BylineWords) -> bool {
+    let text = normalize_whitespace(text).trim().to_lowercase();
+    let byline = normalize_whitespace(byline).trim().to_lowercase();
+    if byline.is_empty() || text.len() > byline.len() + 60 {
+        return false;
+    }
+    let name = words
+        .prefixes()
+        .find_map(|prefix| strip_byline_prefix(&byline, prefix))
+        .unwrap_or(&byline);
+    let Some(idx) = text.find(name) else {
+        return false;
+    };
+
+    let is_separator =
+        |c: char| c.is_whitespace() || matches!(c, ',' | '|' | '·' | '-' | '–' | '—' | ':');
+    let before = text[..idx].trim_matches(is_separator);
+    let after = text[idx + name.len()..].trim_matches(is_separator);
+    (before.is_empty()
+        || words
+            .prefixes()
+            .any(|prefix| before == prefix.trim().to_lowercase()))
+        && (after.is_empty()
+            || looks_like_datetime_segment(after)
+            || looks_like_social_handle(after))
+}
+
 pub fn is_byline_redundant_with_site_name(byline: &str, site_name: &str) -> bool {
     let normalized_byline = normalize_whitespace(byline).to_lowercase();
     if normalized_byline.len() < 3 {
@@ -733,60 +2039,596 @@ mod tests {
 
     #[test]
     fn test_unescape_html_entities() {
+        assert!(matches!(
+            unescape_html_entities("No entities here"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            unescape_html_entities("AT&T &unknown; & more"),
+            Cow::Borrowed(_)
+        ));
         assert_eq!(unescape_html_entities("&lt;div&gt;"), "<div>");
         assert_eq!(unescape_html_entities("A &amp; B"), "A & B");
+        assert_eq!(unescape_html_entities("1990&mdash;2000"), "1990—2000");
+        assert_eq!(unescape_html_entities("New&nbsp;York"), "New\u{a0}York");
+        assert_eq!(unescape_html_entities("&NotEqualTilde;"), "\u{2242}\u{338}");
+        assert_eq!(
+            unescape_html_entities("&copy 2024 &eacute;t&eacute;"),
+            "© 2024 été"
+        );
+        assert_eq!(
+            unescape_html_entities("&#8217;&#x2014;"),
+            "\u{2019}\u{2014}"
+        );
+        assert_eq!(
+            unescape_html_entities("Caf&#233 &#x201C;ok&#x201D"),
+            "Café “ok”"
+        );
+        assert_eq!(unescape_html_entities("&#233;t&#233"), "été");
+        assert_eq!(
+            unescape_html_entities("it&#146;s &#150; &#128;5"),
+            "it’s – €5"
+        );
+        assert_eq!(unescape_html_entities("&#0; &#xD800;"), "\u{fffd} \u{fffd}");
+        assert_eq!(unescape_html_entities("& &# &#x &#;"), "& &# &#x &#;");
+    }
+
+    #[test]
+    fn test_unescape_html_entities_invalid_code_points() {
+        for reference in [
+            "&#0;",
+            "&#x0;",
+            "&#xD800;",
+            "&#xDFFF;",
+            "&#xFFFE;",
+            "&#xFFFF;",
+            "&#x10FFFF;",
+            "&#xFDD0;",
+            "&#1114112;",
+            "&#99999999999999999999;",
+            "&#x110000",
+            "&#1;",
+            "&#x7F;",
+            "&#x81;",
+            "&#13;",
+        ] {
+            assert_eq!(unescape_html_entities(reference), "\u{fffd}", "{reference}");
+        }
+        assert_eq!(unescape_html_entities("a&#9;b&#10;c"), "a\tb\nc");
+        assert_eq!(unescape_html_entities("&#x1F600;&#65;"), "\u{1f600}A");
+        assert_eq!(
+            unescape_html_entities("&unknown; &copyright ?a=1&amp=2 & more"),
+            "&unknown; &copyright ?a=1&amp=2 & more"
+        );
+    }
+
+    #[test]
+    fn test_strip_tracking_params() {
+        let strip = |url| strip_tracking_params(url, &[]);
+        assert_eq!(
+            strip("https://example.com/a?utm_source=tw&id=7&fbclid=x1&page=2#comments").as_deref(),
+            Some("https://example.com/a?id=7&page=2#comments")
+        );
+        assert_eq!(
+            strip("https://example.com/a?UTM_Medium=email&ref=home").as_deref(),
+            Some("https://example.com/a")
+        );
+        // Names are matched after decoding, values are kept exactly as written
+        assert_eq!(
+            strip("/search?q=caf%C3%A9+au+lait&utm%5Fcampaign=x&next=%2Fa%3Fb%3D1").as_deref(),
+            Some("/search?q=caf%C3%A9+au+lait&next=%2Fa%3Fb%3D1")
+        );
+        assert_eq!(
+            strip("https://example.com/a?id=7&reference=x#utm_source=y"),
+            None
+        );
+        assert_eq!(strip("https://example.com/a"), None);
+        assert_eq!(
+            strip_tracking_params("/a?src=feed&id=1", &["SRC".to_string()]).as_deref(),
+            Some("/a?id=1")
+        );
+    }
+
+    #[test]
+    fn test_classify_url() {
+        assert_eq!(classify_url("https://example.com/page"), UrlKind::Absolute);
+        assert_eq!(classify_url(" HTTP://Example.com "), UrlKind::Absolute);
+        assert_eq!(
+            classify_url("https://bücher.example/café"),
+            UrlKind::Absolute
+        );
+        assert_eq!(
+            classify_url("https://xn--bcher-kva.example/"),
+            UrlKind::Absolute
+        );
+        assert_eq!(classify_url("https://exa mple.com/"), UrlKind::Invalid);
+        assert_eq!(classify_url("http://"), UrlKind::Invalid);
+        assert_eq!(
+            classify_url("//cdn.example.com/a.jpg"),
+            UrlKind::ProtocolRelative
+        );
+        assert_eq!(
+            classify_url("//bücher.example/a.jpg"),
+            UrlKind::ProtocolRelative
+        );
+        assert_eq!(classify_url("///a.jpg"), UrlKind::Invalid);
+        assert_eq!(classify_url("/blog/post?id=1"), UrlKind::RootRelative);
+        assert_eq!(classify_url("../images/a.jpg"), UrlKind::Relative);
+        assert_eq!(classify_url("example.com/page"), UrlKind::Relative);
+        assert_eq!(classify_url("?page=2"), UrlKind::Relative);
+        assert_eq!(classify_url("#section-2"), UrlKind::Fragment);
+        assert_eq!(
+            classify_url("MAILTO:jane@example.com"),
+            UrlKind::Special(SpecialScheme::Mailto)
+        );
+        assert_eq!(
+            classify_url("tel:+15551234"),
+            UrlKind::Special(SpecialScheme::Tel)
+        );
+        assert_eq!(
+            classify_url("java\nscript:alert(1)"),
+            UrlKind::Special(SpecialScheme::Javascript)
+        );
+        assert_eq!(
+            classify_url("data:image/png;base64,iVBORw0KGgo="),
+            UrlKind::Special(SpecialScheme::Data)
+        );
+        assert_eq!(
+            classify_url("data:image/svg+xml;utf8,<svg viewBox='0 0 24 24'></svg>"),
+            UrlKind::Special(SpecialScheme::Data)
+        );
+        assert_eq!(classify_url(""), UrlKind::Invalid);
+        assert_eq!(classify_url("Jane Doe"), UrlKind::Invalid);
     }
 
     #[test]
     fn test_normalize_whitespace() {
         assert_eq!(normalize_whitespace("hello   world"), "hello world");
         assert_eq!(normalize_whitespace("a  b  c"), "a b c");
+        assert_eq!(normalize_whitespace("John\u{00a0}Smith"), "John Smith");
+        assert_eq!(
+            normalize_whitespace("John\u{2009}\u{200b} Smith\u{feff}"),
+            "John Smith"
+        );
+        assert_eq!(
+            normalize_whitespace_ascii("John\u{00a0}Smith  Jr"),
+            "John\u{00a0}Smith Jr"
+        );
+    }
+
+    #[test]
+    fn test_normalize_whitespace_matches_regex() {
+        fn reference(text: &str, collapse: &Regex) -> String {
+            let spaced: String = text
+                .chars()
+                .filter(|c| !INVISIBLE_CHARS.contains(c))
+                .map(|c| {
+                    if SPACE_LIKE_CHARS.contains(&c) {
+                        ' '
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+            collapse.replace_all(&spaced, " ").into_owned()
+        }
+        let collapse = Regex::new(r"\s{2,}").unwrap();
+
+        // Random mixes of words, ASCII whitespace and non-ASCII spaces
+        let pieces = [
+            "word", "é", "中文", ",", " ", "  ", "\t", "\n", "\r\n", "\x0b", "\x0c", "\u{a0}",
+            "\u{2009}", "\u{200b}", "\u{feff}", "\u{2028}", "\u{85}",
+        ];
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        };
+        for _ in 0..5000 {
+            let len = next() % 12;
+            let text: String = (0..len).map(|_| pieces[next() % pieces.len()]).collect();
+            assert_eq!(
+                normalize_whitespace(&text),
+                reference(&text, &collapse),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_break_spaces_match_plain_spaces() {
+        let words = BylineWords::default();
+        assert!(is_byline_redundant_with_site_name(
+            "John\u{00a0}Smith",
+            "Notes by John Smith"
+        ));
+        assert!(is_byline_redundant_with_site_name(
+            "John Smith",
+            "Notes by John\u{2007}Smith"
+        ));
+        assert!(looks_like_org_credit(
+            "Our\u{00a0}Foreign\u{200b} Staff",
+            &words
+        ));
+        assert_eq!(
+            trim_soft_space("\u{feff}\u{2009}John Smith\u{00a0}"),
+            "John Smith"
+        );
+        assert_eq!(
+            clean_byline_text("By John\u{202f}Smith", &words, false).as_deref(),
+            Some("By John Smith")
+        );
     }
 
     #[test]
     fn test_looks_like_byline() {
-        assert!(looks_like_byline("By Alice Smith"));
-        assert!(looks_like_byline("BY: Bob Jones"));
-        assert!(!looks_like_byline("Alice Smith"));
-        assert!(!looks_like_byline("By clicking \"Submit\""));
+        assert!(looks_like_byline("By Alice Smith", &BylineWords::default()));
+        assert!(looks_like_byline("BY: Bob Jones", &BylineWords::default()));
+        assert!(!looks_like_byline("Alice Smith", &BylineWords::default()));
+        assert!(!looks_like_byline(
+            "By clicking \"Submit\"",
+            &BylineWords::default()
+        ));
+    }
+
+    #[test]
+    fn test_looks_like_byline_stylized_names() {
+        assert!(looks_like_byline("by bell hooks", &BylineWords::default()));
+        assert!(looks_like_byline("By danah boyd", &BylineWords::default()));
+        assert!(looks_like_byline("BY JOHN SMITH", &BylineWords::default()));
+        assert!(!looks_like_byline(
+            "BY CLICKING SUBMIT",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_byline("by the way", &BylineWords::default()));
+        assert!(!looks_like_byline("BY SIGNING UP", &BylineWords::default()));
+        assert!(!looks_like_dateline("BY JOHN SMITH"));
+    }
+
+    #[test]
+    fn test_author_names_with_particles_and_punctuation() {
+        let names = [
+            "Jean-Luc Picard",
+            "Conan O'Brien",
+            "Ngũgĩ wa Thiong'o",
+            "Ursula von der Leyen",
+            "Ahmed al-Sayed",
+            "Vincent van Gogh",
+            "Charles de Gaulle",
+            "Leonardo da Vinci",
+            "José Ortega y Gasset",
+            "Dr. von Braun",
+            "Þórunn Guðmundsdóttir",
+            "Zoë Saldaña-Pérez",
+        ];
+        for name in names {
+            let byline = format!("By {name}");
+            assert!(
+                looks_like_author_name(name, &BylineWords::default()),
+                "{name}"
+            );
+            assert!(
+                looks_like_byline(&byline, &BylineWords::default()),
+                "{name}"
+            );
+            assert_eq!(
+                split_byline_authors(&byline, &BylineWords::default()),
+                vec![name],
+                "{name}"
+            );
+            assert_eq!(
+                title_case_caps_byline(&byline.to_uppercase()),
+                byline,
+                "{name}"
+            );
+        }
+        assert_eq!(
+            split_byline_authors(
+                "By Ursula von der Leyen and Ahmed al-Sayed",
+                &BylineWords::default()
+            ),
+            vec!["Ursula von der Leyen", "Ahmed al-Sayed"]
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_title_cases_caps() {
+        assert_eq!(
+            clean_byline_text("BY JOHN O'BRIEN-SMITH", &BylineWords::default(), true).as_deref(),
+            Some("By John O'Brien-Smith")
+        );
+        assert_eq!(
+            clean_byline_text("BY JANE DOE, PHD", &BylineWords::default(), true).as_deref(),
+            Some("By Jane Doe, PhD")
+        );
+        assert_eq!(
+            clean_byline_text("By bell hooks", &BylineWords::default(), true).as_deref(),
+            Some("By bell hooks")
+        );
+        assert_eq!(
+            clean_byline_text("BY JOHN SMITH", &BylineWords::default(), false).as_deref(),
+            Some("BY JOHN SMITH")
+        );
+    }
+
+    #[test]
+    fn test_split_byline_authors_localized_conjunctions() {
+        let cases: [(&str, &[&str]); 9] = [
+            ("By Jane Doe and John Roe", &["Jane Doe", "John Roe"]),
+            (
+                "Por Ana García y Luis Ortega",
+                &["Ana García", "Luis Ortega"],
+            ),
+            (
+                "Di Maria Rossi e Paolo Bianchi",
+                &["Maria Rossi", "Paolo Bianchi"],
+            ),
+            (
+                "Von Anna Schmidt und Peter Weber",
+                &["Anna Schmidt", "Peter Weber"],
+            ),
+            (
+                "Par Jean Dupont et Marie Curie",
+                &["Jean Dupont", "Marie Curie"],
+            ),
+            (
+                "Door Pieter de Vries en Anne Jansen",
+                &["Pieter de Vries", "Anne Jansen"],
+            ),
+            ("山田太郎、佐藤花子", &["山田太郎", "佐藤花子"]),
+            ("أحمد علي و سارة حسن", &["أحمد علي", "سارة حسن"]),
+            (
+                "Alice Smith, Bob Jones, and Carol White",
+                &["Alice Smith", "Bob Jones", "Carol White"],
+            ),
+        ];
+        for (byline, expected) in cases {
+            assert_eq!(
+                split_byline_authors(byline, &BylineWords::default()),
+                expected,
+                "{byline}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_byline_authors_falls_back_to_unsplit() {
+        assert_eq!(
+            split_byline_authors("Por Ana García y Reuters", &BylineWords::default()),
+            vec!["Ana García y Reuters"]
+        );
+        assert_eq!(
+            split_byline_authors("Maria da Silva e Souza", &BylineWords::default()),
+            vec!["Maria da Silva e Souza"]
+        );
+        assert_eq!(
+            split_byline_authors("By Jane Doe", &BylineWords::default()),
+            vec!["Jane Doe"]
+        );
+        assert!(split_byline_authors("  ", &BylineWords::default()).is_empty());
+    }
+
+    #[test]
+    fn test_looks_like_byline_localized_prefixes() {
+        assert!(looks_like_byline(
+            "Par Jean Dupont",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_byline(
+            "Von Anna Schmidt",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_byline("Por Juan Pérez", &BylineWords::default()));
+        assert!(looks_like_byline("Di Maria Rossi", &BylineWords::default()));
+        assert!(looks_like_byline("Por: Ana Souza", &BylineWords::default()));
+        assert!(looks_like_byline(
+            "Door Pieter de Vries",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_byline(
+            "Av Erik Lindqvist",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_byline(
+            "Di solito non piove",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_byline(
+            "Vonnegut wrote it",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_byline(
+            "Avec Marie Curie",
+            &BylineWords::default()
+        ));
+
+        let prefixes = vec!["Af".to_string(), "Автор:".to_string()];
+        let extra = BylineWords {
+            prefixes: &prefixes,
+            ..Default::default()
+        };
+        assert!(!looks_like_byline(
+            "Af Mette Jensen",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_byline("Af Mette Jensen", &extra));
+        assert!(looks_like_byline("автор: Иван Петров", &extra));
     }
 
     #[test]
     fn test_clean_byline_text_trims_delimiters() {
-        let cleaned = clean_byline_text("Nicolas Perriault — ").unwrap();
+        let cleaned =
+            clean_byline_text("Nicolas Perriault — ", &BylineWords::default(), false).unwrap();
         assert_eq!(cleaned, "Nicolas Perriault");
     }
 
     #[test]
     fn test_clean_byline_text_rejects_social_follow() {
-        assert!(clean_byline_text("Follow @example").is_none());
-        assert!(clean_byline_text("@example on Twitter").is_none());
+        assert!(clean_byline_text("Follow @example", &BylineWords::default(), false).is_none());
+        assert!(clean_byline_text("@example on Twitter", &BylineWords::default(), false).is_none());
     }
 
     #[test]
     fn test_clean_byline_text_collapses_blank_line_but_keeps_indent() {
         let input = "By Brenda  Goodman, MA\n            \nWebMD Health News";
         let expected = "By Brenda  Goodman, MA\n            WebMD Health News";
-        assert_eq!(clean_byline_text(input).unwrap(), expected);
+        assert_eq!(
+            clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+            expected
+        );
     }
 
     #[test]
     fn test_clean_byline_text_strips_trailing_timestamp() {
         let input = "Dan Goodin - Apr 16, 2015 8:02 pm UTC";
-        assert_eq!(clean_byline_text(input).unwrap(), "Dan Goodin");
+        assert_eq!(
+            clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+            "Dan Goodin"
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_strips_localized_trailing_dates() {
+        let cases = [
+            ("Ana García - 12 de marzo de 2023", "Ana García"),
+            ("Jean Dupont — 3 février 2023 à 14h30", "Jean Dupont"),
+            ("Anna Schmidt | 15. März 2023", "Anna Schmidt"),
+            ("Marco Rossi - 7 settembre 2022", "Marco Rossi"),
+            ("João Silva · 21 de outubro de 2021", "João Silva"),
+            ("Pieter de Vries - 15 maart 2023", "Pieter de Vries"),
+            ("Anna Schmidt - 15.03.2023", "Anna Schmidt"),
+            ("Anna Schmidt | 2023-03-15", "Anna Schmidt"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_byline_text_keeps_localized_absolute_dates() {
+        for input in [
+            "Von Anna Schmidt\n15. März 2023, 14:30 Uhr",
+            "Door Pieter de Vries\n15 maart 2023 14:30",
+            "Von Anna Schmidt\n15.03.2023 14:30",
+        ] {
+            assert_eq!(
+                clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_byline_text_strips_emoji() {
+        let words = BylineWords::default();
+        let cases = [
+            ("✍️ Jane Doe", "Jane Doe"),
+            ("By Jane Doe 🖋️", "By Jane Doe"),
+            ("👤 Renée Lefèvre ⭐", "Renée Lefèvre"),
+            ("🇫🇷 Zoë Saldaña — 12:30", "Zoë Saldaña"),
+            ("✍️ João Conceição\n@joaoc", "João Conceição"),
+            ("Jane Doe 🖋️ Senior Writer", "Jane Doe"),
+            ("By Jane ⭐ Doe", "By Jane Doe"),
+            ("Łukasz Żółć", "Łukasz Żółć"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert!(clean_byline_text("✍️ 🖋️", &words, false).is_none());
+        assert!(looks_like_byline("✍️ By Jane Doe", &words));
+        assert!(looks_like_author_name("Jane Doe 🖋️", &words));
     }
 
     #[test]
     fn test_clean_byline_text_drops_relative_time_line() {
         let input = "Alex Perry\n                                                1 day ago";
-        assert_eq!(clean_byline_text(input).unwrap(), "Alex Perry");
+        assert_eq!(
+            clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+            "Alex Perry"
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_drops_localized_relative_time_lines() {
+        let cases = [
+            ("Ana García\n                hace 2 días", "Ana García"),
+            (
+                "Jean Dupont\n                il y a 5 minutes",
+                "Jean Dupont",
+            ),
+            (
+                "Anna Schmidt\n                vor 3 Stunden",
+                "Anna Schmidt",
+            ),
+            ("Marco Rossi\n                2 ore fa", "Marco Rossi"),
+            ("João Silva\n                há 4 horas", "João Silva"),
+            (
+                "Pieter de Vries\n                3 uur geleden",
+                "Pieter de Vries",
+            ),
+            ("田中太郎\n                2 時間前", "田中太郎"),
+            ("김지수 기자\n                3시간 전", "김지수 기자"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+        assert_eq!(
+            clean_byline_text("Anna Schmidt\nVorstand", &BylineWords::default(), false).unwrap(),
+            "Anna Schmidt\nVorstand"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_heuristics_recognize_timezones() {
+        for timestamp in [
+            "10.30 IST",
+            "9.15 AEST",
+            "18.00 JST",
+            "7 BST",
+            "11.45 CDT",
+            "6pm MST",
+            "8.02 p.m. GMT",
+            "+05:30",
+            "UTC+5",
+        ] {
+            let lower = timestamp.to_lowercase();
+            assert!(looks_like_datetime_segment(&lower), "{timestamp}");
+            assert!(looks_like_live_timestamp_segment(&lower), "{timestamp}");
+            assert_eq!(
+                clean_byline_text(
+                    &format!("Priya Sharma - {timestamp}"),
+                    &BylineWords::default(),
+                    false
+                )
+                .unwrap(),
+                "Priya Sharma",
+                "{timestamp}"
+            );
+        }
+        assert!(!looks_like_datetime_segment("seit 2019 ist er autor"));
+        assert!(!looks_like_live_timestamp_segment("das ist 3 mal passiert"));
+        assert!(!looks_like_datetime_segment("sam kessler 2019"));
     }
 
     #[test]
     fn test_clean_byline_text_keeps_timestamp_without_author() {
         let input = "April 28, 2019 at 6:01 am Updated April 29, 2019 at 3:33 pm";
         assert_eq!(
-            clean_byline_text(input).unwrap(),
+            clean_byline_text(input, &BylineWords::default(), false).unwrap(),
             "April 28, 2019 at 6:01 am Updated April 29, 2019 at 3:33 pm"
         );
     }
@@ -794,23 +2636,490 @@ mod tests {
     #[test]
     fn test_clean_byline_text_preserves_name_with_plain_date() {
         let input = "By Nathan Willis\nMarch 25, 2015";
-        assert_eq!(clean_byline_text(input).unwrap(), input);
+        assert_eq!(
+            clean_byline_text(input, &BylineWords::default(), false).unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_keeps_absolute_date_lines() {
+        let words = BylineWords::default();
+        let cases = [
+            ("By Jane Doe\nWednesday, 15.03.2023", true),
+            ("By Jane Doe\nPublished Friday at 9:00", true),
+            ("By Jane Doe\nSat 14:30", true),
+            ("By Jane Doe\n2023-03-15 14:30", true),
+            ("By Jane Doe\nMarch 25, 2015", true),
+            ("By Jane Doe\n14:30", false),
+            ("By Jane Doe\n3 hours ago", false),
+            ("By Jane Doe\nUpdated Friday at 9:00", false),
+        ];
+        for (input, keeps_date) in cases {
+            let expected = if keeps_date { input } else { "By Jane Doe" };
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_byline_text_decodes_entities() {
+        let words = BylineWords::default();
+        assert_eq!(
+            clean_byline_text("By John&nbsp;Smith", &words, false).as_deref(),
+            Some("By John Smith")
+        );
+        assert_eq!(
+            clean_byline_text(
+                "Jos&eacute; Mart&iacute;nez &amp; Fran&ccedil;ois M&uuml;ller",
+                &words,
+                false
+            )
+            .as_deref(),
+            Some("José Martínez & François Müller")
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_strips_trailing_metadata_clauses() {
+        let words = BylineWords::default();
+        let cases = [
+            (
+                "Jane Doe | Tech Desk | Updated 3 hours ago | 5 min read",
+                "Jane Doe | Tech Desk",
+            ),
+            ("Jane Doe · 5 min read", "Jane Doe"),
+            ("By Jane Doe | March 3, 2024 | 651 comments", "By Jane Doe"),
+            ("Jane Doe — 3 minute read — Updated today", "Jane Doe"),
+            ("Jane Doe | Reading time: 4 minutes", "Jane Doe"),
+            (
+                "Jane Doe | Minnesota Public Radio",
+                "Jane Doe | Minnesota Public Radio",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_byline_text_detailed_confidence() {
+        let words = BylineWords::default();
+        let details = |text: &str| clean_byline_text_detailed(text, &words);
+
+        let prefixed = details("By Jane Doe");
+        let bare = details("Jane Doe");
+        let single = details("Jane");
+        let org_heavy = details("By Jane Doe, Metro Desk Staff");
+        assert!(prefixed.confidence > bare.confidence);
+        assert!(bare.confidence > single.confidence);
+        assert!(prefixed.confidence > org_heavy.confidence);
+        assert_eq!(org_heavy.org_keyword_hits, 2);
+        assert!(prefixed
+            .signals
+            .contains(BylineSignals::BY_PREFIX | BylineSignals::AUTHOR_SEGMENT));
+
+        let dated = details("By Jane Doe\nUpdated 3 hours ago\n@janedoe");
+        assert_eq!(
+            dated.outcome,
+            CleanBylineOutcome::Accepted("By Jane Doe".into())
+        );
+        assert!(dated
+            .signals
+            .contains(BylineSignals::TIMESTAMP_STRIPPED | BylineSignals::SOCIAL_LINE_REMOVED));
+        assert_eq!(dated.confidence, prefixed.confidence);
+
+        let menu = details("Home · About · Contact");
+        assert_eq!(menu.outcome, CleanBylineOutcome::Dropped);
+        assert!(menu.signals.contains(BylineSignals::NAVIGATION_MENU));
+        assert_eq!(menu.confidence, 0.0);
+        assert_eq!(
+            details("Our Foreign Staff").outcome,
+            CleanBylineOutcome::DroppedOrgCredit
+        );
+
+        // Prose opening with a prefix word gets no credit for it
+        for text in [
+            "Di solito andiamo al mare",
+            "By clicking Submit you agree to our terms",
+        ] {
+            let prose = details(text);
+            assert!(!prose.signals.contains(BylineSignals::BY_PREFIX), "{text}");
+            assert!(prose.confidence < prefixed.confidence, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_contributor_credits() {
+        let credit = |text: &str| parse_contributor_credit(text).map(|c| (c.role, c.name));
+        assert_eq!(
+            credit("Photography by Ansel Adams"),
+            Some((ContributorRole::Photography, "Ansel Adams".to_string()))
+        );
+        assert_eq!(
+            credit("Illustrations by Quentin Blake."),
+            Some((ContributorRole::Illustration, "Quentin Blake".to_string()))
+        );
+        assert_eq!(
+            credit("Video by AP"),
+            Some((ContributorRole::Video, "AP".to_string()))
+        );
+        assert_eq!(
+            credit("Graphics by: Jane Doe/Reuters"),
+            Some((ContributorRole::Graphics, "Jane Doe/Reuters".to_string()))
+        );
+        assert_eq!(
+            credit("Medically reviewed by Dr. Sam Lee"),
+            Some((ContributorRole::Review, "Dr. Sam Lee".to_string()))
+        );
+        assert_eq!(
+            credit("Fact-checked by Chris Park"),
+            Some((ContributorRole::FactCheck, "Chris Park".to_string()))
+        );
+        assert_eq!(credit("Photos by the river were taken at dawn"), None);
+        assert_eq!(credit("By Jane Doe"), None);
+
+        let words = BylineWords::default();
+        assert_eq!(
+            clean_byline_text("By Jane Doe\nPhotography by Ansel Adams", &words, false).as_deref(),
+            Some("By Jane Doe")
+        );
+        assert_eq!(
+            clean_byline_text("By Jane Doe | Photos by Ansel Adams", &words, false).as_deref(),
+            Some("By Jane Doe")
+        );
+        assert_eq!(
+            clean_byline_text("Photography by Ansel Adams", &words, false),
+            None
+        );
+        assert_eq!(
+            clean_byline_text("Medically reviewed by Dr. Sam Lee", &words, false),
+            None
+        );
+        assert_eq!(
+            clean_byline_text("By Jane Doe\nEdited by Chris Park", &words, false).as_deref(),
+            Some("By Jane Doe\nEdited by Chris Park")
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_attribution_labels() {
+        let words = BylineWords::default();
+        let cases = [
+            ("Posted by Jane Doe", Some("Jane Doe")),
+            ("Guest post by John Smith", Some("John Smith")),
+            ("Written by: Maria Garcia", Some("Maria Garcia")),
+            ("Contributed by Li Wei", Some("Li Wei")),
+            ("Posted by Newsroom Staff", None),
+            ("Guest post by Acme Press Team", None),
+            ("Written by Our Foreign Staff", None),
+            ("Contributed by the News Desk Team", None),
+            ("Promoted by Jane Doe", None),
+            ("Promoted by Acme Corp", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_byline_text_strips_email_addresses() {
+        let words = BylineWords::default();
+        let cases = [
+            ("Jane Doe jane.doe@example.com", Some("Jane Doe")),
+            ("By Jane Doe\njane.doe@example.com", Some("By Jane Doe")),
+            ("By Jane Doe (jane@example.com)", Some("By Jane Doe")),
+            (
+                "Jane Doe, Email: jane.doe@news.example.co.uk",
+                Some("Jane Doe"),
+            ),
+            ("Jane Doe | mailto:jane@example.com", Some("Jane Doe")),
+            ("jane.doe@example.com", None),
+            ("By Jane Doe @janedoe", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                expected,
+                "{input}"
+            );
+        }
+        assert!(!looks_like_social_handle("Follow jane.doe@example.com"));
+        assert!(looks_like_social_handle("Follow @janedoe"));
+    }
+
+    #[test]
+    fn test_agency_byline() {
+        let words = BylineWords::default();
+        assert_eq!(agency_byline("Reuters", &words), Some("Reuters"));
+        assert_eq!(
+            agency_byline(" By Associated Press ", &words),
+            Some("By Associated Press")
+        );
+        assert_eq!(
+            agency_byline("Staff and wire reports (AP)", &words),
+            Some("Staff and wire reports (AP)")
+        );
+        assert_eq!(agency_byline("Our Foreign Staff", &words), None);
+        assert_eq!(agency_byline("Reuters Staff", &words), None);
+        assert_eq!(agency_byline("Jane Doe", &words), None);
+    }
+
+    #[test]
+    fn test_is_byline_duplicate() {
+        let words = BylineWords::default();
+        assert!(is_byline_duplicate("Jane Doe", "Jane Doe", &words));
+        assert!(is_byline_duplicate("By  Jane Doe", "Jane Doe", &words));
+        assert!(is_byline_duplicate("Jane Doe", "By Jane Doe", &words));
+        assert!(is_byline_duplicate(
+            "By Jane Doe | March 18 at 3:57 PM",
+            "By Jane Doe",
+            &words
+        ));
+        assert!(is_byline_duplicate(
+            "By Jane Doe March 18 at 3:57 PM Follow @janedoe",
+            "Jane Doe",
+            &words
+        ));
+        assert!(!is_byline_duplicate(
+            "Jane Doe said the council would vote on Tuesday",
+            "Jane Doe",
+            &words
+        ));
+        assert!(!is_byline_duplicate(
+            "Interview with Jane Doe",
+            "Jane Doe",
+            &words
+        ));
+    }
+
+    #[test]
+    fn test_clean_byline_text_strips_job_titles() {
+        let words = BylineWords::default();
+        let cases = [
+            ("By Jane Doe, Science Editor", "By Jane Doe"),
+            ("John Smith, Staff Writer", "John Smith"),
+            ("John Smith, Jr., Senior Correspondent", "John Smith, Jr."),
+            ("Renée Lefèvre, Columnist", "Renée Lefèvre"),
+            ("Jane Doe, John Smith", "Jane Doe, John Smith"),
+            ("Brenda Goodman, MA", "Brenda Goodman, MA"),
+            (
+                "Jane Doe, Editor and John Smith",
+                "Jane Doe, Editor and John Smith",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                clean_byline_text(input, &words, false).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert!(clean_byline_text("Our Foreign Staff, Staff Writer", &words, false).is_none());
     }
 
     #[test]
     fn test_clean_byline_text_drops_org_credit() {
-        assert!(clean_byline_text("Our Foreign Staff").is_none());
-        assert!(clean_byline_text("BuzzFeed News Reporter").is_none());
-        assert!(clean_byline_text("Android Developers").is_none());
+        assert!(clean_byline_text("Our Foreign Staff", &BylineWords::default(), false).is_none());
+        assert!(
+            clean_byline_text("BuzzFeed News Reporter", &BylineWords::default(), false).is_none()
+        );
+        assert!(clean_byline_text("Android Developers", &BylineWords::default(), false).is_none());
+    }
+
+    #[test]
+    fn test_clean_byline_text_extra_org_keywords() {
+        let defaults = BylineWords::default();
+        assert_eq!(
+            clean_byline_text("SPIEGEL Redaktion", &defaults, false).as_deref(),
+            Some("SPIEGEL Redaktion")
+        );
+
+        let keywords = vec!["Redaktion".to_string()];
+        let words = BylineWords {
+            org_keywords: &keywords,
+            ..Default::default()
+        };
+        assert!(clean_byline_text("SPIEGEL Redaktion", &words, false).is_none());
+        assert!(clean_byline_text("Von Anna Schmidt", &words, false).is_some());
+    }
+
+    #[test]
+    fn test_looks_like_author_name_extra_disqualifiers() {
+        let disqualifiers = vec!["Redaktion".to_string()];
+        let words = BylineWords {
+            author_disqualifiers: &disqualifiers,
+            ..Default::default()
+        };
+        assert!(looks_like_author_name(
+            "Redaktion Wirtschaft",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name("Redaktion Wirtschaft", &words));
+        assert!(looks_like_author_name("Anna Schmidt", &words));
     }
 
     #[test]
     fn test_looks_like_author_name() {
-        assert!(looks_like_author_name("Daniel Kahn Gillmor"));
-        assert!(looks_like_author_name("R.J. Eskow"));
-        assert!(!looks_like_author_name("BuzzFeed News Reporter"));
-        assert!(!looks_like_author_name("Follow @example"));
-        assert!(!looks_like_author_name("SingleWord"));
+        assert!(looks_like_author_name(
+            "Daniel Kahn Gillmor",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_author_name(
+            "R.J. Eskow",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name(
+            "BuzzFeed News Reporter",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name(
+            "Follow @example",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name(
+            "SingleWord",
+            &BylineWords::default()
+        ));
+    }
+
+    #[test]
+    fn test_looks_like_author_name_honorifics_and_credentials() {
+        assert!(looks_like_author_name(
+            "Dr. Jane Rivera",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_author_name(
+            "Prof. A. N. Whitehead",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_author_name(
+            "Rev. John Smith Jr.",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_author_name(
+            "Brenda Goodman, MA",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_author_name(
+            "Jane Doe, M.D., PhD",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name("Dr. med", &BylineWords::default()));
+        assert!(!looks_like_author_name(
+            "Smith, MD",
+            &BylineWords::default()
+        ));
+    }
+
+    #[test]
+    fn test_split_byline_authors_credentials() {
+        assert_eq!(
+            split_byline_authors("By Brenda Goodman, MA", &BylineWords::default()),
+            vec!["Brenda Goodman"]
+        );
+        assert_eq!(
+            split_byline_authors(
+                "By Dr. Jane Rivera, MD, PhD and Rev. John Smith Jr.",
+                &BylineWords::default()
+            ),
+            vec!["Dr. Jane Rivera", "Rev. John Smith Jr."]
+        );
+        assert_eq!(
+            split_byline_authors(
+                "Prof. A. N. Whitehead, John Smith, Jr., and Jack Ma",
+                &BylineWords::default()
+            ),
+            vec!["Prof. A. N. Whitehead", "John Smith, Jr.", "Jack Ma"]
+        );
+    }
+
+    #[test]
+    fn test_looks_like_author_name_cjk() {
+        assert!(looks_like_author_name("王小明", &BylineWords::default()));
+        assert!(looks_like_author_name("田中太郎", &BylineWords::default()));
+        assert!(looks_like_author_name("김지수", &BylineWords::default()));
+        assert!(looks_like_author_name("李华", &BylineWords::default()));
+        assert!(!looks_like_author_name("王", &BylineWords::default()));
+        assert!(!looks_like_author_name(
+            "东京奥运会开幕式",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_author_name("第3版", &BylineWords::default()));
+        assert!(!looks_like_author_name("王小明。", &BylineWords::default()));
+    }
+
+    #[test]
+    fn test_cjk_length_threshold() {
+        assert!(is_mostly_cjk("北浜市で十五日、新しい路面電車が開業した。"));
+        assert!(is_mostly_cjk("서울시는 15일 새 노선을 열었다"));
+        assert!(is_mostly_cjk("GPS: 新竹縣尖石鄉玉峰村"));
+        assert!(!is_mostly_cjk("The Tokyo (東京) office opened"));
+        assert!(!is_mostly_cjk("Новая линия трамвая открылась"));
+        assert!(!is_mostly_cjk(""));
+
+        assert_eq!(cjk_length_threshold(140, 0.4, true), 56);
+        assert_eq!(cjk_length_threshold(140, 0.4, false), 140);
+    }
+
+    #[test]
+    fn test_looks_like_byline_rtl_prefixes() {
+        let words = BylineWords::default();
+        assert!(looks_like_byline("بقلم أحمد علي", &words));
+        assert!(looks_like_byline("كتب: محمد عبد الله", &words));
+        assert!(looks_like_byline("מאת דנה לוי", &words));
+        assert!(looks_like_byline("\u{200f}מאת: יוסי כהן\u{200f}", &words));
+        assert!(looks_like_byline("نوشته علی رضایی", &words));
+        assert!(looks_like_byline("نویسنده: مهسا امینی‌پور", &words));
+        assert!(!looks_like_byline("بقلم أحمد", &words));
+        assert!(!looks_like_byline("بقلم 2023", &words));
+        assert!(!looks_like_byline("بقلم أحمد 12", &words));
+        assert!(!looks_like_byline("מאת", &words));
+        assert!(!looks_like_byline(
+            "מאת השנים האחרונות ועד היום הזה ממש עכשיו",
+            &words
+        ));
+
+        assert_eq!(
+            split_byline_authors("بقلم أحمد علي و سارة محمود", &words),
+            vec!["أحمد علي", "سارة محمود"]
+        );
+        assert_eq!(split_byline_authors("מאת דנה לוי", &words), vec!["דנה לוי"]);
+    }
+
+    #[test]
+    fn test_looks_like_byline_cjk_markers() {
+        assert!(looks_like_byline("文/王小明", &BylineWords::default()));
+        assert!(looks_like_byline(
+            "文／王小明、李华",
+            &BylineWords::default()
+        ));
+        assert!(looks_like_byline("記者 田中太郎", &BylineWords::default()));
+        assert!(looks_like_byline("王小明 记者", &BylineWords::default()));
+        assert!(looks_like_byline("김지수 기자", &BylineWords::default()));
+        assert!(!looks_like_byline(
+            "文/王小明报道了东京奥运会开幕式的盛况",
+            &BylineWords::default()
+        ));
+        assert!(!looks_like_byline(
+            "記者会見で首相が発表した",
+            &BylineWords::default()
+        ));
+        assert_eq!(
+            split_byline_authors("文/王小明、李华", &BylineWords::default()),
+            vec!["王小明", "李华"]
+        );
     }
 
     #[test]
@@ -832,21 +3141,112 @@ mod tests {
     #[test]
     fn test_clean_byline_text_handles_inline_date_and_count() {
         let input = "by Lucas Nolan22 Dec 2016651";
-        let cleaned = clean_byline_text(input).expect("byline should be kept");
+        let cleaned = clean_byline_text(input, &BylineWords::default(), false)
+            .expect("byline should be kept");
         assert!(cleaned.contains("Lucas Nolan"));
     }
 
     #[test]
     fn test_clean_byline_text_strips_social_handle_lines() {
         let input = "By John Smith\n@johnsmith\nJanuary 1, 2020";
-        let cleaned = clean_byline_text(input).expect("byline should be kept");
+        let cleaned = clean_byline_text(input, &BylineWords::default(), false)
+            .expect("byline should be kept");
         assert_eq!(cleaned, "By John Smith\nJanuary 1, 2020");
     }
 
+    #[test]
+    fn test_clean_byline_text_strips_profile_link_lines() {
+        for profile in [
+            "twitter.com/johnsmith",
+            "x.com/johnsmith",
+            "instagram.com/johnsmith",
+            "https://www.threads.net/@johnsmith",
+            "mastodon.social/@johnsmith",
+            "bsky.app/profile/johnsmith.bsky.social",
+            "linkedin.com/in/johnsmith",
+            "t.me/johnsmith",
+            "hachyderm.io/@johnsmith",
+        ] {
+            let input = format!("By John Smith\n{profile}\nJanuary 1, 2020");
+            assert_eq!(
+                remove_social_handle_lines(&input).as_deref(),
+                Some("By John Smith\nJanuary 1, 2020"),
+                "{profile}"
+            );
+        }
+        assert!(remove_social_handle_lines("By John Smith\njane@example.com").is_none());
+        assert!(remove_social_handle_lines("By John Smith\nbox.com/johnsmith").is_none());
+    }
+
+    #[test]
+    fn test_looks_like_menu() {
+        assert!(looks_like_menu("[Home] [About] [Contact]"));
+        assert!(looks_like_menu("[edit] [history] 12"));
+        assert!(looks_like_menu("(Home) (About) (Contact)"));
+        assert!(looks_like_menu("«Home» «News» «Sport»"));
+        assert!(looks_like_menu("Home · About · Contact"));
+        assert!(looks_like_menu("Home | News | Sport | Weather 2"));
+        assert!(looks_like_menu("Home • Blog • Smith"));
+
+        assert!(!looks_like_menu("[Home]"));
+        assert!(!looks_like_menu("(AP) (Reuters)"));
+        assert!(!looks_like_menu(
+            "[Home] [About] read the rest of this story"
+        ));
+        assert!(!looks_like_menu("Smith · Jones · Brown"));
+        assert!(!looks_like_menu("Home · About | Contact"));
+        assert!(!looks_like_menu("Home · About us · Contact"));
+
+        let words = BylineWords::default();
+        assert!(clean_byline_text("Home · About · Contact", &words, false).is_none());
+        assert_eq!(
+            clean_byline_text("Smith · Jones · Brown", &words, false).as_deref(),
+            Some("Smith · Jones · Brown")
+        );
+    }
+
+    #[test]
+    fn test_split_leading_dateline() {
+        fn lede(text: &str) -> Option<&str> {
+            split_leading_dateline(text).map(|idx| &text[idx..])
+        }
+        assert_eq!(
+            lede("CAIRO — Egyptian officials said on Monday"),
+            Some("Egyptian officials said on Monday")
+        );
+        assert_eq!(
+            lede("  Cairo, Egypt — Egyptian officials said on Monday"),
+            Some("Egyptian officials said on Monday")
+        );
+        assert_eq!(
+            lede("San Francisco (Reuters) - Shares of chipmakers rose"),
+            Some("Shares of chipmakers rose")
+        );
+        assert_eq!(
+            lede("Paris — the city of light — has long drawn painters."),
+            None
+        );
+        assert_eq!(
+            lede("Paris, Texas — a town most people know from the film."),
+            None
+        );
+        assert_eq!(
+            lede("London, Ontario — Not for the first time — the river flooded."),
+            None
+        );
+        assert_eq!(lede("Paris — It rained all week."), None);
+    }
+
     #[test]
     fn test_looks_like_dateline_detection() {
         assert!(looks_like_dateline("CAIRO"));
         assert!(looks_like_dateline("PARIS —"));
+        assert!(looks_like_dateline("Cairo, Egypt —"));
+        assert!(looks_like_dateline("San Francisco (Reuters) –"));
+        assert!(looks_like_dateline("Washington, D.C. -"));
+        assert!(!looks_like_dateline("Paris —"));
+        assert!(!looks_like_dateline("San Francisco (Photo) –"));
+        assert!(!looks_like_dateline("By Jane, Doe —"));
         assert!(!looks_like_dateline("By Erin Cunningham"));
         assert!(!looks_like_dateline("Washington Post Staff"));
     }
@@ -0,0 +1,125 @@
+//! JavaScript bindings, enabled with the `wasm` feature.
+//!
+//! Built for `wasm32-unknown-unknown` with `wasm-bindgen`, this exposes the
+//! same two entry points as Readability.js, so the crate can run in browsers,
+//! extensions and edge runtimes:
+//!
+//! ```js
+//! import { parse, isProbablyReaderable } from "readabilityrs";
+//!
+//! if (isProbablyReaderable(html)) {
+//!   const article = parse(html, "https://example.com/post", { charThreshold: 300 });
+//!   console.log(article.title, article.textContent);
+//! }
+//! ```
+//!
+//! Options use Readability.js names. Unknown keys are ignored, and `undefined`
+//! or `null` means the defaults.
+
+use crate::{Readability, ReadabilityOptions, ReaderableOptions};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Options accepted by [`parse`], named as in Readability.js
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ParseOptions {
+    debug: Option<bool>,
+    max_elems_to_parse: Option<usize>,
+    nb_top_candidates: Option<usize>,
+    char_threshold: Option<usize>,
+    classes_to_preserve: Option<Vec<String>>,
+    keep_classes: Option<bool>,
+    #[serde(rename = "disableJSONLD")]
+    disable_json_ld: Option<bool>,
+    allowed_video_regex: Option<String>,
+    link_density_modifier: Option<f64>,
+}
+
+impl ParseOptions {
+    fn into_options(self) -> Result<ReadabilityOptions, JsError> {
+        let mut builder = ReadabilityOptions::builder();
+        if let Some(debug) = self.debug {
+            builder = builder.debug(debug);
+        }
+        if let Some(max) = self.max_elems_to_parse {
+            builder = builder.max_elems_to_parse(max);
+        }
+        if let Some(nb) = self.nb_top_candidates {
+            builder = builder.nb_top_candidates(nb);
+        }
+        if let Some(threshold) = self.char_threshold {
+            builder = builder.char_threshold(threshold);
+        }
+        if let Some(classes) = self.classes_to_preserve {
+            builder = builder.classes_to_preserve(classes);
+        }
+        if let Some(keep) = self.keep_classes {
+            builder = builder.keep_classes(keep);
+        }
+        if let Some(disable) = self.disable_json_ld {
+            builder = builder.disable_json_ld(disable);
+        }
+        if let Some(pattern) = self.allowed_video_regex {
+            builder = builder.allowed_video_regex(Regex::new(&pattern)?);
+        }
+        if let Some(modifier) = self.link_density_modifier {
+            builder = builder.link_density_modifier(modifier);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Options accepted by [`is_probably_readerable`], named as in Readability.js
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ReaderableCheckOptions {
+    min_content_length: Option<usize>,
+    min_score: Option<f64>,
+}
+
+impl From<ReaderableCheckOptions> for ReaderableOptions {
+    fn from(options: ReaderableCheckOptions) -> Self {
+        let defaults = ReaderableOptions::default();
+        ReaderableOptions {
+            min_content_length: options
+                .min_content_length
+                .unwrap_or(defaults.min_content_length),
+            min_score: options.min_score.unwrap_or(defaults.min_score),
+            ..defaults
+        }
+    }
+}
+
+/// Read an options object, treating `undefined` and `null` as empty
+fn from_js_options<T: Default + for<'de> Deserialize<'de>>(options: JsValue) -> Result<T, JsError> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(T::default());
+    }
+    serde_wasm_bindgen::from_value(options).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Extract the article from `html`, or `null` when none is found.
+///
+/// Throws when `url` does not parse or an option is invalid.
+#[wasm_bindgen]
+pub fn parse(html: &str, url: Option<String>, options: JsValue) -> Result<JsValue, JsError> {
+    let options = from_js_options::<ParseOptions>(options)?.into_options()?;
+    let Some(article) = Readability::new(html, url.as_deref(), Some(options))?.parse() else {
+        return Ok(JsValue::NULL);
+    };
+
+    // Missing fields are `null`, as in Readability.js, rather than `undefined`
+    article
+        .to_mozilla_json()
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Whether `html` probably holds an article worth parsing.
+#[wasm_bindgen(js_name = isProbablyReaderable)]
+pub fn is_probably_readerable(html: &str, options: JsValue) -> Result<bool, JsError> {
+    let options: ReaderableCheckOptions = from_js_options(options)?;
+    Ok(crate::is_probably_readerable(html, Some(options.into())))
+}
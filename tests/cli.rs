@@ -0,0 +1,89 @@
+//! Drives the `readability` binary against the fixtures
+//!
+//! ```text
+//! cargo test --features cli --test cli
+//! ```
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SOURCE: &str = "tests/test-pages/001/source.html";
+const URL: &str = "http://fakehost/test/page.html";
+
+fn readability(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_readability"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn reads_stdin_and_prints_html() {
+    let html = std::fs::read_to_string(SOURCE).unwrap();
+    let output = readability(&["--url", URL], Some(&html));
+
+    assert!(output.status.success());
+    let content = stdout(&output);
+    assert!(content.starts_with("<section>"));
+    // Relative links are resolved against --url
+    assert!(content.contains("href=\"http://fakehost/code/2013/"));
+}
+
+#[test]
+fn prints_text_and_markdown() {
+    let text = readability(&[SOURCE, "--output", "text"], None);
+    assert!(text.status.success());
+    assert!(!stdout(&text).contains("<p>"));
+    assert!(stdout(&text).contains("So finally you're testing your frontend JavaScript code?"));
+
+    let markdown = readability(&[SOURCE, "--output", "markdown"], None);
+    assert!(markdown.status.success());
+    assert!(stdout(&markdown).starts_with("# Get your Frontend JavaScript Code Covered | Code\n"));
+}
+
+#[test]
+fn json_matches_article_serialization() {
+    let output = readability(&[SOURCE, "--url", URL, "--output", "json"], None);
+    assert!(output.status.success());
+
+    let article: readabilityrs::Article = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(article.byline.as_deref(), Some("Nicolas Perriault"));
+    assert_eq!(
+        article.excerpt.as_deref(),
+        Some("Nicolas Perriault's homepage.")
+    );
+}
+
+#[test]
+fn exits_non_zero_without_article() {
+    let page = "<html><body><p>Too short.</p></body></html>";
+    let output = readability(&["--readerable-only"], Some(page));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+
+    let output = readability(&["--char-threshold", "1000000"], Some(page));
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn rejects_invalid_url() {
+    let output = readability(&[SOURCE, "--url", "not a url"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid URL"));
+}
@@ -0,0 +1,88 @@
+//! Builds `tests/ffi/smoke.c` against the header and the shared library, then
+//! runs it on a fixture
+//!
+//! ```text
+//! cargo test --features ffi --test ffi
+//! ```
+//!
+//! The C compiler is taken from `$CC`, falling back to `cc`. The library is
+//! built as a `cdylib` with the profile the test runs under, unless
+//! `READABILITYRS_CDYLIB_DIR` names a directory that already holds one, as
+//! left by `cargo rustc --lib --features ffi --crate-type cdylib`.
+
+#![cfg(all(unix, feature = "ffi"))]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory holding `libreadabilityrs.{so,dylib}` built with `ffi`
+fn cdylib_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("READABILITYRS_CDYLIB_DIR") {
+        return dir.into();
+    }
+
+    // Tests run from target/<profile dir>/deps
+    let exe = std::env::current_exe().unwrap();
+    let profile_dir = exe
+        .parent()
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .unwrap()
+        .to_string();
+    let profile = match profile_dir.as_str() {
+        "debug" => "dev",
+        name => name,
+    };
+
+    // A target directory of our own, as the outer `cargo test` holds the lock on its own
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["rustc", "--lib", "--features", "ffi"])
+        .args(["--crate-type", "cdylib"])
+        .args(["--profile", profile])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "building the cdylib failed");
+    target_dir.join(profile_dir)
+}
+
+#[test]
+fn c_program_uses_the_api() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib_dir = cdylib_dir();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("readabilityrs-ffi-smoke");
+
+    let compiler = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join("tests/ffi/smoke.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lreadabilityrs")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("a C compiler should be available");
+    assert!(status.success(), "compiling smoke.c failed");
+
+    // Cargo's library search path for tests may hold a library built without
+    // `ffi`, and it takes precedence over the rpath
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .env("DYLD_LIBRARY_PATH", &lib_dir)
+        .arg(manifest_dir.join("tests/test-pages/001/source.html"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "smoke.c failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,68 @@
+/* Drives the C API the way an embedding program would.
+ *
+ * Usage: smoke <source.html>
+ */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "readabilityrs.h"
+
+#define CHECK(cond)                                                  \
+    do {                                                             \
+        if (!(cond)) {                                               \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            return 1;                                                \
+        }                                                            \
+    } while (0)
+
+static int equals(const uint8_t *value, size_t len, const char *expected) {
+    return value != NULL && len == strlen(expected) && memcmp(value, expected, len) == 0;
+}
+
+int main(int argc, char **argv) {
+    CHECK(argc == 2);
+
+    FILE *file = fopen(argv[1], "rb");
+    CHECK(file != NULL);
+    fseek(file, 0, SEEK_END);
+    size_t html_len = (size_t)ftell(file);
+    fseek(file, 0, SEEK_SET);
+    uint8_t *html = malloc(html_len);
+    CHECK(html != NULL);
+    CHECK(fread(html, 1, html_len, file) == html_len);
+    fclose(file);
+
+    ReadabilityArticle *article = NULL;
+    ReadabilityStatus status =
+        readability_parse(html, html_len, "http://fakehost/test/page.html", &article);
+    CHECK(status == READABILITY_STATUS_OK);
+    CHECK(article != NULL);
+
+    size_t len = 0;
+    const uint8_t *title = readability_article_title(article, &len);
+    CHECK(equals(title, len, "Get your Frontend JavaScript Code Covered | Code"));
+    const uint8_t *byline = readability_article_byline(article, &len);
+    CHECK(equals(byline, len, "Nicolas Perriault"));
+    const uint8_t *content = readability_article_content(article, &len);
+    CHECK(content != NULL && len > 0);
+    CHECK(readability_article_excerpt(article, &len) != NULL);
+    readability_article_free(article);
+
+    article = (ReadabilityArticle *)1;
+    status = readability_parse(html, html_len, "not a url", &article);
+    CHECK(status == READABILITY_STATUS_INVALID_URL);
+    CHECK(article == NULL);
+
+    const uint8_t invalid[] = {0xff, 0xfe};
+    status = readability_parse(invalid, sizeof invalid, NULL, &article);
+    CHECK(status == READABILITY_STATUS_INVALID_UTF8);
+
+    CHECK(readability_parse(NULL, 10, NULL, &article) == READABILITY_STATUS_NULL_ARGUMENT);
+    CHECK(readability_parse(html, html_len, NULL, NULL) == READABILITY_STATUS_NULL_ARGUMENT);
+    CHECK(readability_article_title(NULL, &len) == NULL && len == 0);
+    readability_article_free(NULL);
+
+    free(html);
+    return 0;
+}
@@ -208,6 +208,46 @@ fn test_mozilla_suite_metadata() {
     println!("\nNote: This is the initial baseline. Improvements will come with iteration.");
 }
 
+/// Our own output for every fixture, checked in as `expected-output.html`
+/// next to the source (missing when nothing is extracted), so changes that
+/// should not affect output can be checked byte for byte. Run with
+/// `UPDATE_EXPECTED_OUTPUT=1` to rewrite the files after an intended change.
+#[test]
+fn test_output_matches_expected_output() {
+    let update = std::env::var_os("UPDATE_EXPECTED_OUTPUT").is_some();
+    let mut mismatches = Vec::new();
+
+    for test_case in load_test_cases() {
+        let path = PathBuf::from("tests/test-pages")
+            .join(&test_case.name)
+            .join("expected-output.html");
+        let content = Readability::new(
+            &test_case.source_html,
+            Some("http://fakehost/test/page.html"),
+            None,
+        )
+        .ok()
+        .and_then(|readability| readability.parse())
+        .map(|article| article.content.unwrap_or_default());
+
+        if update {
+            match &content {
+                Some(content) => fs::write(&path, content).unwrap(),
+                None if path.exists() => fs::remove_file(&path).unwrap(),
+                None => {}
+            }
+        } else if content != fs::read_to_string(&path).ok() {
+            mismatches.push(test_case.name);
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "output differs from expected-output.html for {mismatches:?}; \
+         rerun with UPDATE_EXPECTED_OUTPUT=1 if the change is intended"
+    );
+}
+
 #[test]
 #[ignore]
 fn test_single_case_debug() {
@@ -272,3 +312,63 @@ fn test_single_case_debug() {
         println!("{}", &expected_html.chars().take(500).collect::<String>());
     }
 }
+
+#[test]
+fn test_mozilla_json_matches_expected_metadata() {
+    let test_dir = PathBuf::from("tests/test-pages/rtl-1");
+    let test_case = TestCase::load(&test_dir).expect("Failed to load test case");
+    let expected: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(test_dir.join("expected-metadata.json")).unwrap())
+            .unwrap();
+
+    let article = Readability::new(
+        &test_case.source_html,
+        Some("http://fakehost/test/page.html"),
+        None,
+    )
+    .unwrap()
+    .parse()
+    .expect("rtl-1 is readerable");
+    let json = article.to_mozilla_json();
+
+    // `readerable` comes from isProbablyReaderable, not parse()
+    for (key, value) in expected.iter().filter(|(key, _)| *key != "readerable") {
+        assert_eq!(&json[key], value, "mismatch in {key}");
+    }
+
+    let text = json["textContent"].as_str().unwrap();
+    assert_eq!(json["length"], text.chars().count());
+    assert_eq!(json["content"].as_str(), article.content.as_deref());
+}
+
+#[test]
+fn test_noscript_images_replace_lazy_placeholders() {
+    // Medium renders a blurred placeholder and puts the real image in <noscript>
+    let test_case =
+        TestCase::load(Path::new("tests/test-pages/medium-3")).expect("Failed to load test case");
+    let content = Readability::new(
+        &test_case.source_html,
+        Some("http://fakehost/test/page.html"),
+        None,
+    )
+    .unwrap()
+    .parse()
+    .expect("medium-3 is readerable")
+    .content
+    .unwrap();
+
+    for image in [
+        "https://miro.medium.com/max/796/1*kbPh7V97eyRodSOw2-ALDw.png",
+        "https://miro.medium.com/max/1388/1*Fpb2Bvdx7Q-688vdm-NdkQ.png",
+        "https://miro.medium.com/max/616/1*lvOySry5gHHJfGU_bQXrzA.png",
+    ] {
+        assert!(
+            content.contains(&format!(r#"src="{image}""#)),
+            "{image} missing from output"
+        );
+    }
+    // Each figure keeps only the full-size image, not the blurred preview
+    assert_eq!(content.matches(r#"alt="Image for post""#).count(), 3);
+    assert!(!content.contains("?q=20"));
+    assert!(!content.contains("<noscript"));
+}
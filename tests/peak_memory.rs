@@ -0,0 +1,78 @@
+//! Peak heap usage of a full parse, measured with a counting global allocator
+//!
+//! Lives in its own test binary so no other test allocates while it measures.
+
+use readabilityrs::Readability;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Forwards to the system allocator while tracking live and peak bytes
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn grow(bytes: usize) {
+    let live = LIVE_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn shrink(bytes: usize) {
+    LIVE_BYTES.fetch_sub(bytes, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Bytes allocated on top of what was live before `f` ran, at its highest point
+fn peak_allocated<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let baseline = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(baseline, Ordering::Relaxed);
+    let result = f();
+    (result, PEAK_BYTES.load(Ordering::Relaxed) - baseline)
+}
+
+#[test]
+fn test_parse_peak_memory() {
+    let html = fs::read_to_string("tests/test-pages/wikipedia-2/source.html").unwrap();
+
+    let (article, peak) = peak_allocated(|| Readability::new(&html, None, None).unwrap().parse());
+    assert!(article.is_some());
+
+    // Holding the source tree, the preprocessed tree and every intermediate
+    // copy of the article until the end used to take over 34x the page size
+    let limit = html.len() * 24;
+    assert!(
+        peak < limit,
+        "parse peaked at {peak} bytes for a {} byte page (limit {limit})",
+        html.len()
+    );
+}
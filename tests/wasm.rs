@@ -0,0 +1,59 @@
+//! Headless check of the JavaScript bindings
+//!
+//! ```text
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+//! ```
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SOURCE: &str = include_str!("test-pages/001/source.html");
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn parse_returns_readability_js_article() {
+    let article = readabilityrs::wasm::parse(
+        SOURCE,
+        Some("http://fakehost/test/page.html".into()),
+        JsValue::UNDEFINED,
+    )
+    .unwrap();
+
+    assert_eq!(
+        get(&article, "title").as_string().as_deref(),
+        Some("Get your Frontend JavaScript Code Covered | Code")
+    );
+    assert_eq!(
+        get(&article, "byline").as_string().as_deref(),
+        Some("Nicolas Perriault")
+    );
+    assert!(get(&article, "dir").is_null());
+    assert!(get(&article, "siteName").is_null());
+    assert!(get(&article, "content")
+        .as_string()
+        .unwrap()
+        .contains("<p>"));
+    assert!(get(&article, "length").as_f64().unwrap() > 0.0);
+}
+
+#[wasm_bindgen_test]
+fn parse_rejects_invalid_input() {
+    assert!(readabilityrs::wasm::parse(SOURCE, Some("not a url".into()), JsValue::NULL).is_err());
+
+    let options = js_sys::JSON::parse(r#"{"allowedVideoRegex": "("}"#).unwrap();
+    assert!(readabilityrs::wasm::parse(SOURCE, None, options).is_err());
+}
+
+#[wasm_bindgen_test]
+fn is_probably_readerable_takes_options() {
+    assert!(readabilityrs::wasm::is_probably_readerable(SOURCE, JsValue::UNDEFINED).unwrap());
+
+    let options = js_sys::JSON::parse(r#"{"minContentLength": 100000}"#).unwrap();
+    assert!(!readabilityrs::wasm::is_probably_readerable(SOURCE, options).unwrap());
+}